@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Boot Graphics Resource Table (BGRT)
+//!
+//! ## References
+//!
+//! - ACPI Specification, Version 6.5
+//!   - 5.2.23: Boot Graphics Resource Table (BGRT)
+
+use super::HEADER_SIZE;
+use super::Sdt;
+use super::Table;
+use crate::bytes::read_u8;
+use crate::bytes::read_u16;
+use crate::bytes::read_u32;
+use crate::bytes::read_u64;
+
+/// The length of the BGRT.
+const LENGTH: usize = HEADER_SIZE + 20;
+
+/// The format of the boot image.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ImageType(u8);
+
+impl ImageType {
+    pub const BITMAP: Self = Self(0);
+}
+
+/// A view of the BGRT, with signature `BGRT`.
+#[derive(Clone, Copy, Debug)]
+pub struct Bgrt<'a> {
+    sdt: Sdt<'a>,
+    pub version: u16,
+    /// Bit 0 is set if the image is displayed, and bits 1-2 give its
+    /// clockwise orientation offset in units of 90 degrees.
+    pub status: u8,
+    pub image_type: ImageType,
+    /// The physical address of the image.
+    pub image_address: u64,
+    pub image_offset_x: u32,
+    pub image_offset_y: u32,
+}
+
+impl<'a> Table<'a> for Bgrt<'a> {
+    const SIGNATURE: [u8; 4] = *b"BGRT";
+
+    fn from_sdt(sdt: Sdt<'a>) -> Option<Self> {
+        let d = sdt.as_bytes();
+        if d.len() < LENGTH {
+            return None;
+        }
+
+        Some(Self {
+            version: read_u16(d, 36)?,
+            status: read_u8(d, 38)?,
+            image_type: ImageType(read_u8(d, 39)?),
+            image_address: read_u64(d, 40)?,
+            image_offset_x: read_u32(d, 48)?,
+            image_offset_y: read_u32(d, 52)?,
+            sdt,
+        })
+    }
+}
+
+impl<'a> Bgrt<'a> {
+    pub fn sdt(&self) -> &Sdt<'a> {
+        &self.sdt
+    }
+
+    /// Returns `true` if the image is currently displayed.
+    pub fn is_displayed(&self) -> bool {
+        self.status & 1 != 0
+    }
+
+    /// The clockwise rotation of the image, in degrees.
+    pub fn orientation(&self) -> u16 {
+        u16::from((self.status >> 1) & 0b11) * 90
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::sdt;
+    use super::*;
+
+    #[rustfmt::skip]
+    const BGRT: &[u8] = &[
+        0x01, 0x00, // Version
+        0x05, // Status
+        0x00, // Image Type
+        0x00, 0x00, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00, // Image Address
+        0x64, 0x00, 0x00, 0x00, // Image Offset X
+        0xC8, 0x00, 0x00, 0x00, // Image Offset Y
+    ];
+
+    #[test]
+    fn parse() {
+        let mut buf = [0; 64];
+        let table = sdt(&mut buf, *b"BGRT", 1, BGRT);
+        let bgrt = Sdt::from_bytes(table).unwrap().as_typed::<Bgrt>().unwrap();
+        assert_eq!(bgrt.version, 1);
+        assert_eq!(bgrt.image_type, ImageType::BITMAP);
+        assert_eq!(bgrt.image_address, 0x7F00_0000);
+        assert_eq!((bgrt.image_offset_x, bgrt.image_offset_y), (100, 200));
+        assert!(bgrt.is_displayed());
+        assert_eq!(bgrt.orientation(), 180);
+    }
+}
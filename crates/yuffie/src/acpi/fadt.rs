@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Fixed ACPI Description Table (FADT)
+//!
+//! ## References
+//!
+//! - ACPI Specification, Version 6.5
+//!   - 5.2.9: Fixed ACPI Description Table (FADT)
+
+use core::ops;
+
+use super::AddressSpace;
+use super::GenericAddress;
+use super::Sdt;
+use super::Table;
+use crate::bytes::read_u8;
+use crate::bytes::read_u16;
+use crate::bytes::read_u32;
+use crate::bytes::read_u64;
+
+/// The length of the FADT in ACPI 1.0.
+const V1_LENGTH: usize = 116;
+
+/// Preferred power management profile
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct PmProfile(u8);
+
+impl PmProfile {
+    pub const UNSPECIFIED: Self = Self(0);
+    pub const DESKTOP: Self = Self(1);
+    pub const MOBILE: Self = Self(2);
+    pub const WORKSTATION: Self = Self(3);
+    pub const ENTERPRISE_SERVER: Self = Self(4);
+    pub const SOHO_SERVER: Self = Self(5);
+    pub const APPLIANCE_PC: Self = Self(6);
+    pub const PERFORMANCE_SERVER: Self = Self(7);
+    pub const TABLET: Self = Self(8);
+}
+
+/// Fixed feature flags
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct FadtFlags(u32);
+
+impl FadtFlags {
+    pub const WBINVD: Self = Self(1 << 0);
+    pub const PWR_BUTTON: Self = Self(1 << 4);
+    pub const SLP_BUTTON: Self = Self(1 << 5);
+    pub const RESET_REG_SUP: Self = Self(1 << 10);
+    pub const HW_REDUCED_ACPI: Self = Self(1 << 20);
+    pub const LOW_POWER_S0_IDLE_CAPABLE: Self = Self(1 << 21);
+
+    /// Returns `true` if all of the flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl ops::BitOr for FadtFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// A view of the FADT, with signature `FACP`.
+///
+/// Fields added after ACPI 1.0 are `None` if the table is too short to
+/// contain them.
+#[derive(Clone, Copy, Debug)]
+pub struct Fadt<'a>(Sdt<'a>);
+
+impl<'a> Table<'a> for Fadt<'a> {
+    const SIGNATURE: [u8; 4] = *b"FACP";
+
+    fn from_sdt(sdt: Sdt<'a>) -> Option<Self> {
+        (sdt.length() >= V1_LENGTH).then_some(Self(sdt))
+    }
+}
+
+impl<'a> Fadt<'a> {
+    pub fn sdt(&self) -> &Sdt<'a> {
+        &self.0
+    }
+
+    fn u8(&self, offset: usize) -> u8 {
+        read_u8(self.0.as_bytes(), offset).unwrap_or_default()
+    }
+
+    fn u16(&self, offset: usize) -> u16 {
+        read_u16(self.0.as_bytes(), offset).unwrap_or_default()
+    }
+
+    fn u32(&self, offset: usize) -> u32 {
+        read_u32(self.0.as_bytes(), offset).unwrap_or_default()
+    }
+
+    /// Reads a 64-bit extended address, treating 0 as not present.
+    fn x_address(&self, offset: usize) -> Option<u64> {
+        read_u64(self.0.as_bytes(), offset).filter(|&addr| addr != 0)
+    }
+
+    /// Reads an extended generic address, treating address 0 as not present.
+    fn x_register(&self, offset: usize) -> Option<GenericAddress> {
+        GenericAddress::parse(self.0.as_bytes(), offset).filter(|gas| gas.address != 0)
+    }
+
+    /// The physical address of the FACS.
+    pub fn firmware_ctrl_address(&self) -> Option<u64> {
+        self.x_address(132).or_else(|| Some(u64::from(self.u32(36))).filter(|&addr| addr != 0))
+    }
+
+    /// The physical address of the DSDT.
+    pub fn dsdt_address(&self) -> Option<u64> {
+        self.x_address(140).or_else(|| Some(u64::from(self.u32(40))).filter(|&addr| addr != 0))
+    }
+
+    pub fn preferred_pm_profile(&self) -> PmProfile {
+        PmProfile(self.u8(45))
+    }
+
+    /// The system vector the SCI interrupt is wired to in 8259 mode, or the
+    /// global system interrupt otherwise.
+    pub fn sci_interrupt(&self) -> u16 {
+        self.u16(46)
+    }
+
+    /// The I/O port of the SMI command port, or 0 if SMM is not supported.
+    pub fn smi_command_port(&self) -> u32 {
+        self.u32(48)
+    }
+
+    /// The value written to the SMI command port to enable ACPI.
+    pub fn acpi_enable(&self) -> u8 {
+        self.u8(52)
+    }
+
+    /// The value written to the SMI command port to disable ACPI.
+    pub fn acpi_disable(&self) -> u8 {
+        self.u8(53)
+    }
+
+    /// The `PM1a` control register block.
+    pub fn pm1a_control_block(&self) -> Option<GenericAddress> {
+        self.x_register(172).or_else(|| self.io_register(64, 89))
+    }
+
+    /// The PM timer register block.
+    pub fn pm_timer_block(&self) -> Option<GenericAddress> {
+        self.x_register(208).or_else(|| self.io_register(76, 91))
+    }
+
+    /// Creates a generic address for a 32-bit I/O port block and its length.
+    fn io_register(&self, port: usize, len: usize) -> Option<GenericAddress> {
+        let port = self.u32(port);
+        (port != 0).then(|| GenericAddress {
+            address_space: AddressSpace::SYSTEM_IO,
+            bit_width: self.u8(len).saturating_mul(8),
+            bit_offset: 0,
+            access_size: 0,
+            address: u64::from(port),
+        })
+    }
+
+    /// The RTC CMOS index of the century, or 0 if not supported.
+    pub fn century(&self) -> u8 {
+        self.u8(108)
+    }
+
+    /// IA-PC boot architecture flags.
+    pub fn iapc_boot_arch(&self) -> u16 {
+        self.u16(109)
+    }
+
+    /// Fixed feature flags.
+    pub fn flags(&self) -> FadtFlags {
+        FadtFlags(self.u32(112))
+    }
+
+    /// The reset register, if present and supported.
+    pub fn reset_register(&self) -> Option<GenericAddress> {
+        if !self.flags().contains(FadtFlags::RESET_REG_SUP) {
+            return None;
+        }
+
+        GenericAddress::parse(self.0.as_bytes(), 116)
+    }
+
+    /// The value to write to the reset register to reset the system.
+    pub fn reset_value(&self) -> Option<u8> {
+        read_u8(self.0.as_bytes(), 128)
+    }
+
+    /// ARM boot architecture flags.
+    pub fn arm_boot_arch(&self) -> Option<u16> {
+        read_u16(self.0.as_bytes(), 129)
+    }
+
+    /// The minor version of the FADT. The major version is the table
+    /// revision.
+    pub fn minor_version(&self) -> Option<u8> {
+        read_u8(self.0.as_bytes(), 131)
+    }
+
+    /// The sleep control register of hardware-reduced ACPI systems.
+    pub fn sleep_control_register(&self) -> Option<GenericAddress> {
+        self.x_register(244)
+    }
+
+    /// The sleep status register of hardware-reduced ACPI systems.
+    pub fn sleep_status_register(&self) -> Option<GenericAddress> {
+        self.x_register(256)
+    }
+
+    /// Returns `true` if the system implements the hardware-reduced ACPI
+    /// interface.
+    pub fn is_hardware_reduced(&self) -> bool {
+        self.flags().contains(FadtFlags::HW_REDUCED_ACPI)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::HEADER_SIZE;
+    use super::super::test::sdt;
+    use super::*;
+
+    /// Writes an FADT of `len` bytes with the fields used by the tests to
+    /// `buf`.
+    fn fadt(buf: &mut [u8], len: usize) -> &[u8] {
+        let mut data = [0; 276 - HEADER_SIZE];
+        let mut set = |offset: usize, bytes: &[u8]| {
+            let offset = offset - HEADER_SIZE;
+            data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        };
+
+        set(40, &0x1000u32.to_le_bytes()); // DSDT
+        set(45, &[PmProfile::MOBILE.0]);
+        set(46, &9u16.to_le_bytes()); // SCI_INT
+        set(76, &0x408u32.to_le_bytes()); // PM_TMR_BLK
+        set(91, &[4]); // PM_TMR_LEN
+        set(112, &FadtFlags::RESET_REG_SUP.0.to_le_bytes());
+        set(116, &[1, 8, 0, 1]);
+        set(120, &0xCF9u64.to_le_bytes()); // RESET_REG
+        set(128, &[0x06]); // RESET_VALUE
+        set(140, &0x2000u64.to_le_bytes()); // X_DSDT
+        sdt(buf, *b"FACP", 6, &data[..len - HEADER_SIZE])
+    }
+
+    #[test]
+    fn v1() {
+        let mut buf = [0; 276];
+        let table = fadt(&mut buf, 116);
+        let fadt = Sdt::from_bytes(table).unwrap().as_typed::<Fadt>().unwrap();
+        assert_eq!(fadt.dsdt_address(), Some(0x1000));
+        assert_eq!(fadt.preferred_pm_profile(), PmProfile::MOBILE);
+        assert_eq!(fadt.sci_interrupt(), 9);
+        assert!(fadt.flags().contains(FadtFlags::RESET_REG_SUP));
+        assert!(!fadt.is_hardware_reduced());
+        assert_eq!(fadt.reset_register(), None);
+        assert_eq!(fadt.minor_version(), None);
+
+        let timer = fadt.pm_timer_block().unwrap();
+        assert_eq!(timer.address_space, AddressSpace::SYSTEM_IO);
+        assert_eq!(timer.address, 0x408);
+        assert_eq!(timer.bit_width, 32);
+
+        let short = sdt(&mut buf, *b"FACP", 1, &[0; 40]);
+        assert!(Sdt::from_bytes(short).unwrap().as_typed::<Fadt>().is_none());
+    }
+
+    #[test]
+    fn v6() {
+        let mut buf = [0; 276];
+        let table = fadt(&mut buf, 276);
+        let fadt = Sdt::from_bytes(table).unwrap().as_typed::<Fadt>().unwrap();
+        assert_eq!(fadt.dsdt_address(), Some(0x2000));
+        assert_eq!(fadt.reset_value(), Some(6));
+
+        let reset = fadt.reset_register().unwrap();
+        assert_eq!(reset.address_space, AddressSpace::SYSTEM_IO);
+        assert_eq!(reset.address, 0xCF9);
+        assert_eq!(fadt.sleep_control_register(), None);
+    }
+}
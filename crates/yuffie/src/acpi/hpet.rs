@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # High Precision Event Timer (HPET) table
+//!
+//! ## References
+//!
+//! - IA-PC HPET Specification, Revision 1.0a
+//!   - 3.2.4: The ACPI 2.0 HPET Description Table (HPET)
+
+use super::GenericAddress;
+use super::HEADER_SIZE;
+use super::Sdt;
+use super::Table;
+use crate::bytes::read_u8;
+use crate::bytes::read_u16;
+use crate::bytes::read_u32;
+
+/// The length of the HPET table.
+const LENGTH: usize = HEADER_SIZE + 20;
+
+/// A view of the HPET table, with signature `HPET`.
+#[derive(Clone, Copy, Debug)]
+pub struct Hpet<'a> {
+    sdt: Sdt<'a>,
+    /// The hardware ID of the event timer block, which matches its general
+    /// capabilities register.
+    pub event_timer_block_id: u32,
+    /// The address of the event timer block registers.
+    pub base_address: GenericAddress,
+    pub hpet_number: u8,
+    /// The minimum clock tick to use in periodic mode.
+    pub minimum_clock_tick: u16,
+    pub page_protection: u8,
+}
+
+impl<'a> Table<'a> for Hpet<'a> {
+    const SIGNATURE: [u8; 4] = *b"HPET";
+
+    fn from_sdt(sdt: Sdt<'a>) -> Option<Self> {
+        let d = sdt.as_bytes();
+        if d.len() < LENGTH {
+            return None;
+        }
+
+        Some(Self {
+            event_timer_block_id: read_u32(d, 36)?,
+            base_address: GenericAddress::parse(d, 40)?,
+            hpet_number: read_u8(d, 52)?,
+            minimum_clock_tick: read_u16(d, 53)?,
+            page_protection: read_u8(d, 55)?,
+            sdt,
+        })
+    }
+}
+
+impl<'a> Hpet<'a> {
+    pub fn sdt(&self) -> &Sdt<'a> {
+        &self.sdt
+    }
+
+    /// The number of comparators in the event timer block.
+    pub fn comparator_count(&self) -> u8 {
+        ((self.event_timer_block_id >> 8) & 0x1F) as u8 + 1
+    }
+
+    /// The PCI vendor ID of the event timer block.
+    pub fn vendor_id(&self) -> u16 {
+        (self.event_timer_block_id >> 16) as u16
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::AddressSpace;
+    use super::super::test::sdt;
+    use super::*;
+
+    #[rustfmt::skip]
+    const HPET: &[u8] = &[
+        0x01, 0xA2, 0x86, 0x80, // Event Timer Block ID
+        0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0xD0, 0xFE, 0x00, 0x00, 0x00, 0x00, // Base Address
+        0x00, // HPET Number
+        0x80, 0x00, // Main Counter Minimum Clock Tick
+        0x00, // Page Protection
+    ];
+
+    #[test]
+    fn parse() {
+        let mut buf = [0; 64];
+        let table = sdt(&mut buf, *b"HPET", 1, HPET);
+        let hpet = Sdt::from_bytes(table).unwrap().as_typed::<Hpet>().unwrap();
+        assert_eq!(hpet.base_address.address_space, AddressSpace::SYSTEM_MEMORY);
+        assert_eq!(hpet.base_address.address, 0xFED0_0000);
+        assert_eq!(hpet.minimum_clock_tick, 0x80);
+        assert_eq!(hpet.comparator_count(), 3);
+        assert_eq!(hpet.vendor_id(), 0x8086);
+
+        let short = sdt(&mut buf, *b"HPET", 1, &HPET[..16]);
+        assert!(Sdt::from_bytes(short).unwrap().as_typed::<Hpet>().is_none());
+    }
+}
@@ -0,0 +1,418 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Multiple APIC Description Table (MADT)
+//!
+//! ## References
+//!
+//! - ACPI Specification, Version 6.5
+//!   - 5.2.12: Multiple APIC Description Table (MADT)
+
+use core::ops;
+
+use super::HEADER_SIZE;
+use super::Sdt;
+use super::Table;
+use crate::bytes::read_u8;
+use crate::bytes::read_u16;
+use crate::bytes::read_u32;
+use crate::bytes::read_u64;
+
+/// The offset of the first interrupt controller structure.
+const ENTRIES_OFFSET: usize = HEADER_SIZE + 8;
+
+/// The type of an interrupt controller structure.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct MadtEntryType(u8);
+
+impl MadtEntryType {
+    pub const LOCAL_APIC: Self = Self(0x00);
+    pub const IO_APIC: Self = Self(0x01);
+    pub const INTERRUPT_OVERRIDE: Self = Self(0x02);
+    pub const NMI_SOURCE: Self = Self(0x03);
+    pub const LOCAL_APIC_NMI: Self = Self(0x04);
+    pub const LOCAL_APIC_ADDRESS_OVERRIDE: Self = Self(0x05);
+    pub const LOCAL_X2APIC: Self = Self(0x09);
+    pub const LOCAL_X2APIC_NMI: Self = Self(0x0A);
+    pub const GICC: Self = Self(0x0B);
+    pub const GICD: Self = Self(0x0C);
+    pub const GIC_MSI_FRAME: Self = Self(0x0D);
+    pub const GICR: Self = Self(0x0E);
+    pub const GIC_ITS: Self = Self(0x0F);
+}
+
+/// Multiple APIC flags
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct MadtFlags(u32);
+
+impl MadtFlags {
+    /// The system also has a PC-AT-compatible dual-8259 setup.
+    pub const PCAT_COMPAT: Self = Self(1 << 0);
+
+    /// Returns `true` if all of the flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl ops::BitOr for MadtFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Processor Local APIC structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LocalApic {
+    pub processor_uid: u8,
+    pub apic_id: u8,
+    /// Bit 0 is set if the processor is enabled, and bit 1 if it can be
+    /// enabled.
+    pub flags: u32,
+}
+
+/// I/O APIC structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IoApic {
+    pub io_apic_id: u8,
+    pub address: u32,
+    /// The first global system interrupt handled by the I/O APIC.
+    pub gsi_base: u32,
+}
+
+/// Interrupt Source Override structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InterruptOverride {
+    /// 0, for ISA.
+    pub bus: u8,
+    /// The bus-relative interrupt source (IRQ).
+    pub source: u8,
+    pub gsi: u32,
+    /// MPS INTI flags for polarity and trigger mode.
+    pub flags: u16,
+}
+
+/// Local APIC NMI structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LocalApicNmi {
+    /// The processor the NMI is connected to, or `0xFF` for all.
+    pub processor_uid: u8,
+    pub flags: u16,
+    pub lint: u8,
+}
+
+/// Processor Local x2APIC structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LocalX2Apic {
+    pub x2apic_id: u32,
+    pub flags: u32,
+    pub processor_uid: u32,
+}
+
+/// GIC CPU Interface (GICC) structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Gicc {
+    pub cpu_interface_number: u32,
+    pub processor_uid: u32,
+    pub flags: u32,
+    pub parking_protocol_version: u32,
+    pub performance_interrupt_gsiv: u32,
+    pub parked_address: u64,
+    pub physical_base_address: u64,
+    pub gicv: u64,
+    pub gich: u64,
+    pub vgic_maintenance_interrupt: u32,
+    pub gicr_base_address: u64,
+    pub mpidr: u64,
+}
+
+/// GIC Distributor (GICD) structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Gicd {
+    pub gic_id: u32,
+    pub physical_base_address: u64,
+    pub gic_version: u8,
+}
+
+/// GIC Redistributor (GICR) structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Gicr {
+    pub discovery_range_base_address: u64,
+    pub discovery_range_length: u32,
+}
+
+/// GIC Interrupt Translation Service (ITS) structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GicIts {
+    pub gic_its_id: u32,
+    pub physical_base_address: u64,
+}
+
+/// An interrupt controller structure of the MADT.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MadtEntry<'a> {
+    LocalApic(LocalApic),
+    IoApic(IoApic),
+    InterruptOverride(InterruptOverride),
+    LocalApicNmi(LocalApicNmi),
+    /// The 64-bit physical address of the local APIC, overriding the 32-bit
+    /// address in the table header.
+    LocalApicAddressOverride(u64),
+    LocalX2Apic(LocalX2Apic),
+    Gicc(Gicc),
+    Gicd(Gicd),
+    Gicr(Gicr),
+    GicIts(GicIts),
+    /// A structure without a typed view, or that is too short for its type.
+    Other {
+        kind: MadtEntryType,
+        /// The structure, including its type and length.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> MadtEntry<'a> {
+    fn parse(d: &'a [u8]) -> Self {
+        let kind = MadtEntryType(d[0]);
+        let entry = match kind {
+            MadtEntryType::LOCAL_APIC => Self::local_apic(d),
+            MadtEntryType::IO_APIC => Self::io_apic(d),
+            MadtEntryType::INTERRUPT_OVERRIDE => Self::interrupt_override(d),
+            MadtEntryType::LOCAL_APIC_NMI => Self::local_apic_nmi(d),
+            MadtEntryType::LOCAL_APIC_ADDRESS_OVERRIDE => {
+                read_u64(d, 4).map(Self::LocalApicAddressOverride)
+            }
+            MadtEntryType::LOCAL_X2APIC => Self::local_x2apic(d),
+            MadtEntryType::GICC => Self::gicc(d),
+            MadtEntryType::GICD => Self::gicd(d),
+            MadtEntryType::GICR => Self::gicr(d),
+            MadtEntryType::GIC_ITS => Self::gic_its(d),
+            _ => None,
+        };
+
+        entry.unwrap_or(Self::Other {
+            kind,
+            data: d,
+        })
+    }
+
+    fn local_apic(d: &[u8]) -> Option<Self> {
+        Some(Self::LocalApic(LocalApic {
+            processor_uid: read_u8(d, 2)?,
+            apic_id: read_u8(d, 3)?,
+            flags: read_u32(d, 4)?,
+        }))
+    }
+
+    fn io_apic(d: &[u8]) -> Option<Self> {
+        Some(Self::IoApic(IoApic {
+            io_apic_id: read_u8(d, 2)?,
+            address: read_u32(d, 4)?,
+            gsi_base: read_u32(d, 8)?,
+        }))
+    }
+
+    fn interrupt_override(d: &[u8]) -> Option<Self> {
+        Some(Self::InterruptOverride(InterruptOverride {
+            bus: read_u8(d, 2)?,
+            source: read_u8(d, 3)?,
+            gsi: read_u32(d, 4)?,
+            flags: read_u16(d, 8)?,
+        }))
+    }
+
+    fn local_apic_nmi(d: &[u8]) -> Option<Self> {
+        Some(Self::LocalApicNmi(LocalApicNmi {
+            processor_uid: read_u8(d, 2)?,
+            flags: read_u16(d, 3)?,
+            lint: read_u8(d, 5)?,
+        }))
+    }
+
+    fn local_x2apic(d: &[u8]) -> Option<Self> {
+        Some(Self::LocalX2Apic(LocalX2Apic {
+            x2apic_id: read_u32(d, 4)?,
+            flags: read_u32(d, 8)?,
+            processor_uid: read_u32(d, 12)?,
+        }))
+    }
+
+    fn gicc(d: &[u8]) -> Option<Self> {
+        Some(Self::Gicc(Gicc {
+            cpu_interface_number: read_u32(d, 4)?,
+            processor_uid: read_u32(d, 8)?,
+            flags: read_u32(d, 12)?,
+            parking_protocol_version: read_u32(d, 16)?,
+            performance_interrupt_gsiv: read_u32(d, 20)?,
+            parked_address: read_u64(d, 24)?,
+            physical_base_address: read_u64(d, 32)?,
+            gicv: read_u64(d, 40)?,
+            gich: read_u64(d, 48)?,
+            vgic_maintenance_interrupt: read_u32(d, 56)?,
+            gicr_base_address: read_u64(d, 60)?,
+            mpidr: read_u64(d, 68)?,
+        }))
+    }
+
+    fn gicd(d: &[u8]) -> Option<Self> {
+        Some(Self::Gicd(Gicd {
+            gic_id: read_u32(d, 4)?,
+            physical_base_address: read_u64(d, 8)?,
+            gic_version: read_u8(d, 20)?,
+        }))
+    }
+
+    fn gicr(d: &[u8]) -> Option<Self> {
+        Some(Self::Gicr(Gicr {
+            discovery_range_base_address: read_u64(d, 4)?,
+            discovery_range_length: read_u32(d, 12)?,
+        }))
+    }
+
+    fn gic_its(d: &[u8]) -> Option<Self> {
+        Some(Self::GicIts(GicIts {
+            gic_its_id: read_u32(d, 4)?,
+            physical_base_address: read_u64(d, 8)?,
+        }))
+    }
+}
+
+/// A view of the MADT, with signature `APIC`.
+#[derive(Clone, Copy, Debug)]
+pub struct Madt<'a>(Sdt<'a>);
+
+impl<'a> Table<'a> for Madt<'a> {
+    const SIGNATURE: [u8; 4] = *b"APIC";
+
+    fn from_sdt(sdt: Sdt<'a>) -> Option<Self> {
+        (sdt.length() >= ENTRIES_OFFSET).then_some(Self(sdt))
+    }
+}
+
+impl<'a> Madt<'a> {
+    pub fn sdt(&self) -> &Sdt<'a> {
+        &self.0
+    }
+
+    /// The 32-bit physical address of the local APIC of each processor.
+    pub fn local_apic_address(&self) -> u32 {
+        read_u32(self.0.as_bytes(), HEADER_SIZE).unwrap_or_default()
+    }
+
+    pub fn flags(&self) -> MadtFlags {
+        MadtFlags(read_u32(self.0.as_bytes(), HEADER_SIZE + 4).unwrap_or_default())
+    }
+
+    /// Returns an iterator over the interrupt controller structures.
+    ///
+    /// Iteration stops at a structure with an invalid length.
+    pub fn entries(&self) -> MadtEntries<'a> {
+        MadtEntries(&self.0.as_bytes()[ENTRIES_OFFSET..])
+    }
+}
+
+/// An iterator over the interrupt controller structures of the MADT.
+#[derive(Clone, Debug)]
+pub struct MadtEntries<'a>(&'a [u8]);
+
+impl<'a> Iterator for MadtEntries<'a> {
+    type Item = MadtEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = usize::from(*self.0.get(1)?);
+        if len < 2 || len > self.0.len() {
+            self.0 = &[];
+            return None;
+        }
+
+        let (entry, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(MadtEntry::parse(entry))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::sdt;
+    use super::*;
+
+    #[rustfmt::skip]
+    const MADT: &[u8] = &[
+        0x00, 0x00, 0xE0, 0xFE, // Local Interrupt Controller Address
+        0x01, 0x00, 0x00, 0x00, // Flags
+        0x00, 0x08, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, // Local APIC
+        0x01, 0x0C, 0x02, 0x00, 0x00, 0x00, 0xC0, 0xFE, 0x00, 0x00, 0x00, 0x00, // I/O APIC
+        0x02, 0x0A, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0F, 0x00, // Interrupt Override
+        0x04, 0x06, 0xFF, 0x05, 0x00, 0x01, // Local APIC NMI
+        0x80, 0x04, 0xAA, 0xBB, // OEM
+        0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // GICD
+        // Truncated entry, which ends the iteration.
+        0x00, 0x09, 0x00,
+    ];
+
+    #[test]
+    fn entries() {
+        let mut buf = [0; 128];
+        let table = sdt(&mut buf, *b"APIC", 5, MADT);
+        let madt = Sdt::from_bytes(table).unwrap().as_typed::<Madt>().unwrap();
+        assert_eq!(madt.local_apic_address(), 0xFEE0_0000);
+        assert!(madt.flags().contains(MadtFlags::PCAT_COMPAT));
+
+        let mut entries = madt.entries();
+        assert_eq!(
+            entries.next(),
+            Some(MadtEntry::LocalApic(LocalApic {
+                processor_uid: 0,
+                apic_id: 1,
+                flags: 1,
+            }))
+        );
+        assert_eq!(
+            entries.next(),
+            Some(MadtEntry::IoApic(IoApic {
+                io_apic_id: 2,
+                address: 0xFEC0_0000,
+                gsi_base: 0,
+            }))
+        );
+        assert_eq!(
+            entries.next(),
+            Some(MadtEntry::InterruptOverride(InterruptOverride {
+                bus: 0,
+                source: 0,
+                gsi: 2,
+                flags: 0xF,
+            }))
+        );
+        assert_eq!(
+            entries.next(),
+            Some(MadtEntry::LocalApicNmi(LocalApicNmi {
+                processor_uid: 0xFF,
+                flags: 5,
+                lint: 1,
+            }))
+        );
+        assert_eq!(
+            entries.next(),
+            Some(MadtEntry::Other {
+                kind: MadtEntryType(0x80),
+                data: &[0x80, 4, 0xAA, 0xBB],
+            })
+        );
+        assert_eq!(
+            entries.next(),
+            Some(MadtEntry::Gicd(Gicd {
+                gic_id: 0,
+                physical_base_address: 0x0800_0000,
+                gic_version: 3,
+            }))
+        );
+        assert_eq!(entries.next(), None);
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # PCI Express Memory-mapped Configuration (MCFG) table
+//!
+//! ## References
+//!
+//! - PCI Firmware Specification, Revision 3.3
+//!   - 4.1.2: MCFG Table Description
+
+use super::HEADER_SIZE;
+use super::Sdt;
+use super::Table;
+use crate::bytes::read_u8;
+use crate::bytes::read_u16;
+use crate::bytes::read_u64;
+
+/// The offset of the first allocation structure.
+const ENTRIES_OFFSET: usize = HEADER_SIZE + 8;
+/// The size of an allocation structure.
+const ENTRY_SIZE: usize = 16;
+
+/// Configuration space base address allocation structure
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct McfgEntry {
+    /// The base address of the enhanced configuration mechanism.
+    pub base_address: u64,
+    pub segment_group: u16,
+    pub start_bus: u8,
+    pub end_bus: u8,
+}
+
+impl McfgEntry {
+    fn parse(d: &[u8]) -> Option<Self> {
+        Some(Self {
+            base_address: read_u64(d, 0)?,
+            segment_group: read_u16(d, 8)?,
+            start_bus: read_u8(d, 10)?,
+            end_bus: read_u8(d, 11)?,
+        })
+    }
+
+    /// Returns the physical address of the configuration space of a
+    /// function, or `None` if the bus is not in this range.
+    pub fn config_address(&self, bus: u8, device: u8, function: u8) -> Option<u64> {
+        if bus < self.start_bus || bus > self.end_bus || device > 31 || function > 7 {
+            return None;
+        }
+
+        let offset =
+            (u64::from(bus) << 20) | (u64::from(device) << 15) | (u64::from(function) << 12);
+        self.base_address.checked_add(offset)
+    }
+}
+
+/// A view of the MCFG table, with signature `MCFG`.
+#[derive(Clone, Copy, Debug)]
+pub struct Mcfg<'a>(Sdt<'a>);
+
+impl<'a> Table<'a> for Mcfg<'a> {
+    const SIGNATURE: [u8; 4] = *b"MCFG";
+
+    fn from_sdt(sdt: Sdt<'a>) -> Option<Self> {
+        (sdt.length() >= ENTRIES_OFFSET).then_some(Self(sdt))
+    }
+}
+
+impl<'a> Mcfg<'a> {
+    pub fn sdt(&self) -> &Sdt<'a> {
+        &self.0
+    }
+
+    /// Returns an iterator over the allocation structures.
+    pub fn entries(&self) -> impl Iterator<Item = McfgEntry> + 'a {
+        self.0.as_bytes()[ENTRIES_OFFSET..].chunks_exact(ENTRY_SIZE).filter_map(McfgEntry::parse)
+    }
+
+    /// Returns the allocation structure of a bus in a segment group.
+    pub fn find(&self, segment_group: u16, bus: u8) -> Option<McfgEntry> {
+        self.entries()
+            .find(|e| e.segment_group == segment_group && (e.start_bus..=e.end_bus).contains(&bus))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::sdt;
+    use super::*;
+
+    #[rustfmt::skip]
+    const MCFG: &[u8] = &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Reserved
+        0x00, 0x00, 0x00, 0xE0, 0x00, 0x00, 0x00, 0x00, // Base Address
+        0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, // Segment 0, Bus 0-255
+        0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, // Base Address
+        0x01, 0x00, 0x10, 0x1F, 0x00, 0x00, 0x00, 0x00, // Segment 1, Bus 16-31
+    ];
+
+    #[test]
+    fn entries() {
+        let mut buf = [0; 128];
+        let table = sdt(&mut buf, *b"MCFG", 1, MCFG);
+        let mcfg = Sdt::from_bytes(table).unwrap().as_typed::<Mcfg>().unwrap();
+        assert_eq!(mcfg.entries().count(), 2);
+
+        let last = mcfg.entries().nth(1).unwrap();
+        assert_eq!(last, McfgEntry {
+            base_address: 0xC000_0000,
+            segment_group: 1,
+            start_bus: 0x10,
+            end_bus: 0x1F,
+        });
+
+        let entry = mcfg.find(0, 2).unwrap();
+        assert_eq!(entry.config_address(2, 0x1F, 3), Some(0xE020_0000 | (0x1F << 15) | (3 << 12)));
+        assert_eq!(last.config_address(2, 0, 0), None);
+        assert_eq!(mcfg.find(1, 2), None);
+    }
+}
@@ -0,0 +1,529 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # ACPI Tables
+//!
+//! Read-only views over the ACPI tables found through the RSDP, which is
+//! installed as the [`ACPI_20_TABLE`](crate::table::cfg::ACPI_20_TABLE)
+//! configuration table.
+//!
+//! Tables are read through a [`Memory`] implementation, so they can be parsed
+//! from identity-mapped physical memory during boot services, or from a byte
+//! slice.
+//!
+//! ```ignore
+//! let rsdp = st.find_config_table(&cfg::ACPI_20_TABLE).ok_or(Status::NOT_FOUND)?;
+//! let mem = unsafe { PhysicalMemory::new() };
+//! let acpi = Acpi::new(&mem, rsdp as u64)?;
+//! if let Some(madt) = acpi.table::<Madt>() {
+//!     for entry in madt.entries() {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! ## References
+//!
+//! - [ACPI Specification, Version 6.5][ACPI Spec]
+//!   - 5.2: ACPI System Description Tables
+//!
+//! [ACPI Spec]: https://uefi.org/specs/ACPI/6.5/
+
+pub mod bgrt;
+pub mod fadt;
+pub mod hpet;
+pub mod madt;
+pub mod mcfg;
+
+use crate::bytes::checksum_ok;
+use crate::bytes::read_u8;
+use crate::bytes::read_u32;
+use crate::bytes::read_u64;
+use crate::mem::Memory;
+use crate::prelude::*;
+
+/// The size of the RSDP in ACPI 1.0.
+const RSDP_V1_SIZE: usize = 20;
+/// The size of the RSDP in ACPI 2.0 and later.
+const RSDP_SIZE: usize = 36;
+/// The size of the header of a system description table.
+pub const HEADER_SIZE: usize = 36;
+
+/// The address space of a [`GenericAddress`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct AddressSpace(u8);
+
+impl AddressSpace {
+    pub const SYSTEM_MEMORY: Self = Self(0x00);
+    pub const SYSTEM_IO: Self = Self(0x01);
+    pub const PCI_CONFIG: Self = Self(0x02);
+    pub const EMBEDDED_CONTROLLER: Self = Self(0x03);
+    pub const SMBUS: Self = Self(0x04);
+    pub const SYSTEM_CMOS: Self = Self(0x05);
+    pub const PCI_BAR_TARGET: Self = Self(0x06);
+    pub const IPMI: Self = Self(0x07);
+    pub const GPIO: Self = Self(0x08);
+    pub const GENERIC_SERIAL_BUS: Self = Self(0x09);
+    pub const PCC: Self = Self(0x0A);
+    pub const PRM: Self = Self(0x0B);
+    pub const FUNCTIONAL_FIXED_HARDWARE: Self = Self(0x7F);
+}
+
+/// Generic Address Structure (GAS)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GenericAddress {
+    pub address_space: AddressSpace,
+    pub bit_width: u8,
+    pub bit_offset: u8,
+    pub access_size: u8,
+    pub address: u64,
+}
+
+impl GenericAddress {
+    /// The size of the structure.
+    pub const SIZE: usize = 12;
+
+    fn parse(data: &[u8], offset: usize) -> Option<Self> {
+        Some(Self {
+            address_space: AddressSpace(read_u8(data, offset)?),
+            bit_width: read_u8(data, offset + 1)?,
+            bit_offset: read_u8(data, offset + 2)?,
+            access_size: read_u8(data, offset + 3)?,
+            address: read_u64(data, offset + 4)?,
+        })
+    }
+}
+
+/// Root System Description Pointer (RSDP)
+#[derive(Clone, Copy, Debug)]
+pub struct Rsdp<'a>(&'a [u8]);
+
+impl<'a> Rsdp<'a> {
+    pub const SIGNATURE: &'static [u8; 8] = b"RSD PTR ";
+
+    /// Checks the signature and checksums of an RSDP.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The signature or length is not valid.
+    /// - `CRC_ERROR`: A checksum is not valid.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self> {
+        if data.len() < RSDP_V1_SIZE || &data[..8] != Self::SIGNATURE {
+            return Err(Status::INVALID_PARAMETER);
+        }
+        if !checksum_ok(&data[..RSDP_V1_SIZE]) {
+            return Err(Status::CRC_ERROR);
+        }
+
+        if data[15] < 2 {
+            return Ok(Self(&data[..RSDP_V1_SIZE]));
+        }
+
+        let len = read_u32(data, 20).ok_or(Status::INVALID_PARAMETER)? as usize;
+        if len < RSDP_SIZE || len > data.len() {
+            return Err(Status::INVALID_PARAMETER);
+        }
+        if !checksum_ok(&data[..len]) {
+            return Err(Status::CRC_ERROR);
+        }
+
+        Ok(Self(&data[..len]))
+    }
+
+    /// Reads and checks an RSDP.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The RSDP could not be read.
+    /// - The errors of [`Rsdp::from_bytes()`].
+    pub fn read<M: Memory + ?Sized>(mem: &'a M, addr: u64) -> Result<Self> {
+        let v1 = mem.read(addr, RSDP_V1_SIZE).ok_or(Status::NOT_FOUND)?;
+        if v1[15] < 2 {
+            return Self::from_bytes(v1);
+        }
+
+        let header = mem.read(addr, RSDP_SIZE).ok_or(Status::NOT_FOUND)?;
+        let len = read_u32(header, 20).unwrap_or_default() as usize;
+        let data = mem.read(addr, len.max(RSDP_SIZE)).ok_or(Status::NOT_FOUND)?;
+        Self::from_bytes(data)
+    }
+
+    /// The OEM that supplied the RSDP.
+    pub fn oem_id(&self) -> &'a [u8] {
+        &self.0[9..15]
+    }
+
+    /// The revision of the RSDP: 0 for ACPI 1.0, and 2 for ACPI 2.0 and later.
+    pub fn revision(&self) -> u8 {
+        self.0[15]
+    }
+
+    /// The physical address of the RSDT.
+    pub fn rsdt_address(&self) -> u32 {
+        read_u32(self.0, 16).unwrap_or_default()
+    }
+
+    /// The physical address of the XSDT, if the RSDP is for ACPI 2.0 or later.
+    pub fn xsdt_address(&self) -> Option<u64> {
+        read_u64(self.0, 24).filter(|&addr| addr != 0)
+    }
+}
+
+/// A system description table, starting with the common header.
+#[derive(Clone, Copy, Debug)]
+pub struct Sdt<'a>(&'a [u8]);
+
+impl<'a> Sdt<'a> {
+    /// Checks the length and checksum of a table.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The length of the table is not valid.
+    /// - `CRC_ERROR`: The checksum is not valid.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self> {
+        let len = read_u32(data, 4).ok_or(Status::INVALID_PARAMETER)? as usize;
+        if len < HEADER_SIZE || len > data.len() {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        let data = &data[..len];
+        if !checksum_ok(data) {
+            return Err(Status::CRC_ERROR);
+        }
+
+        Ok(Self(data))
+    }
+
+    /// Reads and checks a table.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The table could not be read.
+    /// - The errors of [`Sdt::from_bytes()`].
+    pub fn read<M: Memory + ?Sized>(mem: &'a M, addr: u64) -> Result<Self> {
+        let header = mem.read(addr, HEADER_SIZE).ok_or(Status::NOT_FOUND)?;
+        let len = read_u32(header, 4).unwrap_or_default() as usize;
+        let data = mem.read(addr, len.max(HEADER_SIZE)).ok_or(Status::NOT_FOUND)?;
+        Self::from_bytes(data)
+    }
+
+    pub fn signature(&self) -> [u8; 4] {
+        [self.0[0], self.0[1], self.0[2], self.0[3]]
+    }
+
+    /// The length of the table, including the header.
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn revision(&self) -> u8 {
+        self.0[8]
+    }
+
+    pub fn oem_id(&self) -> &'a [u8] {
+        &self.0[10..16]
+    }
+
+    pub fn oem_table_id(&self) -> &'a [u8] {
+        &self.0[16..24]
+    }
+
+    pub fn oem_revision(&self) -> u32 {
+        read_u32(self.0, 24).unwrap_or_default()
+    }
+
+    pub fn creator_id(&self) -> u32 {
+        read_u32(self.0, 28).unwrap_or_default()
+    }
+
+    pub fn creator_revision(&self) -> u32 {
+        read_u32(self.0, 32).unwrap_or_default()
+    }
+
+    /// The entire table, including the header.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// The contents of the table after the header. For the DSDT and SSDTs,
+    /// this is the AML definition block.
+    pub fn data(&self) -> &'a [u8] {
+        &self.0[HEADER_SIZE..]
+    }
+
+    /// Returns a typed view of the table, if it has the signature and minimum
+    /// length of `T`.
+    pub fn as_typed<T: Table<'a>>(&self) -> Option<T> {
+        if self.signature() != T::SIGNATURE {
+            return None;
+        }
+
+        T::from_sdt(*self)
+    }
+}
+
+/// A table with a typed view.
+pub trait Table<'a>: Sized {
+    /// The signature of the table.
+    const SIGNATURE: [u8; 4];
+
+    /// Creates a view of a table with the signature of the type.
+    ///
+    /// Returns `None` if the table is too short.
+    fn from_sdt(sdt: Sdt<'a>) -> Option<Self>;
+}
+
+/// The ACPI tables reachable from an RSDP.
+pub struct Acpi<'a, M: Memory + ?Sized> {
+    mem: &'a M,
+    rsdp: Rsdp<'a>,
+    root: Sdt<'a>,
+}
+
+impl<'a, M: Memory + ?Sized> Acpi<'a, M> {
+    pub const XSDT: [u8; 4] = *b"XSDT";
+    pub const RSDT: [u8; 4] = *b"RSDT";
+    pub const DSDT: [u8; 4] = *b"DSDT";
+    pub const SSDT: [u8; 4] = *b"SSDT";
+
+    /// Reads the RSDP at `rsdp_addr` and the XSDT, or the RSDT for ACPI 1.0.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: A table could not be read.
+    /// - `INVALID_PARAMETER`: A table is not valid, or the root table has the
+    ///   wrong signature.
+    /// - `CRC_ERROR`: The checksum of a table is not valid.
+    pub fn new(mem: &'a M, rsdp_addr: u64) -> Result<Self> {
+        let rsdp = Rsdp::read(mem, rsdp_addr)?;
+        let (addr, signature) = match rsdp.xsdt_address() {
+            Some(addr) => (addr, Self::XSDT),
+            None => (u64::from(rsdp.rsdt_address()), Self::RSDT),
+        };
+
+        let root = Sdt::read(mem, addr)?;
+        if root.signature() != signature {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        Ok(Self {
+            mem,
+            rsdp,
+            root,
+        })
+    }
+
+    pub fn rsdp(&self) -> &Rsdp<'a> {
+        &self.rsdp
+    }
+
+    /// The XSDT, or the RSDT for ACPI 1.0.
+    pub fn root(&self) -> &Sdt<'a> {
+        &self.root
+    }
+
+    /// Returns the physical addresses of the tables in the root table.
+    pub fn table_addresses(&self) -> impl Iterator<Item = u64> + 'a {
+        let xsdt = self.root.signature() == Self::XSDT;
+        let size = if xsdt {
+            8
+        } else {
+            4
+        };
+        self.root.data().chunks_exact(size).map(move |c| {
+            if xsdt {
+                read_u64(c, 0).unwrap_or_default()
+            } else {
+                u64::from(read_u32(c, 0).unwrap_or_default())
+            }
+        })
+    }
+
+    /// Returns the tables in the root table.
+    ///
+    /// Tables that cannot be read or are not valid are returned as errors.
+    pub fn tables(&self) -> impl Iterator<Item = Result<Sdt<'a>>> + '_ {
+        self.table_addresses().map(|addr| Sdt::read(self.mem, addr))
+    }
+
+    /// Returns the valid tables with a signature.
+    pub fn find_all(&self, signature: [u8; 4]) -> impl Iterator<Item = Sdt<'a>> + '_ {
+        self.tables().filter_map(Result::ok).filter(move |t| t.signature() == signature)
+    }
+
+    /// Returns the first valid table with a signature.
+    pub fn find(&self, signature: [u8; 4]) -> Option<Sdt<'a>> {
+        self.find_all(signature).next()
+    }
+
+    /// Returns a typed view of the first valid table of a type.
+    pub fn table<T: Table<'a>>(&self) -> Option<T> {
+        self.find(T::SIGNATURE)?.as_typed()
+    }
+
+    /// Returns the DSDT, located through the FADT.
+    pub fn dsdt(&self) -> Option<Sdt<'a>> {
+        let fadt = self.table::<fadt::Fadt>()?;
+        let dsdt = Sdt::read(self.mem, fadt.dsdt_address()?).ok()?;
+        (dsdt.signature() == Self::DSDT).then_some(dsdt)
+    }
+
+    /// Returns the SSDTs.
+    pub fn ssdts(&self) -> impl Iterator<Item = Sdt<'a>> + '_ {
+        self.find_all(Self::SSDT)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Writes a table with a valid checksum to the start of `buf`, and
+    /// returns it.
+    pub(super) fn sdt<'a>(
+        buf: &'a mut [u8],
+        signature: [u8; 4],
+        revision: u8,
+        data: &[u8],
+    ) -> &'a mut [u8] {
+        let len = HEADER_SIZE + data.len();
+        let table = &mut buf[..len];
+        table[..4].copy_from_slice(&signature);
+        table[4..8].copy_from_slice(&u32::try_from(len).unwrap().to_le_bytes());
+        table[8..10].copy_from_slice(&[revision, 0]);
+        table[10..16].copy_from_slice(b"YUFFIE");
+        table[16..24].copy_from_slice(b"TESTTABL");
+        table[24..28].copy_from_slice(&1u32.to_le_bytes());
+        table[28..32].copy_from_slice(b"YUFF");
+        table[32..36].copy_from_slice(&2u32.to_le_bytes());
+        table[HEADER_SIZE..].copy_from_slice(data);
+        table[9] = checksum(table);
+        table
+    }
+
+    fn checksum(data: &[u8]) -> u8 {
+        0u8.wrapping_sub(data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)))
+    }
+
+    /// An ACPI 2.0 RSDP.
+    fn rsdp(rsdt: u32, xsdt: u64) -> [u8; RSDP_SIZE] {
+        let mut data = [0; RSDP_SIZE];
+        data[..8].copy_from_slice(Rsdp::SIGNATURE);
+        data[9..15].copy_from_slice(b"YUFFIE");
+        data[15] = 2;
+        data[16..20].copy_from_slice(&rsdt.to_le_bytes());
+        data[20..24].copy_from_slice(&36u32.to_le_bytes());
+        data[24..32].copy_from_slice(&xsdt.to_le_bytes());
+        data[8] = checksum(&data[..20]);
+        data[32] = checksum(&data);
+        data
+    }
+
+    /// An ACPI 1.0 RSDP.
+    fn rsdp_v1(rsdt: u32) -> [u8; RSDP_V1_SIZE] {
+        let mut data = [0; RSDP_V1_SIZE];
+        data.copy_from_slice(&rsdp(rsdt, 0)[..RSDP_V1_SIZE]);
+        data[15] = 0;
+        data[8] = 0;
+        data[8] = checksum(&data);
+        data
+    }
+
+    /// Places tables in memory after an RSDP and XSDT, and returns the memory.
+    fn memory(tables: &[&[u8]]) -> [u8; 512] {
+        // RSDP at 0, XSDT at 64, tables from 256.
+        let mut mem = [0; 512];
+        let mut addrs = [0; 64];
+        let mut addr = 256;
+        for (i, table) in tables.iter().enumerate() {
+            addrs[i * 8..(i + 1) * 8].copy_from_slice(&(addr as u64).to_le_bytes());
+            mem[addr..addr + table.len()].copy_from_slice(table);
+            addr += table.len();
+        }
+
+        let mut xsdt = [0; 128];
+        let xsdt = sdt(&mut xsdt, *b"XSDT", 1, &addrs[..tables.len() * 8]);
+        mem[..RSDP_SIZE].copy_from_slice(&rsdp(0, 64));
+        mem[64..64 + xsdt.len()].copy_from_slice(xsdt);
+        mem
+    }
+
+    #[test]
+    fn rsdp_checksums() {
+        let data = rsdp(0x1000, 0x2000);
+        let rsdp = Rsdp::from_bytes(&data).unwrap();
+        assert_eq!(rsdp.revision(), 2);
+        assert_eq!(rsdp.oem_id(), b"YUFFIE");
+        assert_eq!(rsdp.rsdt_address(), 0x1000);
+        assert_eq!(rsdp.xsdt_address(), Some(0x2000));
+
+        let mut bad = data;
+        bad[30] = 1;
+        assert_eq!(Rsdp::from_bytes(&bad).unwrap_err(), Status::CRC_ERROR);
+
+        let v1 = rsdp_v1(0x1000);
+        let rsdp = Rsdp::from_bytes(&v1).unwrap();
+        assert_eq!(rsdp.rsdt_address(), 0x1000);
+        assert_eq!(rsdp.xsdt_address(), None);
+
+        assert_eq!(Rsdp::from_bytes(b"RSD PTR").unwrap_err(), Status::INVALID_PARAMETER);
+    }
+
+    #[test]
+    fn sdt_checksum() {
+        let mut buf = [0; 64];
+        let table = sdt(&mut buf, *b"TEST", 3, &[1, 2, 3]);
+        let sdt = Sdt::from_bytes(table).unwrap();
+        assert_eq!(sdt.signature(), *b"TEST");
+        assert_eq!(sdt.length(), 39);
+        assert_eq!(sdt.revision(), 3);
+        assert_eq!(sdt.oem_table_id(), b"TESTTABL");
+        assert_eq!(sdt.data(), [1, 2, 3]);
+
+        table[36] = 0;
+        assert_eq!(Sdt::from_bytes(table).unwrap_err(), Status::CRC_ERROR);
+        assert_eq!(Sdt::from_bytes(&table[..38]).unwrap_err(), Status::INVALID_PARAMETER);
+    }
+
+    #[test]
+    fn walk_xsdt() {
+        let mut bufs = [[0; 64]; 4];
+        let [a, b, c, d] = &mut bufs;
+        let bad = sdt(b, *b"BAD!", 1, &[]);
+        bad[9] ^= 1;
+
+        let mem = memory(&[
+            sdt(a, *b"SSDT", 2, &[0x10, 0x20]),
+            bad,
+            sdt(c, *b"SSDT", 2, &[0x30]),
+            sdt(d, *b"OEM1", 1, &[]),
+        ]);
+        let acpi = Acpi::new(mem.as_slice(), 0).unwrap();
+        assert_eq!(acpi.root().signature(), *b"XSDT");
+
+        assert_eq!(acpi.tables().count(), 4);
+        assert_eq!(acpi.tables().nth(1).unwrap().unwrap_err(), Status::CRC_ERROR);
+
+        let mut ssdts = acpi.ssdts().map(|t| t.data());
+        assert_eq!(ssdts.next(), Some(&[0x10, 0x20][..]));
+        assert_eq!(ssdts.next(), Some(&[0x30][..]));
+        assert_eq!(ssdts.next(), None);
+        assert!(acpi.find(*b"OEM1").is_some());
+        assert!(acpi.find(*b"NONE").is_none());
+        assert!(acpi.dsdt().is_none());
+    }
+
+    #[test]
+    fn walk_rsdt() {
+        let mut mem = [0; 256];
+        sdt(&mut mem[64..], *b"RSDT", 1, &128u32.to_le_bytes());
+        sdt(&mut mem[128..], *b"OEM1", 1, &[]);
+        mem[..RSDP_V1_SIZE].copy_from_slice(&rsdp_v1(64));
+
+        let acpi = Acpi::new(mem.as_slice(), 0).unwrap();
+        assert_eq!(acpi.root().signature(), *b"RSDT");
+        assert!(acpi.table_addresses().eq([128]));
+        assert!(acpi.find(*b"OEM1").is_some());
+    }
+}
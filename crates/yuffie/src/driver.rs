@@ -0,0 +1,514 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # UEFI Driver Model
+//!
+//! A driver implements [`UefiDriver`] and calls [`install()`] from its entry
+//! point. This installs the Driver Binding, Component Name 2, and Driver
+//! Supported EFI Version protocols on the image handle, and sets the unload
+//! function of the image to disconnect the driver from its controllers and
+//! uninstall them.
+//!
+//! ```ignore
+//! struct MyDriver;
+//!
+//! impl UefiDriver for MyDriver {
+//!     const NAME: &'static CStr16 = cstr16!("My Driver");
+//!
+//!     fn supported(&self, bs: &BootServices, ctrl: Handle, _: Option<&DevicePath>) -> Result<()> {
+//!         bs.test_protocol::<PciIo>(ctrl, ...)
+//!     }
+//!     // ...
+//! }
+//!
+//! #[entry]
+//! fn main(image: Handle, st: &mut SystemTable) -> Status {
+//!     match yuffie::driver::install(st.boot_services(), image, MyDriver) {
+//!         Ok(()) => Status::SUCCESS,
+//!         Err(e) => e,
+//!     }
+//! }
+//! ```
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 11.1: EFI Driver Binding Protocol
+//!   - 11.5: EFI Component Name Protocol
+//!   - 11.7: EFI Driver Supported EFI Version Protocol
+
+use alloc::boxed::Box;
+use core::ffi::CStr;
+use core::ptr;
+use core::sync::atomic::AtomicPtr;
+use core::sync::atomic::Ordering;
+
+use crate::prelude::*;
+use crate::proto::device_path::DevicePath;
+use crate::proto::device_path::DevicePathProtocol;
+use crate::proto::driver::component_name::ComponentName2;
+use crate::proto::driver::driver_binding::DriverBinding;
+use crate::proto::driver::driver_supported_version::DriverSupportedUefiVersion;
+use crate::proto::loaded_image::LoadedImage;
+use crate::table::BootServices;
+use crate::table::boot::LocateSearchType;
+
+/// The languages supported for component names, in RFC 4646 format.
+const LANGUAGES: &CStr = c"en";
+
+/// A driver that follows the UEFI Driver Model.
+///
+/// The functions are called by firmware from `ConnectController()` and
+/// `DisconnectController()`, which may be nested, so they take `&self`.
+/// State shared with event notification functions should use a
+/// [`TplMutex`](crate::sync::TplMutex).
+pub trait UefiDriver: 'static {
+    /// The name of the driver returned by `ComponentName2`.
+    const NAME: &'static CStr16;
+
+    /// The version of the driver, used to order drivers that support the same
+    /// controller.
+    const VERSION: u32 = 0x10;
+
+    /// The version of the UEFI specification the driver follows.
+    const UEFI_VERSION: u32 = (2 << 16) | 100;
+
+    /// Tests whether the driver supports the controller.
+    ///
+    /// `remaining` is the remaining device path of a child to create, if any.
+    ///
+    /// # Errors
+    ///
+    /// - `UNSUPPORTED`: The driver does not support the controller.
+    /// - `ALREADY_STARTED`: The controller is already managed by the driver.
+    /// - `ACCESS_DENIED`: The controller is managed by another driver.
+    fn supported(
+        &self,
+        bs: &BootServices,
+        controller: Handle,
+        remaining: Option<&DevicePath>,
+    ) -> Result<()>;
+
+    /// Starts managing the controller.
+    ///
+    /// # Errors
+    ///
+    /// - `DEVICE_ERROR`: The controller could not be started.
+    /// - `OUT_OF_RESOURCES`: The controller could not be started due to a lack
+    ///   of resources.
+    fn start(
+        &self,
+        bs: &BootServices,
+        controller: Handle,
+        remaining: Option<&DevicePath>,
+    ) -> Result<()>;
+
+    /// Stops managing the controller.
+    ///
+    /// If `children` is empty, the controller itself is stopped. Otherwise,
+    /// only the given child handles are stopped.
+    ///
+    /// # Errors
+    ///
+    /// - `DEVICE_ERROR`: The controller or a child could not be stopped.
+    fn stop(&self, bs: &BootServices, controller: Handle, children: &[Handle]) -> Result<()>;
+
+    /// Returns the name of a controller, or a child of it, managed by the
+    /// driver.
+    fn controller_name(&self, controller: Handle, child: Option<Handle>) -> Option<&CStr16> {
+        let _ = (controller, child);
+        None
+    }
+
+    /// Called when the image is unloaded, before the protocols are
+    /// uninstalled.
+    ///
+    /// # Errors
+    ///
+    /// Returning an error prevents the image from being unloaded.
+    fn unload(&self, bs: &BootServices) -> Result<()> {
+        let _ = bs;
+        Ok(())
+    }
+}
+
+/// The protocols installed for a driver, followed by the driver itself.
+///
+/// The driver binding must be the first field so the driver can be found from
+/// the pointer firmware passes to the thunks.
+#[repr(C)]
+struct DriverProtocols<D> {
+    binding: DriverBinding,
+    component_name: ComponentName2,
+    version: DriverSupportedUefiVersion,
+    /// The boot services the driver was installed with, passed to the driver
+    /// by the thunks.
+    bs: *const BootServices,
+    driver: D,
+}
+
+impl<D: UefiDriver> DriverProtocols<D> {
+    fn new(bs: &BootServices, image: Handle, driver: D) -> Self {
+        Self {
+            binding: DriverBinding {
+                Supported: supported::<D>,
+                Start: start::<D>,
+                Stop: stop::<D>,
+                Version: D::VERSION,
+                ImageHandle: image,
+                DriverBindingHandle: image,
+            },
+            component_name: ComponentName2 {
+                GetDriverName: driver_name::<D>,
+                GetControllerName: controller_name::<D>,
+                SupportedLanguages: LANGUAGES.as_ptr().cast(),
+            },
+            version: DriverSupportedUefiVersion::new(D::UEFI_VERSION),
+            bs,
+            driver,
+        }
+    }
+
+    /// The protocols to install, in order.
+    ///
+    /// The interfaces are derived from a pointer to the whole structure, so
+    /// the thunks can use them to access the other fields.
+    fn protocols(&self) -> [(&'static Guid, *const u8); 3] {
+        let this = ptr::from_ref(self);
+        unsafe {
+            [
+                (&DriverBinding::GUID, (&raw const (*this).binding).cast()),
+                (&ComponentName2::GUID, (&raw const (*this).component_name).cast()),
+                (&DriverSupportedUefiVersion::GUID, (&raw const (*this).version).cast()),
+            ]
+        }
+    }
+
+    fn boot_services(&self) -> &BootServices {
+        // Firmware only calls the driver while boot services are available.
+        unsafe { &*self.bs }
+    }
+
+    /// Recovers the binding from the interface pointer of a field.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to the field at `offset` in a `DriverProtocols<D>`.
+    unsafe fn from_field<'a, T>(ptr: *mut T, offset: usize) -> &'a Self {
+        unsafe { &*ptr.cast::<u8>().sub(offset).cast::<Self>() }
+    }
+}
+
+/// The Boot Services table passed to [`install()`], used by the unload
+/// function of the image, which is given only the image handle.
+static BOOT_SERVICES: AtomicPtr<BootServices> = AtomicPtr::new(ptr::null_mut());
+
+/// Installs the driver on its image handle.
+///
+/// # Errors
+///
+/// - The error from opening the loaded image protocol of `image`.
+/// - The error from [`BootServices::install_protocols()`].
+pub fn install<D: UefiDriver>(bs: &BootServices, image: Handle, driver: D) -> Result<()> {
+    let mut loaded_image = bs.handle_protocol::<LoadedImage>(image)?;
+    let binding = Box::into_raw(Box::new(DriverProtocols::new(bs, image, driver)));
+    let protocols = unsafe { (*binding).protocols() };
+
+    if let Err(e) = unsafe { bs.install_protocols(Some(image), &protocols) } {
+        drop(unsafe { Box::from_raw(binding) });
+        return Err(e);
+    }
+
+    BOOT_SERVICES.store(ptr::from_ref(bs).cast_mut(), Ordering::Release);
+    unsafe { loaded_image.as_mut() }.set_unload(Some(unload::<D>));
+    Ok(())
+}
+
+/// Converts a device path pointer that may be null.
+unsafe fn remaining<'a>(path: *const DevicePathProtocol) -> Option<&'a DevicePath> {
+    if path.is_null() {
+        None
+    } else {
+        Some(unsafe { DevicePath::from_ptr(path) })
+    }
+}
+
+fn status(result: Result<()>) -> Status {
+    match result {
+        Ok(()) => Status::SUCCESS,
+        Err(e) => e,
+    }
+}
+
+extern "efiapi" fn supported<D: UefiDriver>(
+    this: *mut DriverBinding,
+    controller: Handle,
+    path: *const DevicePathProtocol,
+) -> Status {
+    let binding = unsafe { DriverProtocols::<D>::from_field(this, 0) };
+    let bs = binding.boot_services();
+    status(binding.driver.supported(bs, controller, unsafe { remaining(path) }))
+}
+
+extern "efiapi" fn start<D: UefiDriver>(
+    this: *mut DriverBinding,
+    controller: Handle,
+    path: *const DevicePathProtocol,
+) -> Status {
+    let binding = unsafe { DriverProtocols::<D>::from_field(this, 0) };
+    let bs = binding.boot_services();
+    status(binding.driver.start(bs, controller, unsafe { remaining(path) }))
+}
+
+extern "efiapi" fn stop<D: UefiDriver>(
+    this: *mut DriverBinding,
+    controller: Handle,
+    count: usize,
+    children: *const Handle,
+) -> Status {
+    let binding = unsafe { DriverProtocols::<D>::from_field(this, 0) };
+    let bs = binding.boot_services();
+    let children = if children.is_null() || count == 0 {
+        &[]
+    } else {
+        unsafe { core::slice::from_raw_parts(children, count) }
+    };
+    status(binding.driver.stop(bs, controller, children))
+}
+
+/// Returns `SUCCESS` if `language` is one of [`LANGUAGES`].
+fn check_language(language: *const u8) -> Status {
+    if language.is_null() {
+        return Status::INVALID_PARAMETER;
+    }
+
+    let language = unsafe { CStr::from_ptr(language.cast()) };
+    if language == LANGUAGES {
+        Status::SUCCESS
+    } else {
+        Status::UNSUPPORTED
+    }
+}
+
+extern "efiapi" fn driver_name<D: UefiDriver>(
+    _this: *mut ComponentName2,
+    language: *const u8,
+    name: *mut *mut u16,
+) -> Status {
+    match check_language(language) {
+        Status::SUCCESS if !name.is_null() => {
+            unsafe { name.write(D::NAME.as_ptr().cast_mut()) };
+            Status::SUCCESS
+        }
+        Status::SUCCESS => Status::INVALID_PARAMETER,
+        e => e,
+    }
+}
+
+extern "efiapi" fn controller_name<D: UefiDriver>(
+    this: *mut ComponentName2,
+    controller: Handle,
+    child: Option<Handle>,
+    language: *const u8,
+    name: *mut *mut u16,
+) -> Status {
+    let offset = core::mem::offset_of!(DriverProtocols<D>, component_name);
+    let binding = unsafe { DriverProtocols::<D>::from_field(this, offset) };
+
+    match check_language(language) {
+        Status::SUCCESS if !name.is_null() => {
+            match binding.driver.controller_name(controller, child) {
+                Some(s) => {
+                    unsafe { name.write(s.as_ptr().cast_mut()) };
+                    Status::SUCCESS
+                }
+                None => Status::UNSUPPORTED,
+            }
+        }
+        Status::SUCCESS => Status::INVALID_PARAMETER,
+        e => e,
+    }
+}
+
+extern "efiapi" fn unload<D: UefiDriver>(image: Handle) -> Status {
+    let Some(bs) = (unsafe { BOOT_SERVICES.load(Ordering::Acquire).as_ref() }) else {
+        return Status::NOT_READY;
+    };
+    let binding = match bs.handle_protocol::<DriverBinding>(image) {
+        Ok(binding) => binding.cast::<DriverProtocols<D>>().as_ptr(),
+        Err(e) => return e,
+    };
+
+    if let Err(e) = unsafe { (*binding).driver.unload(bs) } {
+        return e;
+    }
+
+    // Stop managing all controllers before uninstalling the binding.
+    if let Ok(handles) = bs.locate_handle_buffer(LocateSearchType::ALL_HANDLES, None, None) {
+        for handle in handles.iter() {
+            let _ = bs.disconnect_controller(*handle, Some(image), None);
+        }
+    }
+
+    let protocols = unsafe { (*binding).protocols() };
+    if let Err(e) = unsafe { bs.uninstall_protocols(image, &protocols) } {
+        return e;
+    }
+
+    drop(unsafe { Box::from_raw(binding) });
+    Status::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use super::*;
+
+    fn handle(n: usize) -> Handle {
+        Handle(ptr::NonNull::new(ptr::without_provenance_mut(n)).unwrap())
+    }
+
+    fn controller() -> Handle {
+        handle(0x10)
+    }
+
+    /// The end node of a device path.
+    const END: [u8; 4] = [0x7F, 0xFF, 0x04, 0x00];
+
+    /// Records the calls made to it.
+    #[derive(Default)]
+    struct TestDriver {
+        started: Cell<Option<Handle>>,
+        stopped: Cell<usize>,
+    }
+
+    impl UefiDriver for TestDriver {
+        const NAME: &'static CStr16 = cstr16!("Test Driver");
+
+        fn supported(
+            &self,
+            _: &BootServices,
+            controller: Handle,
+            remaining: Option<&DevicePath>,
+        ) -> Result<()> {
+            if controller != self::controller() {
+                Err(Status::ACCESS_DENIED)
+            } else if remaining.is_some() {
+                Err(Status::UNSUPPORTED)
+            } else {
+                Ok(())
+            }
+        }
+
+        fn start(
+            &self,
+            _: &BootServices,
+            controller: Handle,
+            _: Option<&DevicePath>,
+        ) -> Result<()> {
+            self.started.set(Some(controller));
+            Ok(())
+        }
+
+        fn stop(&self, _: &BootServices, controller: Handle, children: &[Handle]) -> Result<()> {
+            if self.started.get() != Some(controller) {
+                return Err(Status::DEVICE_ERROR);
+            }
+            self.stopped.set(children.len());
+            Ok(())
+        }
+
+        fn controller_name(&self, controller: Handle, child: Option<Handle>) -> Option<&CStr16> {
+            (controller == self::controller() && child.is_none())
+                .then_some(cstr16!("Test Controller"))
+        }
+    }
+
+    /// Returns the interface pointers installed for `protocols`.
+    fn interfaces(
+        protocols: &DriverProtocols<TestDriver>,
+    ) -> (*mut DriverBinding, *mut ComponentName2) {
+        let [(_, binding), (_, name), _] = protocols.protocols();
+        (binding.cast_mut().cast(), name.cast_mut().cast())
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn layout() {
+        type Protocols = DriverProtocols<TestDriver>;
+        assert_eq!(core::mem::offset_of!(Protocols, binding), 0);
+        assert_eq!(core::mem::offset_of!(Protocols, component_name), 48);
+        assert_eq!(core::mem::offset_of!(Protocols, version), 72);
+        assert_eq!(size_of::<DriverSupportedUefiVersion>(), 8);
+
+        let bs = BootServices::unusable();
+        let protocols = DriverProtocols::new(&bs, controller(), TestDriver::default());
+        let addr = ptr::from_ref(&protocols).addr();
+        let offsets = protocols.protocols().map(|(_, interface)| interface.addr() - addr);
+        assert_eq!(offsets, [0, 48, 72]);
+    }
+
+    #[test]
+    fn binding() {
+        let bs = BootServices::unusable();
+        let image = handle(0x20);
+        let protocols = DriverProtocols::new(&bs, image, TestDriver::default());
+        let (this, _) = interfaces(&protocols);
+        assert_eq!(protocols.binding.Version, TestDriver::VERSION);
+        assert_eq!(protocols.binding.DriverBindingHandle, image);
+
+        let supported = protocols.binding.Supported;
+        assert_eq!(supported(this, controller(), ptr::null()), Status::SUCCESS);
+        assert_eq!(supported(this, controller(), END.as_ptr().cast()), Status::UNSUPPORTED);
+        assert_eq!(supported(this, image, ptr::null()), Status::ACCESS_DENIED);
+
+        let stop = protocols.binding.Stop;
+        assert_eq!(stop(this, controller(), 0, ptr::null()), Status::DEVICE_ERROR);
+
+        assert_eq!((protocols.binding.Start)(this, controller(), ptr::null()), Status::SUCCESS);
+        assert_eq!(protocols.driver.started.get(), Some(controller()));
+
+        let children = [image, image];
+        assert_eq!(stop(this, controller(), children.len(), children.as_ptr()), Status::SUCCESS);
+        assert_eq!(protocols.driver.stopped.get(), 2);
+        assert_eq!(stop(this, controller(), 0, ptr::null()), Status::SUCCESS);
+        assert_eq!(protocols.driver.stopped.get(), 0);
+    }
+
+    #[test]
+    fn component_name() {
+        let bs = BootServices::unusable();
+        let protocols = DriverProtocols::new(&bs, controller(), TestDriver::default());
+        let (_, this) = interfaces(&protocols);
+        let mut name = ptr::null_mut();
+
+        let driver_name = protocols.component_name.GetDriverName;
+        assert_eq!(driver_name(this, LANGUAGES.as_ptr().cast(), &mut name), Status::SUCCESS);
+        assert_eq!(unsafe { CStr16::from_ptr(name) }, Ok(TestDriver::NAME));
+        assert_eq!(driver_name(this, c"fr".as_ptr().cast(), &mut name), Status::UNSUPPORTED);
+        assert_eq!(driver_name(this, ptr::null(), &mut name), Status::INVALID_PARAMETER);
+        assert_eq!(
+            driver_name(this, LANGUAGES.as_ptr().cast(), ptr::null_mut()),
+            Status::INVALID_PARAMETER
+        );
+
+        let controller_name = protocols.component_name.GetControllerName;
+        let language = LANGUAGES.as_ptr().cast();
+        assert_eq!(controller_name(this, controller(), None, language, &mut name), Status::SUCCESS);
+        let name = unsafe { CStr16::from_ptr(name) }.unwrap();
+        assert_eq!(name, cstr16!("Test Controller"));
+
+        let mut name = ptr::null_mut();
+        assert_eq!(
+            controller_name(this, controller(), Some(controller()), language, &mut name),
+            Status::UNSUPPORTED
+        );
+        assert!(name.is_null());
+    }
+
+    #[test]
+    fn unload_not_installed() {
+        assert_eq!(unload::<TestDriver>(handle(0x20)), Status::NOT_READY);
+    }
+}
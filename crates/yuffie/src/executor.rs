@@ -0,0 +1,515 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Async Executor
+//!
+//! A single-threaded executor for running futures that wait on UEFI events.
+//!
+//! Futures that are not ready register the event they are waiting on with
+//! the executor. When the executor has no more work, it waits on all
+//! registered events with `WaitForEvent()` and wakes the future whose event
+//! was signaled.
+//!
+//! ```ignore
+//! let bs = system_table().boot_services();
+//! let stdin = system_table().stdin();
+//! let ex = Executor::new(bs);
+//!
+//! let choice = ex.block_on(async {
+//!     select(ex.sleep(Duration::from_secs(5)), ex.read_key(stdin)).await
+//! })?;
+//! ```
+
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::pin::pin;
+use core::ptr;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+use core::task::Context;
+use core::task::Poll;
+use core::task::Waker;
+use core::time::Duration;
+
+use crate::Tpl;
+use crate::prelude::*;
+use crate::proto::console::ptr::AbsolutePointer;
+use crate::proto::console::ptr::AbsolutePointerState;
+use crate::proto::console::ptr::SimplePointer;
+use crate::proto::console::ptr::SimplePointerState;
+use crate::proto::console::text::InputKey;
+use crate::proto::console::text::KeyData;
+use crate::proto::console::text::SimpleTextInput;
+use crate::proto::console::text::SimpleTextInputEx;
+use crate::table::BootServices;
+use crate::table::boot::Timer;
+
+/// The event services used by the [`Executor`].
+///
+/// This is implemented by [`BootServices`], and allows the executor to be
+/// driven by a fake source when testing.
+pub trait EventSource {
+    /// Creates a timer event with no notification function.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: The event could not be allocated.
+    fn create_timer(&self) -> Result<Event>;
+
+    /// Sets the timer of an event created by [`create_timer()`].
+    ///
+    /// [`create_timer()`]: Self::create_timer
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: `event` is not a timer event.
+    fn start_timer(&self, event: Event, timer: Timer) -> Result<()>;
+
+    /// Closes an event created by [`create_timer()`].
+    ///
+    /// [`create_timer()`]: Self::create_timer
+    fn close(&self, event: Event);
+
+    /// Checks whether an event is signaled, clearing the signaled state.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The event is of type `NOTIFY_SIGNAL`.
+    fn is_signaled(&self, event: Event) -> Result<bool>;
+
+    /// Waits for any of the events to be signaled, returning its index.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: An event is of type `NOTIFY_SIGNAL`.
+    /// - `UNSUPPORTED`: The current TPL is not `APPLICATION`.
+    fn wait_any(&self, events: &[Event]) -> Result<usize>;
+}
+
+impl EventSource for BootServices {
+    fn create_timer(&self) -> Result<Event> {
+        unsafe { self.create_event_raw(EventType::TIMER, Tpl::APPLICATION, None, ptr::null_mut()) }
+    }
+
+    fn start_timer(&self, event: Event, timer: Timer) -> Result<()> {
+        self.set_timer(event, timer.kind(), timer.time())
+    }
+
+    fn close(&self, event: Event) {
+        let _ = self.close_event(event);
+    }
+
+    fn is_signaled(&self, event: Event) -> Result<bool> {
+        match self.check_event(event) {
+            Ok(()) => Ok(true),
+            Err(Status::NOT_READY) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn wait_any(&self, events: &[Event]) -> Result<usize> {
+        self.wait_for_event(events)
+    }
+}
+
+/// Wakes the future run by [`Executor::block_on()`].
+struct WakeFlag(AtomicBool);
+
+impl Wake for WakeFlag {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+/// A single-threaded executor whose wakers are backed by events.
+pub struct Executor<'a, S: EventSource + ?Sized = BootServices> {
+    source: &'a S,
+    /// Events that pending futures are waiting on.
+    waiting: RefCell<Vec<(Event, Waker)>>,
+    /// Events returned by `WaitForEvent()`, which clears their signaled state.
+    signaled: RefCell<Vec<Event>>,
+}
+
+impl<'a, S: EventSource + ?Sized> Executor<'a, S> {
+    pub fn new(source: &'a S) -> Self {
+        Self {
+            source,
+            waiting: RefCell::new(Vec::new()),
+            signaled: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Runs a future to completion, waiting on events while it is pending.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_READY`: The future is pending without waiting on any event.
+    /// - The error from waiting on the events.
+    pub fn block_on<F: Future>(&self, future: F) -> Result<F::Output> {
+        let flag = Arc::new(WakeFlag(AtomicBool::new(true)));
+        let waker = Waker::from(flag.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            if flag.0.swap(false, Ordering::Acquire) {
+                self.waiting.borrow_mut().clear();
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    self.signaled.borrow_mut().clear();
+                    return Ok(output);
+                }
+            }
+
+            if flag.0.load(Ordering::Acquire) {
+                continue;
+            }
+
+            let waiting = core::mem::take(&mut *self.waiting.borrow_mut());
+            if waiting.is_empty() {
+                return Err(Status::NOT_READY);
+            }
+
+            let events: Vec<Event> = waiting.iter().map(|(event, _)| *event).collect();
+            let index = self.source.wait_any(&events)?;
+            self.signaled.borrow_mut().push(events[index]);
+            for (event, waker) in &waiting {
+                if *event == events[index] {
+                    waker.wake_by_ref();
+                }
+            }
+        }
+    }
+
+    /// Returns a future that completes when `event` is signaled.
+    ///
+    /// The event must not be of type `NOTIFY_SIGNAL`.
+    pub fn wait_event(&self, event: Event) -> EventFuture<'_, 'a, S> {
+        EventFuture {
+            executor: self,
+            event,
+        }
+    }
+
+    /// Returns a future that completes after `duration`.
+    pub fn sleep(&self, duration: Duration) -> Sleep<'_, 'a, S> {
+        Sleep {
+            executor: self,
+            duration,
+            timer: None,
+        }
+    }
+
+    /// Reads the next keystroke, waiting on `WaitForKey`.
+    ///
+    /// # Errors
+    ///
+    /// - `DEVICE_ERROR`: Hardware error.
+    /// - `UNSUPPORTED`: The device does not support reading keystrokes.
+    pub async fn read_key(&self, input: &mut SimpleTextInput) -> Result<InputKey> {
+        loop {
+            match input.read_key() {
+                Err(Status::NOT_READY) => self.wait_event(input.WaitForKey).await?,
+                result => return result,
+            }
+        }
+    }
+
+    /// Reads the next keystroke and its state, waiting on `WaitForKeyEx`.
+    ///
+    /// # Errors
+    ///
+    /// - `DEVICE_ERROR`: Hardware error.
+    /// - `UNSUPPORTED`: The device does not support reading keystrokes.
+    pub async fn read_key_ex(&self, input: &mut SimpleTextInputEx) -> Result<KeyData> {
+        loop {
+            match input.read_key() {
+                Err(Status::NOT_READY) => self.wait_event(input.WaitForKeyEx).await?,
+                result => return result,
+            }
+        }
+    }
+
+    /// Reads the next state change of a pointer, waiting on `WaitForInput`.
+    ///
+    /// # Errors
+    ///
+    /// - `DEVICE_ERROR`: Hardware error.
+    pub async fn pointer_state(&self, pointer: &mut SimplePointer) -> Result<SimplePointerState> {
+        loop {
+            match pointer.get_state() {
+                Err(Status::NOT_READY) => self.wait_event(pointer.WaitForInput).await?,
+                result => return result,
+            }
+        }
+    }
+
+    /// Reads the next state change of an absolute pointer, waiting on
+    /// `WaitForInput`.
+    ///
+    /// # Errors
+    ///
+    /// - `DEVICE_ERROR`: Hardware error.
+    pub async fn absolute_pointer_state(
+        &self,
+        pointer: &mut AbsolutePointer,
+    ) -> Result<AbsolutePointerState> {
+        loop {
+            match pointer.get_state() {
+                Err(Status::NOT_READY) => self.wait_event(pointer.WaitForInput).await?,
+                result => return result,
+            }
+        }
+    }
+
+    /// Checks if `event` was signaled, registering `waker` to be woken when
+    /// it is if not.
+    fn poll_event(&self, event: Event, waker: &Waker) -> Poll<Result<()>> {
+        let mut signaled = self.signaled.borrow_mut();
+        if let Some(index) = signaled.iter().position(|e| *e == event) {
+            signaled.swap_remove(index);
+            return Poll::Ready(Ok(()));
+        }
+
+        match self.source.is_signaled(event) {
+            Ok(true) => Poll::Ready(Ok(())),
+            Ok(false) => {
+                self.waiting.borrow_mut().push((event, waker.clone()));
+                Poll::Pending
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+/// A future that completes when an event is signaled.
+///
+/// Returned by [`Executor::wait_event()`].
+pub struct EventFuture<'e, 'a, S: EventSource + ?Sized> {
+    executor: &'e Executor<'a, S>,
+    event: Event,
+}
+
+impl<S: EventSource + ?Sized> Future for EventFuture<'_, '_, S> {
+    type Output = Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.executor.poll_event(self.event, cx.waker())
+    }
+}
+
+/// A future that completes after a duration.
+///
+/// The timer is started when first polled, and closed when dropped.
+///
+/// Returned by [`Executor::sleep()`].
+pub struct Sleep<'e, 'a, S: EventSource + ?Sized> {
+    executor: &'e Executor<'a, S>,
+    duration: Duration,
+    timer: Option<Event>,
+}
+
+impl<S: EventSource + ?Sized> Future for Sleep<'_, '_, S> {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let timer = if let Some(timer) = self.timer {
+            timer
+        } else {
+            let source = self.executor.source;
+            let timer = match source.create_timer() {
+                Ok(timer) => timer,
+                Err(e) => return Poll::Ready(Err(e)),
+            };
+            self.timer = Some(timer);
+            if let Err(e) = source.start_timer(timer, Timer::oneshot(self.duration)) {
+                return Poll::Ready(Err(e));
+            }
+            timer
+        };
+
+        self.executor.poll_event(timer, cx.waker())
+    }
+}
+
+impl<S: EventSource + ?Sized> Drop for Sleep<'_, '_, S> {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            self.executor.source.close(timer);
+        }
+    }
+}
+
+/// The output of [`select()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+/// Waits for either of two futures to complete.
+///
+/// If both are ready when polled, the first future is preferred. The other
+/// future is dropped.
+pub fn select<A: Future, B: Future>(a: A, b: B) -> Select<A, B> {
+    Select {
+        a,
+        b,
+    }
+}
+
+/// A future that completes when either of two futures completes.
+///
+/// Returned by [`select()`].
+pub struct Select<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: The fields are never moved out of the pinned `Select`.
+        let this = unsafe { self.get_unchecked_mut() };
+        let a = unsafe { Pin::new_unchecked(&mut this.a) };
+        if let Poll::Ready(output) = a.poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+
+        let b = unsafe { Pin::new_unchecked(&mut this.b) };
+        if let Poll::Ready(output) = b.poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+    use core::ptr::NonNull;
+
+    use super::*;
+
+    /// An event source with a simulated clock in 100ns units.
+    ///
+    /// Waiting advances the clock to the earliest deadline of the events
+    /// being waited on.
+    #[derive(Default)]
+    struct FakeSource {
+        now: Cell<u64>,
+        /// The deadline and signaled state of each event.
+        events: RefCell<Vec<(Option<u64>, bool)>>,
+    }
+
+    impl FakeSource {
+        fn create(&self) -> Event {
+            let mut events = self.events.borrow_mut();
+            events.push((None, false));
+            Event(NonNull::new(ptr::without_provenance_mut(events.len())).unwrap())
+        }
+
+        fn index(event: Event) -> usize {
+            event.0.as_ptr().addr() - 1
+        }
+
+        /// Signals `event` at `time`.
+        fn schedule(&self, event: Event, time: u64) {
+            self.events.borrow_mut()[Self::index(event)].0 = Some(time);
+        }
+    }
+
+    impl EventSource for FakeSource {
+        fn create_timer(&self) -> Result<Event> {
+            Ok(self.create())
+        }
+
+        fn start_timer(&self, event: Event, timer: Timer) -> Result<()> {
+            self.schedule(event, self.now.get() + timer.time());
+            Ok(())
+        }
+
+        fn close(&self, event: Event) {
+            self.events.borrow_mut()[Self::index(event)] = (None, false);
+        }
+
+        fn is_signaled(&self, event: Event) -> Result<bool> {
+            let signaled = &mut self.events.borrow_mut()[Self::index(event)].1;
+            Ok(core::mem::take(signaled))
+        }
+
+        fn wait_any(&self, events: &[Event]) -> Result<usize> {
+            let mut state = self.events.borrow_mut();
+            let next = events
+                .iter()
+                .enumerate()
+                .filter_map(|(i, e)| state[Self::index(*e)].0.map(|time| (time, i)))
+                .min();
+
+            let Some((time, i)) = next else {
+                return Err(Status::INVALID_PARAMETER);
+            };
+
+            self.now.set(self.now.get().max(time));
+            state[Self::index(events[i])] = (None, false);
+            Ok(i)
+        }
+    }
+
+    #[test]
+    fn sleep() {
+        let source = FakeSource::default();
+        let ex = Executor::new(&source);
+
+        ex.block_on(ex.sleep(Duration::from_millis(3))).unwrap().unwrap();
+        assert_eq!(source.now.get(), 30_000);
+    }
+
+    #[test]
+    fn select_earliest() {
+        let source = FakeSource::default();
+        let ex = Executor::new(&source);
+        let key = source.create();
+        source.schedule(key, 20_000_000);
+
+        let result = ex
+            .block_on(async { select(ex.sleep(Duration::from_secs(5)), ex.wait_event(key)).await });
+        assert_eq!(result, Ok(Either::Right(Ok(()))));
+        assert_eq!(source.now.get(), 20_000_000);
+    }
+
+    #[test]
+    fn countdown() {
+        let source = FakeSource::default();
+        let ex = Executor::new(&source);
+        let key = source.create();
+        source.schedule(key, 35_000_000);
+
+        let remaining = ex.block_on(async {
+            for remaining in (1..=5).rev() {
+                let tick = ex.sleep(Duration::from_secs(1));
+                if let Either::Right(_) = select(tick, ex.wait_event(key)).await {
+                    return remaining;
+                }
+            }
+            0
+        });
+        assert_eq!(remaining, Ok(2));
+    }
+
+    #[test]
+    fn pending_without_event() {
+        let source = FakeSource::default();
+        let ex = Executor::new(&source);
+        assert_eq!(ex.block_on(core::future::pending::<()>()), Err(Status::NOT_READY));
+    }
+}
@@ -3,47 +3,157 @@
 
 //! The memory allocator to use with Rust.
 
+use core::alloc::GlobalAlloc;
+use core::alloc::Layout;
+use core::ptr;
 use core::ptr::NonNull;
+use core::sync::atomic::AtomicU32;
+use core::sync::atomic::Ordering;
 
 use crate::mem::MemoryType;
 use crate::table::BootServices;
 
+#[cfg(not(test))]
 #[global_allocator]
 static ALLOCATOR: Allocator = Allocator;
 
 // XXX: Put in `lib.rs`?
-// TODO: Set to `None` on `ExitBootServices`
 /// The Boot Services table holds the functions for memory allocation.
+///
+/// Set to `None` once boot services have been exited.
 static mut BOOT_SERVICES: Option<NonNull<BootServices>> = None;
 
 /// Save a reference to the Boot Services table.
 pub(crate) fn init(bs: &mut BootServices) {
     unsafe {
-        BOOT_SERVICES = NonNull::new(core::ptr::from_mut(bs));
+        BOOT_SERVICES = NonNull::new(ptr::from_mut(bs));
+    }
+}
+
+/// Drop the reference to the Boot Services table after `ExitBootServices()`.
+///
+/// Allocations will fail, and deallocations will be ignored as all memory is
+/// now owned by the OS.
+pub(crate) fn exit_boot_services() {
+    unsafe {
+        BOOT_SERVICES = None;
     }
 }
 
 /// A convenience function to access the boot services table.
-unsafe fn boot_services() -> NonNull<BootServices> {
-    unsafe { BOOT_SERVICES.expect("boot services not available") }
+unsafe fn boot_services() -> Option<NonNull<BootServices>> {
+    unsafe { BOOT_SERVICES }
+}
+
+/// The memory type used for pool allocations. Defaults to `LOADER_DATA`.
+static MEMORY_TYPE: AtomicU32 = AtomicU32::new(MemoryType::LOADER_DATA.0);
+
+/// Set the memory type used for subsequent allocations.
+///
+/// Drivers that must keep their allocations after `ExitBootServices()` should
+/// use `RUNTIME_SERVICES_DATA`.
+pub fn set_memory_type(memory_type: MemoryType) {
+    MEMORY_TYPE.store(memory_type.into(), Ordering::Relaxed);
+}
+
+/// Returns the memory type used for allocations.
+pub fn memory_type() -> MemoryType {
+    MEMORY_TYPE.load(Ordering::Relaxed).into()
+}
+
+/// The alignment of allocations from `AllocatePool()`.
+const POOL_ALIGN: usize = 8;
+
+/// The size of the header storing the original pointer of an over-aligned
+/// allocation.
+const HEADER_SIZE: usize = core::mem::size_of::<*mut u8>();
+
+/// Returns the offset from the start of a pool allocation at `addr` to the
+/// over-aligned pointer returned to the caller.
+///
+/// The offset leaves room for a header storing the original pointer.
+const fn aligned_offset(addr: usize, align: usize) -> usize {
+    ((addr + HEADER_SIZE + align - 1) & !(align - 1)) - addr
 }
 
 pub struct Allocator;
 
-unsafe impl core::alloc::GlobalAlloc for Allocator {
-    unsafe fn alloc(&self, layout: core::alloc::Layout) -> *mut u8 {
-        // TODO: Allow other memory types
+unsafe impl GlobalAlloc for Allocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let Some(bs) = (unsafe { boot_services() }) else {
+            return ptr::null_mut();
+        };
+        let bs = unsafe { bs.as_ref() };
+
+        if layout.align() <= POOL_ALIGN {
+            return bs.allocate_pool(memory_type(), layout.size()).unwrap_or(ptr::null_mut());
+        }
+
+        // Allocate enough to align the pointer and store the original pointer
+        // before it.
+        let Some(size) = layout.size().checked_add(layout.align()) else {
+            return ptr::null_mut();
+        };
+        let Ok(raw) = bs.allocate_pool(memory_type(), size) else {
+            return ptr::null_mut();
+        };
+
         unsafe {
-            boot_services()
-                .as_ref()
-                .allocate_pool(MemoryType::LOADER_DATA, layout.size())
-                .expect("failed to allocate memory")
+            let aligned = raw.add(aligned_offset(raw as usize, layout.align()));
+            aligned.sub(HEADER_SIZE).cast::<*mut u8>().write_unaligned(raw);
+            aligned
         }
     }
 
-    unsafe fn dealloc(&self, ptr: *mut u8, _layout: core::alloc::Layout) {
-        unsafe {
-            boot_services().as_ref().free_pool(ptr).expect("failed to free memory");
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let Some(bs) = (unsafe { boot_services() }) else {
+            return;
+        };
+
+        let raw = if layout.align() <= POOL_ALIGN {
+            ptr
+        } else {
+            unsafe { ptr.sub(HEADER_SIZE).cast::<*mut u8>().read_unaligned() }
+        };
+
+        // `dealloc()` cannot report errors, and `FreePool()` only fails for
+        // pointers that were not allocated from pool.
+        let status = unsafe { bs.as_ref().free_pool(raw) };
+        debug_assert!(status.is_ok(), "failed to free memory");
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // The size of the pool allocation is not known, so only shrinking can
+        // be done in place.
+        if new_size <= layout.size() {
+            return ptr;
+        }
+
+        let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+        let new_ptr = unsafe { self.alloc(new_layout) };
+        if !new_ptr.is_null() {
+            unsafe {
+                ptr::copy_nonoverlapping(ptr, new_ptr, layout.size());
+                self.dealloc(ptr, layout);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn over_aligned_offset() {
+        for align in [16, 64, 4096] {
+            for addr in (0x1000..0x1000 + 2 * align).step_by(POOL_ALIGN) {
+                let offset = aligned_offset(addr, align);
+                assert_eq!((addr + offset) % align, 0);
+                assert!(offset >= HEADER_SIZE);
+                assert!(offset <= align);
+            }
         }
     }
 }
@@ -58,11 +58,27 @@ impl Guid {
             panic!("invalid GUID length");
         }
 
+        match Self::try_parse_str(literal) {
+            Some(guid) => guid,
+            None => panic!("invalid GUID format"),
+        }
+    }
+
+    /// Converts a string to a GUID.
+    ///
+    /// The string must be in the form "xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx".
+    /// Hex digits may be either upper case or lower case. Returns `None` if
+    /// the string is not in the standard form.
+    pub const fn try_parse_str(literal: &str) -> Option<Self> {
+        if literal.len() != HYPHENATED_LEN {
+            return None;
+        }
+
         let bytes = literal.as_bytes();
 
         // Check hyphens
         if bytes[8] != b'-' || bytes[13] != b'-' || bytes[18] != b'-' || bytes[23] != b'-' {
-            panic!("invalid GUID format");
+            return None;
         }
 
         let mut raw = [0u8; 16];
@@ -75,8 +91,9 @@ impl Guid {
                 i += 1;
             }
 
-            let hi = hex_to_u8(bytes[i]);
-            let lo = hex_to_u8(bytes[i + 1]);
+            let (Some(hi), Some(lo)) = (hex_to_u8(bytes[i]), hex_to_u8(bytes[i + 1])) else {
+                return None;
+            };
 
             let b = (hi << 4) | lo;
             raw[j] = b;
@@ -90,19 +107,42 @@ impl Guid {
         let d3 = u16::from_be_bytes([raw[6], raw[7]]);
         let d4 = [raw[8], raw[9], raw[10], raw[11], raw[12], raw[13], raw[14], raw[15]];
 
+        Some(Self(d1, d2, d3, d4))
+    }
+
+    /// Creates a GUID from its in-memory representation.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        let d1 = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let d2 = u16::from_le_bytes([bytes[4], bytes[5]]);
+        let d3 = u16::from_le_bytes([bytes[6], bytes[7]]);
+        let d4 =
+            [bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]];
+
         Self(d1, d2, d3, d4)
     }
+
+    /// Returns the in-memory representation of the GUID.
+    pub const fn to_bytes(self) -> [u8; 16] {
+        let d1 = self.0.to_le_bytes();
+        let d2 = self.1.to_le_bytes();
+        let d3 = self.2.to_le_bytes();
+        let d4 = self.3;
+
+        [
+            d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1], d4[0], d4[1], d4[2], d4[3],
+            d4[4], d4[5], d4[6], d4[7],
+        ]
+    }
 }
 
 /// Converts a hex character to its integer value.
-/// Panics on invalid values.
 #[doc(hidden)]
-const fn hex_to_u8(hex: u8) -> u8 {
+const fn hex_to_u8(hex: u8) -> Option<u8> {
     match hex {
-        b'0'..=b'9' => hex - b'0',
-        b'A'..=b'F' => hex - b'A' + 10,
-        b'a'..=b'f' => hex - b'a' + 10,
-        _ => panic!("invalid hex value in GUID"),
+        b'0'..=b'9' => Some(hex - b'0'),
+        b'A'..=b'F' => Some(hex - b'A' + 10),
+        b'a'..=b'f' => Some(hex - b'a' + 10),
+        _ => None,
     }
 }
 
@@ -182,7 +222,21 @@ mod test {
     #[test]
     fn endianness() {
         let guid = Guid::parse_str("01020304-0506-0708-090a-0b0c0d0e0f10");
-        let expected = Guid(0x01020304, 0x0506, 0x0708, [0x9, 0xA, 0xB, 0xC, 0xD, 0xE, 0xF, 0x10]);
+        let expected = Guid(0x0102_0304, 0x0506, 0x0708, [0x9, 0xA, 0xB, 0xC, 0xD, 0xE, 0xF, 0x10]);
         assert_eq!(guid, expected);
     }
+
+    #[test]
+    fn invalid_str() {
+        assert_eq!(Guid::try_parse_str("01020304-0506-0708-090a-0b0c0d0e0f1g"), None);
+        assert_eq!(Guid::try_parse_str("01020304-0506-0708-090a0b0c0d0e0f10"), None);
+    }
+
+    #[test]
+    fn bytes() {
+        let guid = Guid::parse_str("01020304-0506-0708-090a-0b0c0d0e0f10");
+        let bytes = [4, 3, 2, 1, 6, 5, 8, 7, 9, 10, 11, 12, 13, 14, 15, 16];
+        assert_eq!(guid.to_bytes(), bytes);
+        assert_eq!(Guid::from_bytes(bytes), guid);
+    }
 }
@@ -14,6 +14,7 @@
 //! [UEFI PI]: https://uefi.org/sites/default/files/resources/UEFI_PI_Spec_1_8_March3.pdf
 
 #![no_std]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -21,12 +22,27 @@ extern crate alloc;
 pub mod global_alloc;
 
 // Core
+pub mod acpi;
+mod bytes;
+#[cfg(feature = "alloc")]
+pub mod driver;
+#[cfg(feature = "alloc")]
+pub mod executor;
 pub mod guid;
 pub mod hii;
 pub mod mem;
+#[cfg(feature = "panic_handler")]
+pub mod panic;
 pub mod prelude;
+#[doc(hidden)]
+pub mod print;
+pub mod smbios;
 pub mod status;
+pub mod string;
+pub mod sync;
 pub mod table;
+#[cfg(feature = "alloc")]
+pub mod variable;
 
 // Protocols
 pub mod proto;
@@ -37,7 +53,9 @@ use prelude::*;
 
 // Ref: 4.1 UEFI Image Entry Point
 /// Function signature for the entry point of a UEFI application or driver.
-pub type ImageEntryFn = extern "efiapi" fn(Handle, &mut SystemTable) -> Status;
+///
+/// The system table is valid for the lifetime of the image.
+pub type ImageEntryFn = extern "efiapi" fn(Handle, &'static mut SystemTable) -> Status;
 
 /// Cached pointer to the system table (edk2: `gST`).
 static mut SYSTEM_TABLE: Option<ptr::NonNull<SystemTable>> = None;
@@ -51,7 +69,7 @@ pub fn system_table() -> &'static mut SystemTable {
 #[cfg(not(feature = "alloc"))]
 pub fn init(st: &mut SystemTable) {
     unsafe {
-        SYSTEM_TABLE = ptr::NonNull::new(st as *mut _);
+        SYSTEM_TABLE = ptr::NonNull::new(ptr::from_mut(st));
     }
 }
 
@@ -138,16 +156,3 @@ impl EventType {
     /// The event is notified when `SetVirtualAddressMap()` is performed.
     pub const SIGNAL_VIRTUAL_ADDRESS_CHANGE: Self = Self(0x6000_0202);
 }
-
-#[cfg(feature = "panic_handler")]
-#[doc(hidden)]
-#[panic_handler]
-fn panic(_info: &core::panic::PanicInfo) -> ! {
-    // TODO: Print panic info
-    loop {
-        unsafe {
-            // TODO: Handle other architectures
-            core::arch::asm!("hlt");
-        }
-    }
-}
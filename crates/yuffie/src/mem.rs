@@ -3,6 +3,20 @@
 
 //! Memory data types
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops;
+use core::ptr;
+use core::slice;
+
+use crate::prelude::*;
+use crate::table::BootServices;
+
+/// The size of a page of memory used by UEFI services.
+pub const PAGE_SIZE: usize = 4096;
+
 // 7.2.1 EFI_BOOT_SERVICES.AllocatePages()
 
 #[repr(transparent)]
@@ -13,8 +27,9 @@ impl AllocateType {
     pub const ADDRESS: Self = Self(2);
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
-pub struct MemoryType(u32);
+pub struct MemoryType(pub(crate) u32);
 
 impl MemoryType {
     pub const RESERVED: Self = Self(0);
@@ -22,18 +37,32 @@ impl MemoryType {
     pub const LOADER_DATA: Self = Self(2);
     pub const BOOTSERVICES_CODE: Self = Self(3);
     pub const BOOTSERVICES_DATA: Self = Self(4);
-    pub const CONVENTIONAL: Self = Self(5);
-    pub const UNUSABLE: Self = Self(6);
-    pub const ACPI_RECLAIM: Self = Self(7);
-    pub const ACPI_NVS: Self = Self(8);
-    pub const MEMORY_MAPPED_IO: Self = Self(9);
-    pub const MEMORY_MAPPED_IO_PORTSPACE: Self = Self(10);
-    pub const PAL_CODE: Self = Self(11);
-    pub const PERSISTENT: Self = Self(12);
-    pub const UNACCEPTED: Self = Self(13);
-}
-
-#[derive(Debug, Eq, PartialEq, PartialOrd)]
+    pub const RUNTIME_SERVICES_CODE: Self = Self(5);
+    pub const RUNTIME_SERVICES_DATA: Self = Self(6);
+    pub const CONVENTIONAL: Self = Self(7);
+    pub const UNUSABLE: Self = Self(8);
+    pub const ACPI_RECLAIM: Self = Self(9);
+    pub const ACPI_NVS: Self = Self(10);
+    pub const MEMORY_MAPPED_IO: Self = Self(11);
+    pub const MEMORY_MAPPED_IO_PORTSPACE: Self = Self(12);
+    pub const PAL_CODE: Self = Self(13);
+    pub const PERSISTENT: Self = Self(14);
+    pub const UNACCEPTED: Self = Self(15);
+}
+
+impl From<u32> for MemoryType {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<MemoryType> for u32 {
+    fn from(value: MemoryType) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd)]
 #[repr(transparent)]
 pub struct PhysicalAddress(u64);
 
@@ -43,7 +72,38 @@ impl From<u64> for PhysicalAddress {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, PartialOrd)]
+impl From<PhysicalAddress> for u64 {
+    fn from(addr: PhysicalAddress) -> Self {
+        addr.0
+    }
+}
+
+impl PhysicalAddress {
+    /// Returns a pointer to the address, which is identity mapped during boot
+    /// services.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the address does not fit in a pointer.
+    pub fn as_mut_ptr(self) -> *mut u8 {
+        let addr = usize::try_from(self.0).expect("address exceeds pointer width");
+        ptr::with_exposed_provenance_mut(addr)
+    }
+}
+
+impl<T> From<*mut T> for PhysicalAddress {
+    fn from(ptr: *mut T) -> Self {
+        Self(ptr.expose_provenance() as u64)
+    }
+}
+
+impl<T> From<*const T> for PhysicalAddress {
+    fn from(ptr: *const T) -> Self {
+        Self(ptr.expose_provenance() as u64)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd)]
 #[repr(transparent)]
 pub struct VirtualAddress(u64);
 
@@ -53,11 +113,562 @@ impl From<u64> for VirtualAddress {
     }
 }
 
+impl From<VirtualAddress> for u64 {
+    fn from(addr: VirtualAddress) -> Self {
+        addr.0
+    }
+}
+
+/// Read access to memory by physical address, used to parse firmware tables
+/// such as ACPI and SMBIOS.
+pub trait Memory {
+    /// Returns `len` bytes at physical address `addr`, or `None` if the range
+    /// cannot be accessed.
+    fn read(&self, addr: u64, len: usize) -> Option<&[u8]>;
+}
+
+/// A byte slice is memory starting at address 0.
+impl Memory for [u8] {
+    fn read(&self, addr: u64, len: usize) -> Option<&[u8]> {
+        let start = usize::try_from(addr).ok()?;
+        self.get(start..start.checked_add(len)?)
+    }
+}
+
+/// Identity-mapped physical memory, as during boot services.
+pub struct PhysicalMemory(());
+
+impl PhysicalMemory {
+    /// # Safety
+    ///
+    /// Physical memory must be identity mapped, and the memory read must
+    /// remain valid while it is accessed.
+    pub const unsafe fn new() -> Self {
+        Self(())
+    }
+}
+
+impl Memory for PhysicalMemory {
+    fn read(&self, addr: u64, len: usize) -> Option<&[u8]> {
+        let addr = usize::try_from(addr).ok().filter(|&a| a != 0)?;
+        addr.checked_add(len)?;
+        let ptr = ptr::with_exposed_provenance::<u8>(addr);
+        Some(unsafe { slice::from_raw_parts(ptr, len) })
+    }
+}
+
+/// `EFI_MEMORY_DESCRIPTOR`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(C)]
 pub struct MemoryDescriptor {
-    pub Type: u32,
+    pub Type: MemoryType,
     pub PhysicalStart: PhysicalAddress,
     pub VirtualStart: VirtualAddress,
     pub NumberOfPages: u64,
     pub Attribute: u64,
 }
+
+impl MemoryDescriptor {
+    /// The version of the memory descriptor this definition matches.
+    pub const VERSION: u32 = 1;
+
+    /// The physical address of the first byte after the memory region.
+    pub fn physical_end(&self) -> u64 {
+        self.PhysicalStart.0 + self.NumberOfPages * PAGE_SIZE as u64
+    }
+}
+
+/// Returns the number of pages needed to hold `size` bytes.
+pub const fn size_to_pages(size: usize) -> usize {
+    size.div_ceil(PAGE_SIZE)
+}
+
+/// An owned range of pages allocated with `AllocatePages()`.
+///
+/// The pages are zeroed when allocated, and freed when dropped.
+pub struct Pages<'a> {
+    bs: &'a BootServices,
+    addr: PhysicalAddress,
+    count: usize,
+}
+
+impl<'a> Pages<'a> {
+    /// Allocates `count` pages at any address.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: The pages could not be allocated.
+    /// - `INVALID_PARAMETER`: `mem_type` is invalid.
+    pub fn allocate(bs: &'a BootServices, mem_type: MemoryType, count: usize) -> Result<Self> {
+        Self::allocate_with(bs, AllocateType::ANY_PAGES, mem_type, count, PhysicalAddress(0))
+    }
+
+    /// Allocates `count` pages that end at or below `max`.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: The pages could not be allocated.
+    /// - `NOT_FOUND`: No range of pages below `max` is available.
+    /// - `INVALID_PARAMETER`: `mem_type` is invalid.
+    pub fn allocate_below(
+        bs: &'a BootServices,
+        mem_type: MemoryType,
+        count: usize,
+        max: PhysicalAddress,
+    ) -> Result<Self> {
+        Self::allocate_with(bs, AllocateType::MAX_ADDRESS, mem_type, count, max)
+    }
+
+    /// Allocates `count` pages starting at `addr`.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: The pages could not be allocated.
+    /// - `NOT_FOUND`: The requested pages are not available.
+    /// - `INVALID_PARAMETER`: `mem_type` is invalid or `addr` is not page
+    ///   aligned.
+    pub fn allocate_at(
+        bs: &'a BootServices,
+        mem_type: MemoryType,
+        count: usize,
+        addr: PhysicalAddress,
+    ) -> Result<Self> {
+        Self::allocate_with(bs, AllocateType::ADDRESS, mem_type, count, addr)
+    }
+
+    fn allocate_with(
+        bs: &'a BootServices,
+        alloc_type: AllocateType,
+        mem_type: MemoryType,
+        count: usize,
+        addr: PhysicalAddress,
+    ) -> Result<Self> {
+        let addr = bs.allocate_pages(alloc_type, mem_type, count, addr)?;
+        // `AllocatePages()` does not initialize the memory, but it is exposed
+        // as a byte slice.
+        unsafe { ptr::write_bytes(addr.as_mut_ptr(), 0, count * PAGE_SIZE) };
+        Ok(Self {
+            bs,
+            addr,
+            count,
+        })
+    }
+
+    /// Returns the physical address of the first page.
+    pub fn address(&self) -> PhysicalAddress {
+        self.addr
+    }
+
+    /// Returns the number of pages.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Consumes the pages without freeing them, returning the address and
+    /// number of pages.
+    pub fn leak(self) -> (PhysicalAddress, usize) {
+        let pages = core::mem::ManuallyDrop::new(self);
+        (pages.addr, pages.count)
+    }
+}
+
+impl ops::Deref for Pages<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { slice::from_raw_parts(self.addr.as_mut_ptr(), self.count * PAGE_SIZE) }
+    }
+}
+
+impl ops::DerefMut for Pages<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { slice::from_raw_parts_mut(self.addr.as_mut_ptr(), self.count * PAGE_SIZE) }
+    }
+}
+
+impl Drop for Pages<'_> {
+    fn drop(&mut self) {
+        let _ = self.bs.free_pages(self.addr, self.count);
+    }
+}
+
+/// An allocator that backs every allocation with whole pages.
+///
+/// Allocations are aligned to at most [`PAGE_SIZE`].
+#[cfg(feature = "allocator_api")]
+#[derive(Clone, Copy)]
+pub struct PageAllocator<'a> {
+    bs: &'a BootServices,
+    mem_type: MemoryType,
+}
+
+#[cfg(feature = "allocator_api")]
+impl<'a> PageAllocator<'a> {
+    /// Creates an allocator using pages of the given memory type.
+    pub fn new(bs: &'a BootServices, mem_type: MemoryType) -> Self {
+        Self {
+            bs,
+            mem_type,
+        }
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+unsafe impl core::alloc::Allocator for PageAllocator<'_> {
+    fn allocate(
+        &self,
+        layout: core::alloc::Layout,
+    ) -> core::result::Result<ptr::NonNull<[u8]>, core::alloc::AllocError> {
+        if layout.align() > PAGE_SIZE {
+            return Err(core::alloc::AllocError);
+        }
+
+        let count = size_to_pages(layout.size());
+        if count == 0 {
+            let dangling = ptr::without_provenance_mut(layout.align());
+            return Ok(ptr::NonNull::slice_from_raw_parts(
+                ptr::NonNull::new(dangling).ok_or(core::alloc::AllocError)?,
+                0,
+            ));
+        }
+
+        let pages =
+            Pages::allocate(self.bs, self.mem_type, count).map_err(|_| core::alloc::AllocError)?;
+        let (addr, count) = pages.leak();
+        let ptr = ptr::NonNull::new(addr.as_mut_ptr()).ok_or(core::alloc::AllocError)?;
+        Ok(ptr::NonNull::slice_from_raw_parts(ptr, count * PAGE_SIZE))
+    }
+
+    unsafe fn deallocate(&self, ptr: ptr::NonNull<u8>, layout: core::alloc::Layout) {
+        let count = size_to_pages(layout.size());
+        if count > 0 {
+            let _ = self.bs.free_pages(PhysicalAddress::from(ptr.as_ptr()), count);
+        }
+    }
+}
+
+/// A snapshot of the current memory map returned by `GetMemoryMap()`.
+///
+/// The size of each descriptor is reported by firmware and may be larger than
+/// [`MemoryDescriptor`], so entries must be accessed using the descriptor size
+/// as the stride.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct MemoryMap {
+    /// Backing storage for the descriptors, aligned to 8 bytes.
+    buffer: Vec<u64>,
+    /// The size of the memory map in bytes.
+    map_size: usize,
+    key: usize,
+    desc_size: usize,
+    desc_version: u32,
+}
+
+#[cfg(feature = "alloc")]
+impl MemoryMap {
+    /// Creates a memory map with no storage.
+    pub(crate) fn empty() -> Self {
+        Self {
+            buffer: Vec::new(),
+            map_size: 0,
+            key: 0,
+            desc_size: size_of::<MemoryDescriptor>(),
+            desc_version: MemoryDescriptor::VERSION,
+        }
+    }
+
+    /// Grows the buffer to hold `map_size` bytes, with room for additional
+    /// descriptors created by the allocation itself.
+    pub(crate) fn grow(&mut self) {
+        let size = self.map_size + 2 * self.desc_size;
+        self.buffer = alloc::vec![0; size.div_ceil(size_of::<u64>())];
+        self.map_size = self.capacity();
+    }
+
+    /// The size of the buffer in bytes.
+    pub(crate) fn capacity(&self) -> usize {
+        self.buffer.len() * size_of::<u64>()
+    }
+
+    /// Returns the raw parts to pass to `GetMemoryMap()`.
+    pub(crate) fn raw_parts_mut(
+        &mut self,
+    ) -> (&mut usize, *mut MemoryDescriptor, &mut usize, &mut usize, &mut u32) {
+        self.map_size = self.capacity();
+        (
+            &mut self.map_size,
+            self.buffer.as_mut_ptr().cast(),
+            &mut self.key,
+            &mut self.desc_size,
+            &mut self.desc_version,
+        )
+    }
+
+    /// The key for the current memory map.
+    pub fn key(&self) -> usize {
+        self.key
+    }
+
+    /// The size in bytes of each descriptor in the map.
+    pub fn descriptor_size(&self) -> usize {
+        self.desc_size
+    }
+
+    /// The version number associated with the descriptors in the map.
+    pub fn descriptor_version(&self) -> u32 {
+        self.desc_version
+    }
+
+    /// The size in bytes of the memory map.
+    pub fn size(&self) -> usize {
+        self.map_size
+    }
+
+    /// The number of descriptors in the map.
+    pub fn len(&self) -> usize {
+        if self.desc_size == 0 {
+            0
+        } else {
+            self.map_size / self.desc_size
+        }
+    }
+
+    /// Returns `true` if the map contains no descriptors.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the raw bytes of the memory map.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.buffer.as_ptr().cast(), self.map_size) }
+    }
+
+    /// Returns the descriptor at `index`.
+    pub fn get(&self, index: usize) -> Option<&MemoryDescriptor> {
+        if index < self.len() {
+            Some(unsafe { &*self.desc_ptr(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the descriptor at `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut MemoryDescriptor> {
+        if index < self.len() {
+            Some(unsafe { &mut *self.desc_ptr_mut(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the descriptors in the map.
+    pub fn iter(&self) -> MemoryMapIter<'_> {
+        MemoryMapIter {
+            map: self,
+            index: 0,
+        }
+    }
+
+    /// Sorts the descriptors by physical start address.
+    pub fn sort(&mut self) {
+        // Memory maps are small and typically already sorted.
+        for i in 1..self.len() {
+            let mut j = i;
+            while j > 0 && self.start_of(j - 1) > self.start_of(j) {
+                self.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Merges physically contiguous descriptors with the same type and
+    /// attributes. The map should be sorted first.
+    pub fn coalesce(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+
+        let mut last = 0;
+        for i in 1..self.len() {
+            let cur = unsafe { *self.desc_ptr(i) };
+            let prev = unsafe { &mut *self.desc_ptr_mut(last) };
+
+            if prev.Type == cur.Type
+                && prev.Attribute == cur.Attribute
+                && prev.physical_end() == cur.PhysicalStart.0
+            {
+                prev.NumberOfPages += cur.NumberOfPages;
+            } else {
+                last += 1;
+                if last != i {
+                    self.copy_within(i, last);
+                }
+            }
+        }
+
+        self.map_size = (last + 1) * self.desc_size;
+    }
+
+    /// Returns the total number of pages of the given type.
+    pub fn total_pages(&self, mem_type: MemoryType) -> u64 {
+        self.iter().filter(|d| d.Type == mem_type).map(|d| d.NumberOfPages).sum()
+    }
+
+    /// Returns the total number of pages for each type of memory in the map.
+    pub fn totals(&self) -> BTreeMap<MemoryType, u64> {
+        let mut totals = BTreeMap::new();
+        for desc in self {
+            *totals.entry(desc.Type).or_insert(0) += desc.NumberOfPages;
+        }
+        totals
+    }
+
+    fn desc_ptr(&self, index: usize) -> *const MemoryDescriptor {
+        let base: *const u8 = self.buffer.as_ptr().cast();
+        unsafe { base.add(index * self.desc_size).cast() }
+    }
+
+    fn desc_ptr_mut(&mut self, index: usize) -> *mut MemoryDescriptor {
+        let base: *mut u8 = self.buffer.as_mut_ptr().cast();
+        unsafe { base.add(index * self.desc_size).cast() }
+    }
+
+    fn start_of(&self, index: usize) -> u64 {
+        unsafe { (*self.desc_ptr(index)).PhysicalStart.0 }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        let size = self.desc_size;
+        let bytes: &mut [u8] = unsafe {
+            core::slice::from_raw_parts_mut(self.buffer.as_mut_ptr().cast(), self.capacity())
+        };
+        let (lo, hi) = bytes.split_at_mut(b * size);
+        lo[a * size..(a + 1) * size].swap_with_slice(&mut hi[..size]);
+    }
+
+    fn copy_within(&mut self, src: usize, dest: usize) {
+        let size = self.desc_size;
+        let bytes: &mut [u8] = unsafe {
+            core::slice::from_raw_parts_mut(self.buffer.as_mut_ptr().cast(), self.capacity())
+        };
+        bytes.copy_within(src * size..(src + 1) * size, dest * size);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> IntoIterator for &'a MemoryMap {
+    type Item = &'a MemoryDescriptor;
+    type IntoIter = MemoryMapIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the descriptors of a [`MemoryMap`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct MemoryMapIter<'a> {
+    map: &'a MemoryMap,
+    index: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for MemoryMapIter<'a> {
+    type Item = &'a MemoryDescriptor;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let desc = self.map.get(self.index)?;
+        self.index += 1;
+        Some(desc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.map.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ExactSizeIterator for MemoryMapIter<'_> {
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+
+    /// Firmware commonly reports a descriptor size of 48 bytes.
+    const DESC_SIZE: usize = 48;
+
+    fn map_from(descs: &[(MemoryType, u64, u64)]) -> MemoryMap {
+        let mut map = MemoryMap::empty();
+        map.desc_size = DESC_SIZE;
+        map.map_size = descs.len() * DESC_SIZE;
+        map.grow();
+        map.map_size = descs.len() * DESC_SIZE;
+
+        for (i, &(ty, start, pages)) in descs.iter().enumerate() {
+            *map.get_mut(i).unwrap() = MemoryDescriptor {
+                Type: ty,
+                PhysicalStart: PhysicalAddress(start),
+                VirtualStart: VirtualAddress(0),
+                NumberOfPages: pages,
+                Attribute: 0xF,
+            };
+        }
+
+        map
+    }
+
+    #[test]
+    fn iterate_by_stride() {
+        let map = map_from(&[
+            (MemoryType::CONVENTIONAL, 0x0000, 1),
+            (MemoryType::LOADER_DATA, 0x1000, 2),
+        ]);
+
+        assert_eq!(map.len(), 2);
+        let starts: Vec<u64> = map.iter().map(|d| d.PhysicalStart.0).collect();
+        assert_eq!(starts, [0x0000, 0x1000]);
+    }
+
+    #[test]
+    fn sort_and_coalesce() {
+        let mut map = map_from(&[
+            (MemoryType::CONVENTIONAL, 0x3000, 1),
+            (MemoryType::CONVENTIONAL, 0x0000, 1),
+            (MemoryType::LOADER_DATA, 0x4000, 2),
+            (MemoryType::CONVENTIONAL, 0x1000, 2),
+        ]);
+
+        map.sort();
+        map.coalesce();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(0).unwrap().NumberOfPages, 4);
+        assert_eq!(map.get(1).unwrap().Type, MemoryType::LOADER_DATA);
+        assert_eq!(map.size(), 2 * DESC_SIZE);
+    }
+
+    #[test]
+    fn totals_by_type() {
+        let map = map_from(&[
+            (MemoryType::CONVENTIONAL, 0x0000, 1),
+            (MemoryType::LOADER_DATA, 0x1000, 2),
+            (MemoryType::CONVENTIONAL, 0x8000, 3),
+        ]);
+
+        assert_eq!(map.total_pages(MemoryType::CONVENTIONAL), 4);
+        let totals = map.totals();
+        assert_eq!(totals[&MemoryType::LOADER_DATA], 2);
+        assert_eq!(totals.len(), 2);
+    }
+
+    #[test]
+    fn pages_for_size() {
+        assert_eq!(size_to_pages(0), 0);
+        assert_eq!(size_to_pages(1), 1);
+        assert_eq!(size_to_pages(PAGE_SIZE), 1);
+        assert_eq!(size_to_pages(PAGE_SIZE + 1), 2);
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Panic Handler
+//!
+//! The default panic handler writes the panic message and location to the
+//! standard error console, and optionally to the first serial device. It then
+//! performs the configured [`PanicAction`].
+//!
+//! ```ignore
+//! yuffie::panic::set_action(PanicAction::Exit(image));
+//! yuffie::panic::set_serial(true);
+//! ```
+
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+
+use crate::prelude::*;
+use crate::table::runtime::ResetType;
+
+/// The action to take after printing the panic information.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PanicAction {
+    /// Halt the processor.
+    #[default]
+    Halt,
+    /// Exit the image with `ABORTED`.
+    Exit(Handle),
+    /// Reset the system with `ABORTED`.
+    Reset(ResetType),
+}
+
+static mut ACTION: PanicAction = PanicAction::Halt;
+
+static SERIAL: AtomicBool = AtomicBool::new(false);
+
+/// Set when a panic is in progress to prevent recursion if printing the
+/// panic information also panics.
+#[cfg(not(test))]
+static PANICKING: AtomicBool = AtomicBool::new(false);
+
+/// Sets the action to take on panic.
+pub fn set_action(action: PanicAction) {
+    unsafe {
+        ACTION = action;
+    }
+}
+
+/// Returns the action to take on panic.
+pub fn action() -> PanicAction {
+    unsafe { ACTION }
+}
+
+/// Sets whether panic information is also written to serial.
+pub fn set_serial(enabled: bool) {
+    SERIAL.store(enabled, Ordering::Relaxed);
+}
+
+/// Halts the processor indefinitely.
+pub fn halt() -> ! {
+    loop {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        unsafe {
+            core::arch::asm!("hlt", options(nomem, nostack));
+        }
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+        unsafe {
+            core::arch::asm!("wfi", options(nomem, nostack));
+        }
+
+        #[cfg(target_arch = "riscv64")]
+        unsafe {
+            core::arch::asm!("wfi", options(nomem, nostack));
+        }
+
+        #[cfg(target_arch = "loongarch64")]
+        unsafe {
+            core::arch::asm!("idle 0", options(nomem, nostack));
+        }
+
+        #[cfg(not(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "riscv64",
+            target_arch = "loongarch64",
+        )))]
+        core::hint::spin_loop();
+    }
+}
+
+#[cfg(not(test))]
+#[panic_handler]
+fn panic(info: &core::panic::PanicInfo) -> ! {
+    use core::fmt::Write;
+
+    use crate::proto::console::serial::SerialIo;
+
+    let Some(mut st) = (unsafe { crate::SYSTEM_TABLE }) else {
+        halt();
+    };
+    let st = unsafe { st.as_mut() };
+
+    if !PANICKING.swap(true, Ordering::Relaxed) {
+        let print = |w: &mut dyn Write| match info.location() {
+            Some(loc) => write!(w, "\npanicked at {}:\n{}\n", loc, info.message()),
+            None => write!(w, "\npanicked:\n{}\n", info.message()),
+        };
+
+        let _ = print(st.stderr());
+
+        if SERIAL.load(Ordering::Relaxed) {
+            if let Ok(mut serial) = st.boot_services().locate_protocol::<SerialIo>() {
+                let _ = print(unsafe { serial.as_mut() });
+            }
+        }
+    }
+
+    match action() {
+        PanicAction::Halt => {}
+        PanicAction::Exit(image) => {
+            let _ = st.boot_services().exit(image, Status::ABORTED, None);
+        }
+        PanicAction::Reset(kind) => {
+            st.runtime_services().reset_system(kind, Status::ABORTED, None);
+        }
+    }
+
+    halt();
+}
@@ -9,8 +9,15 @@ pub use yuffie_proc_macros::entry;
 pub use crate::Event;
 pub use crate::EventType;
 pub use crate::Handle;
+pub use crate::cstr16;
+pub use crate::eprint;
+pub use crate::eprintln;
 pub use crate::guid;
 pub use crate::guid::Guid;
+pub use crate::print;
+pub use crate::println;
+pub use crate::proto::Protocol;
 pub use crate::status::Result;
 pub use crate::status::Status;
+pub use crate::string::CStr16;
 pub use crate::table::SystemTable;
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Console Printing
+//!
+//! Macros for formatted output to the console of the system table.
+
+use core::fmt;
+use core::fmt::Write;
+
+/// Prints to the standard output.
+///
+/// # Panics
+///
+/// Panics if the system table is not available or writing fails.
+#[macro_export]
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::print::_print(format_args!($($arg)*))
+    };
+}
+
+/// Prints to the standard output, with a newline.
+///
+/// # Panics
+///
+/// Panics if the system table is not available or writing fails.
+#[macro_export]
+macro_rules! println {
+    () => {
+        $crate::print!("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::print::_print(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+/// Prints to the standard error output.
+///
+/// # Panics
+///
+/// Panics if the system table is not available or writing fails.
+#[macro_export]
+macro_rules! eprint {
+    ($($arg:tt)*) => {
+        $crate::print::_eprint(format_args!($($arg)*))
+    };
+}
+
+/// Prints to the standard error output, with a newline.
+///
+/// # Panics
+///
+/// Panics if the system table is not available or writing fails.
+#[macro_export]
+macro_rules! eprintln {
+    () => {
+        $crate::eprint!("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::print::_eprint(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+#[doc(hidden)]
+pub fn _print(args: fmt::Arguments) {
+    crate::system_table().stdout().write_fmt(args).expect("failed printing to stdout");
+}
+
+#[doc(hidden)]
+pub fn _eprint(args: fmt::Arguments) {
+    crate::system_table().stderr().write_fmt(args).expect("failed printing to stderr");
+}
@@ -12,8 +12,8 @@ pub struct EdidDiscovered {
     pub Edid: *mut u8,
 }
 
-impl EdidDiscovered {
-    pub const GUID: Guid = guid!("1c0c34f6-d380-41fa-a049-8ad06c1a66aa");
+unsafe impl Protocol for EdidDiscovered {
+    const GUID: Guid = guid!("1c0c34f6-d380-41fa-a049-8ad06c1a66aa");
 }
 
 /// `EFI_EDID_ACTIVE_PROTOCOL`
@@ -23,8 +23,8 @@ pub struct EdidActive {
     pub Edid: *mut u8,
 }
 
-impl EdidActive {
-    pub const GUID: Guid = guid!("bd8c1056-9f36-44ec-92a8-a6337f817986");
+unsafe impl Protocol for EdidActive {
+    const GUID: Guid = guid!("bd8c1056-9f36-44ec-92a8-a6337f817986");
 }
 
 /// `EFI_EDID_OVERRIDE_PROTOCOL`
@@ -34,6 +34,6 @@ pub struct EdidOverride {
     pub GetEdid: extern "efiapi" fn(*mut Self, *const Handle, *mut u32, *mut usize, *mut *mut u8) -> Status,
 }
 
-impl EdidOverride {
-    pub const GUID: Guid = guid!("48ecb431-fb72-45c0-a922-f458fe040bd5");
+unsafe impl Protocol for EdidOverride {
+    const GUID: Guid = guid!("48ecb431-fb72-45c0-a922-f458fe040bd5");
 }
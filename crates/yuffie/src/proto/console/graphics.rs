@@ -84,9 +84,11 @@ pub struct GraphicsOutput {
     pub Mode: *mut GraphicsOutputMode,
 }
 
-impl GraphicsOutput {
-    pub const GUID: Guid = guid!("9042a9de-23dc-4a38-96fb-7aded080516a");
+unsafe impl Protocol for GraphicsOutput {
+    const GUID: Guid = guid!("9042a9de-23dc-4a38-96fb-7aded080516a");
+}
 
+impl GraphicsOutput {
     // TODO: QueryMode
 
     /// Set the video device into the specified mode and clears the visible
@@ -38,9 +38,11 @@ pub struct SimplePointer {
     pub Mode: *mut SimplePointerMode,
 }
 
-impl SimplePointer {
-    pub const GUID: Guid = guid!("31878c87-0b75-11d5-9a4f-0090273fc14d");
+unsafe impl Protocol for SimplePointer {
+    const GUID: Guid = guid!("31878c87-0b75-11d5-9a4f-0090273fc14d");
+}
 
+impl SimplePointer {
     /// Resets the pointer device hardware.
     ///
     /// ## Errors
@@ -100,9 +102,11 @@ pub struct AbsolutePointer {
     pub Mode: *mut AbsolutePointerMode,
 }
 
-impl AbsolutePointer {
-    pub const GUID: Guid = guid!("8d59d32b-c655-4ae9-9b15-f25904992a43");
+unsafe impl Protocol for AbsolutePointer {
+    const GUID: Guid = guid!("8d59d32b-c655-4ae9-9b15-f25904992a43");
+}
 
+impl AbsolutePointer {
     /// Resets the pointer device hardware.
     ///
     /// ## Errors
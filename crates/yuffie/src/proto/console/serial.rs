@@ -3,6 +3,7 @@
 
 //! Serial I/O protocols
 
+use core::fmt;
 use core::ops;
 
 use crate::prelude::*;
@@ -95,9 +96,11 @@ pub struct SerialIo {
     pub DeviceTypeGuid: *const Guid,
 }
 
-impl SerialIo {
-    pub const GUID: Guid = guid!("bb25cf6f-f1d4-11d2-9a0c-0090273fc1fd");
+unsafe impl Protocol for SerialIo {
+    const GUID: Guid = guid!("bb25cf6f-f1d4-11d2-9a0c-0090273fc1fd");
+}
 
+impl SerialIo {
     /// Resets the serial device.
     ///
     /// ## Errors
@@ -184,3 +187,29 @@ impl SerialIo {
         }
     }
 }
+
+impl fmt::Write for SerialIo {
+    /// Writes a UTF-8 string to the serial device, converting `\n` to `\r\n`.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.write_all(b"\r\n")?;
+            }
+            self.write_all(line.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl SerialIo {
+    /// Writes the entire buffer, retrying partial writes.
+    fn write_all(&mut self, mut buffer: &[u8]) -> fmt::Result {
+        while !buffer.is_empty() {
+            match self.write(buffer) {
+                Ok(0) | Err(_) => return Err(fmt::Error),
+                Ok(n) => buffer = &buffer[n..],
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,10 +3,14 @@
 
 //! Simple text protocols
 
+use core::fmt;
 use core::ptr;
 
 use crate::prelude::*;
 
+/// Number of UCS-2 characters converted at a time when formatting output.
+const WRITE_BUF_LEN: usize = 128;
+
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct KeyShiftState(u32);
@@ -69,9 +73,11 @@ pub struct SimpleTextInputEx {
     pub UnregisterKeyNotify: extern "efiapi" fn(*mut Self, *const u8) -> Status,
 }
 
-impl SimpleTextInputEx {
-    pub const GUID: Guid = guid!("dd9e7534-7762-4698-8c14-f58517a625aa");
+unsafe impl Protocol for SimpleTextInputEx {
+    const GUID: Guid = guid!("dd9e7534-7762-4698-8c14-f58517a625aa");
+}
 
+impl SimpleTextInputEx {
     //// Resets the input device hardware.
     ///
     /// ## Errors
@@ -157,9 +163,11 @@ pub struct SimpleTextInput {
     pub WaitForKey: Event,
 }
 
-impl SimpleTextInput {
-    pub const GUID: Guid = guid!("387477c1-69c7-11d2-8e39-00a0c969723b");
+unsafe impl Protocol for SimpleTextInput {
+    const GUID: Guid = guid!("387477c1-69c7-11d2-8e39-00a0c969723b");
+}
 
+impl SimpleTextInput {
     //// Resets the input device hardware.
     ///
     /// ## Errors
@@ -216,9 +224,57 @@ pub struct SimpleTextOutput {
     pub Mode: *mut SimpleTextOutputMode,
 }
 
-impl SimpleTextOutput {
-    pub const GUID: Guid = guid!("387477c2-69c7-11d2-8e39-00a0c969723b");
+unsafe impl Protocol for SimpleTextOutput {
+    const GUID: Guid = guid!("387477c2-69c7-11d2-8e39-00a0c969723b");
+}
+
+impl fmt::Write for SimpleTextOutput {
+    /// Writes a UTF-8 string to the output device, converting `\n` to `\r\n`.
+    ///
+    /// Characters outside of the BMP are written as `U+FFFD`.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        write_ucs2(s, |chunk| self.output_string(chunk)).map_err(|_| fmt::Error)
+    }
+}
+
+/// Converts a UTF-8 string to UCS-2 in a stack buffer, passing each filled
+/// chunk to `output`.
+fn write_ucs2(s: &str, mut output: impl FnMut(&CStr16) -> Result<()>) -> Result<()> {
+    let mut buf = [0u16; WRITE_BUF_LEN];
+    let mut len = 0;
+
+    for c in s.chars() {
+        // Leave room for a CR, the character, and the NUL terminator.
+        if len + 3 > buf.len() {
+            buf[len] = 0;
+            output(unsafe { CStr16::from_u16_with_nul_unchecked(&buf[..=len]) })?;
+            len = 0;
+        }
+
+        let c = match u16::try_from(u32::from(c)) {
+            Ok(0) => continue,
+            Ok(c) => c,
+            Err(_) => 0xFFFD,
+        };
+
+        if c == u16::from(b'\n') {
+            buf[len] = u16::from(b'\r');
+            len += 1;
+        }
+
+        buf[len] = c;
+        len += 1;
+    }
+
+    if len > 0 {
+        buf[len] = 0;
+        output(unsafe { CStr16::from_u16_with_nul_unchecked(&buf[..=len]) })?;
+    }
 
+    Ok(())
+}
+
+impl SimpleTextOutput {
     /// Resets the text output device hardware.
     ///
     /// ## Errors
@@ -235,8 +291,8 @@ impl SimpleTextOutput {
     /// - `DEVICE_ERROR`: Hardware error.
     /// - `UNSUPPORTED`: The output device's mode is not currently in a defined
     ///   text mode.
-    pub fn output_string(&mut self, string: *const u16) -> Result<()> {
-        (self.OutputString)(self, string).into()
+    pub fn output_string(&mut self, string: &CStr16) -> Result<()> {
+        (self.OutputString)(self, string.as_ptr()).into()
     }
 
     /// Verifies that all characters in a string can be output to the target
@@ -246,8 +302,8 @@ impl SimpleTextOutput {
     ///
     /// - `UNSUPPORTED`: Some of the characters in the string cannot be rendered
     ///   by one or more of the output devices mapped.
-    pub fn test_string(&mut self, string: *const u16) -> Result<()> {
-        (self.TestString)(self, string).into()
+    pub fn test_string(&mut self, string: &CStr16) -> Result<()> {
+        (self.TestString)(self, string.as_ptr()).into()
     }
 
     /// Returns information for an available text mode that the ouput supports.
@@ -318,3 +374,44 @@ impl SimpleTextOutput {
         (self.EnableCursor)(self, visible).into()
     }
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn convert(s: &str) -> (String, usize) {
+        let mut out = String::new();
+        let mut chunks = 0;
+        write_ucs2(s, |chunk| {
+            out.extend(chunk.chars());
+            chunks += 1;
+            Ok(())
+        })
+        .unwrap();
+        (out, chunks)
+    }
+
+    #[test]
+    fn newlines() {
+        assert_eq!(convert("a\nb\n"), (String::from("a\r\nb\r\n"), 1));
+        assert_eq!(convert(""), (String::new(), 0));
+    }
+
+    #[test]
+    fn non_bmp() {
+        assert_eq!(convert("x\u{1F600}y").0, "x\u{FFFD}y");
+    }
+
+    #[test]
+    fn chunks() {
+        let s: String = core::iter::repeat_n('\n', 200).collect();
+        let (out, chunks) = convert(&s);
+        assert_eq!(out.len(), 400);
+        assert!(chunks > 1);
+        let lines: Vec<_> = out.split("\r\n").collect();
+        assert_eq!(lines.len(), 201);
+    }
+}
@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # ACPI Device Path
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 10.3.3: ACPI Device Path
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::DevicePathType;
+use super::TypedNode;
+use crate::bytes::Reader;
+
+/// Sub-types of [`DevicePathType::ACPI`].
+pub struct AcpiSubType;
+
+impl AcpiSubType {
+    pub const ACPI: u8 = 0x01;
+    pub const EXPANDED: u8 = 0x02;
+    pub const ADR: u8 = 0x03;
+}
+
+/// Compressed EISA ID of the `PNP` vendor prefix.
+const PNP_VENDOR: u32 = 0x41D0;
+
+/// Creates a compressed EISA ID for a `PNP` device.
+pub const fn eisa_pnp_id(id: u16) -> u32 {
+    ((id as u32) << 16) | PNP_VENDOR
+}
+
+/// Converts a 7 character EISA ID string (e.g. "PNP0A03") to its compressed
+/// form.
+pub fn eisa_id_from_str(s: &str) -> Option<u32> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 7 || !bytes[..3].iter().all(|c| (b'@'..=b'Z').contains(c)) {
+        return None;
+    }
+
+    let vendor = bytes[..3].iter().fold(0u32, |acc, &c| (acc << 5) | u32::from(c - b'@'));
+    let product = u32::from_str_radix(&s[3..], 16).ok()?;
+    Some((product << 16) | vendor)
+}
+
+/// Writes a compressed EISA ID in its 7 character text form.
+fn write_eisa_id(f: &mut fmt::Formatter, id: u32) -> fmt::Result {
+    let vendor = |shift: u32| char::from(b'@' + ((id >> shift) & 0x1F) as u8);
+    write!(f, "{}{}{}{:04X}", vendor(10), vendor(5), vendor(0), id >> 16)
+}
+
+/// ACPI device path: `Acpi(HID,UID)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Acpi {
+    pub hid: u32,
+    pub uid: u32,
+}
+
+impl Acpi {
+    /// A PCI root bridge: `PciRoot(UID)`
+    pub const fn pci_root(uid: u32) -> Self {
+        Self {
+            hid: eisa_pnp_id(0x0A03),
+            uid,
+        }
+    }
+
+    /// A PCI Express root bridge: `PcieRoot(UID)`
+    pub const fn pcie_root(uid: u32) -> Self {
+        Self {
+            hid: eisa_pnp_id(0x0A08),
+            uid,
+        }
+    }
+}
+
+impl TypedNode for Acpi {
+    const TYPE: DevicePathType = DevicePathType::ACPI;
+    const SUB_TYPE: u8 = AcpiSubType::ACPI;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            hid: r.u32()?,
+            uid: r.u32()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.hid.to_le_bytes());
+        buf.extend_from_slice(&self.uid.to_le_bytes());
+    }
+}
+
+impl fmt::Display for Acpi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.hid & 0xFFFF == PNP_VENDOR {
+            let name = match self.hid >> 16 {
+                0x0A03 => Some("PciRoot"),
+                0x0A08 => Some("PcieRoot"),
+                0x0604 => Some("Floppy"),
+                0x0301 => Some("Keyboard"),
+                0x0501 => Some("Serial"),
+                0x0401 => Some("ParallelPort"),
+                _ => None,
+            };
+
+            if let Some(name) = name {
+                return write!(f, "{}({:#X})", name, self.uid);
+            }
+        }
+
+        f.write_str("Acpi(")?;
+        write_eisa_id(f, self.hid)?;
+        write!(f, ",{:#X})", self.uid)
+    }
+}
+
+/// Expanded ACPI device path: `AcpiEx(HID,CID,UID,HIDSTR,CIDSTR,UIDSTR)`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcpiExpanded {
+    pub hid: u32,
+    pub uid: u32,
+    pub cid: u32,
+    pub hid_str: String,
+    pub uid_str: String,
+    pub cid_str: String,
+}
+
+impl TypedNode for AcpiExpanded {
+    const TYPE: DevicePathType = DevicePathType::ACPI;
+    const SUB_TYPE: u8 = AcpiSubType::EXPANDED;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        let hid = r.u32()?;
+        let uid = r.u32()?;
+        let cid = r.u32()?;
+
+        let mut strings = r.rest().split(|&b| b == 0);
+        let mut next = || String::from_utf8(strings.next().unwrap_or_default().to_vec()).ok();
+        Some(Self {
+            hid,
+            uid,
+            cid,
+            hid_str: next()?,
+            uid_str: next()?,
+            cid_str: next()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.hid.to_le_bytes());
+        buf.extend_from_slice(&self.uid.to_le_bytes());
+        buf.extend_from_slice(&self.cid.to_le_bytes());
+        for s in [&self.hid_str, &self.uid_str, &self.cid_str] {
+            buf.extend_from_slice(s.as_bytes());
+            buf.push(0);
+        }
+    }
+}
+
+impl fmt::Display for AcpiExpanded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AcpiEx(")?;
+        write_eisa_id(f, self.hid)?;
+        f.write_str(",")?;
+        write_eisa_id(f, self.cid)?;
+        write!(f, ",{:#X},{},{},{})", self.uid, self.hid_str, self.cid_str, self.uid_str)
+    }
+}
+
+/// ACPI `_ADR` device path: `AcpiAdr(DisplayDevice[,DisplayDevice...])`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcpiAdr {
+    pub adr: Vec<u32>,
+}
+
+impl TypedNode for AcpiAdr {
+    const TYPE: DevicePathType = DevicePathType::ACPI;
+    const SUB_TYPE: u8 = AcpiSubType::ADR;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        if data.is_empty() || data.len() % 4 != 0 {
+            return None;
+        }
+
+        let adr = data.chunks_exact(4).map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]));
+        Some(Self {
+            adr: adr.collect(),
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        for adr in &self.adr {
+            buf.extend_from_slice(&adr.to_le_bytes());
+        }
+    }
+}
+
+impl fmt::Display for AcpiAdr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AcpiAdr(")?;
+        for (i, adr) in self.adr.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{:#X}", adr)?;
+        }
+        f.write_str(")")
+    }
+}
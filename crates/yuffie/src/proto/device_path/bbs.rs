@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # BIOS Boot Specification Device Path
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 10.3.7: BIOS Boot Specification Device Path
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::DevicePathType;
+use super::TypedNode;
+use crate::bytes::Reader;
+
+/// Sub-types of [`DevicePathType::BBS`].
+pub struct BbsSubType;
+
+impl BbsSubType {
+    pub const BBS: u8 = 0x01;
+}
+
+/// BIOS Boot Specification device path: `BBS(Type,Description,Flags)`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bbs {
+    pub device_type: u16,
+    pub status_flag: u16,
+    /// ASCII description of the device.
+    pub description: String,
+}
+
+impl Bbs {
+    pub const TYPE_FLOPPY: u16 = 0x01;
+    pub const TYPE_HARDDRIVE: u16 = 0x02;
+    pub const TYPE_CDROM: u16 = 0x03;
+    pub const TYPE_PCMCIA: u16 = 0x04;
+    pub const TYPE_USB: u16 = 0x05;
+    pub const TYPE_EMBEDDED_NETWORK: u16 = 0x06;
+
+    pub(super) const TYPE_NAMES: [&str; 6] = ["Floppy", "HD", "CDROM", "PCMCIA", "USB", "Network"];
+}
+
+impl TypedNode for Bbs {
+    const TYPE: DevicePathType = DevicePathType::BBS;
+    const SUB_TYPE: u8 = BbsSubType::BBS;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        let device_type = r.u16()?;
+        let status_flag = r.u16()?;
+        let description = r.rest().split(|&b| b == 0).next().unwrap_or_default();
+        let description = String::from_utf8(description.to_vec()).ok()?;
+        Some(Self {
+            device_type,
+            status_flag,
+            description,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.device_type.to_le_bytes());
+        buf.extend_from_slice(&self.status_flag.to_le_bytes());
+        buf.extend_from_slice(self.description.as_bytes());
+        buf.push(0);
+    }
+}
+
+impl fmt::Display for Bbs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name =
+            usize::from(self.device_type).checked_sub(1).and_then(|i| Self::TYPE_NAMES.get(i));
+
+        f.write_str("BBS(")?;
+        match name {
+            Some(name) => f.write_str(name)?,
+            None => write!(f, "{:#X}", self.device_type)?,
+        }
+        write!(f, ",{},{:#X})", self.description, self.status_flag)
+    }
+}
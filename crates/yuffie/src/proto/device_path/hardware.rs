@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Hardware Device Path
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 10.3.2: Hardware Device Path
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::DevicePathType;
+use super::TypedNode;
+use super::text::write_hex;
+use crate::bytes::Reader;
+use crate::prelude::*;
+
+/// Sub-types of [`DevicePathType::HARDWARE`].
+pub struct HardwareSubType;
+
+impl HardwareSubType {
+    pub const PCI: u8 = 0x01;
+    pub const PCCARD: u8 = 0x02;
+    pub const MEMORY_MAPPED: u8 = 0x03;
+    pub const VENDOR: u8 = 0x04;
+    pub const CONTROLLER: u8 = 0x05;
+    pub const BMC: u8 = 0x06;
+}
+
+/// PCI device path: `Pci(Device,Function)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pci {
+    pub device: u8,
+    pub function: u8,
+}
+
+impl TypedNode for Pci {
+    const TYPE: DevicePathType = DevicePathType::HARDWARE;
+    const SUB_TYPE: u8 = HardwareSubType::PCI;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        let function = r.u8()?;
+        let device = r.u8()?;
+        Some(Self {
+            device,
+            function,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&[self.function, self.device]);
+    }
+}
+
+impl fmt::Display for Pci {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pci({:#X},{:#X})", self.device, self.function)
+    }
+}
+
+/// PCCARD device path: `PcCard(Function)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PcCard {
+    pub function: u8,
+}
+
+impl TypedNode for PcCard {
+    const TYPE: DevicePathType = DevicePathType::HARDWARE;
+    const SUB_TYPE: u8 = HardwareSubType::PCCARD;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            function: Reader::new(data).u8()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.push(self.function);
+    }
+}
+
+impl fmt::Display for PcCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PcCard({:#X})", self.function)
+    }
+}
+
+/// Memory mapped device path:
+/// `MemoryMapped(EfiMemoryType,StartAddress,EndAddress)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryMapped {
+    pub memory_type: u32,
+    pub start: u64,
+    pub end: u64,
+}
+
+impl TypedNode for MemoryMapped {
+    const TYPE: DevicePathType = DevicePathType::HARDWARE;
+    const SUB_TYPE: u8 = HardwareSubType::MEMORY_MAPPED;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            memory_type: r.u32()?,
+            start: r.u64()?,
+            end: r.u64()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.memory_type.to_le_bytes());
+        buf.extend_from_slice(&self.start.to_le_bytes());
+        buf.extend_from_slice(&self.end.to_le_bytes());
+    }
+}
+
+impl fmt::Display for MemoryMapped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MemoryMapped({:#X},{:#X},{:#X})", self.memory_type, self.start, self.end)
+    }
+}
+
+/// Vendor-defined hardware device path: `VenHw(Guid,Data)`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VendorHardware {
+    pub guid: Guid,
+    pub data: Vec<u8>,
+}
+
+impl TypedNode for VendorHardware {
+    const TYPE: DevicePathType = DevicePathType::HARDWARE;
+    const SUB_TYPE: u8 = HardwareSubType::VENDOR;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            guid: r.guid()?,
+            data: r.rest().to_vec(),
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.guid.to_bytes());
+        buf.extend_from_slice(&self.data);
+    }
+}
+
+impl fmt::Display for VendorHardware {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VenHw({:X}", self.guid)?;
+        if !self.data.is_empty() {
+            f.write_str(",")?;
+            write_hex(f, &self.data)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Controller device path: `Ctrl(Controller)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Controller {
+    pub controller: u32,
+}
+
+impl TypedNode for Controller {
+    const TYPE: DevicePathType = DevicePathType::HARDWARE;
+    const SUB_TYPE: u8 = HardwareSubType::CONTROLLER;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            controller: Reader::new(data).u32()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.controller.to_le_bytes());
+    }
+}
+
+impl fmt::Display for Controller {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ctrl({:#X})", self.controller)
+    }
+}
+
+/// Baseboard management controller device path: `BMC(Type,Address)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bmc {
+    pub interface_type: u8,
+    pub base_address: u64,
+}
+
+impl TypedNode for Bmc {
+    const TYPE: DevicePathType = DevicePathType::HARDWARE;
+    const SUB_TYPE: u8 = HardwareSubType::BMC;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            interface_type: r.u8()?,
+            base_address: r.u64()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.push(self.interface_type);
+        buf.extend_from_slice(&self.base_address.to_le_bytes());
+    }
+}
+
+impl fmt::Display for Bmc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BMC({:#X},{:#X})", self.interface_type, self.base_address)
+    }
+}
@@ -0,0 +1,355 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Media Device Path
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 10.3.5: Media Device Path
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::DevicePathType;
+use super::TypedNode;
+use super::text::write_hex;
+use crate::bytes::Reader;
+use crate::prelude::*;
+
+/// Sub-types of [`DevicePathType::MEDIA`].
+pub struct MediaSubType;
+
+impl MediaSubType {
+    pub const HARD_DRIVE: u8 = 0x01;
+    pub const CDROM: u8 = 0x02;
+    pub const VENDOR: u8 = 0x03;
+    pub const FILE_PATH: u8 = 0x04;
+    pub const MEDIA_PROTOCOL: u8 = 0x05;
+    pub const PIWG_FIRMWARE_FILE: u8 = 0x06;
+    pub const PIWG_FIRMWARE_VOLUME: u8 = 0x07;
+    pub const RELATIVE_OFFSET_RANGE: u8 = 0x08;
+    pub const RAM_DISK: u8 = 0x09;
+}
+
+/// The signature of a partition on a hard drive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PartitionSignature {
+    None,
+    /// 32-bit signature from offset 0x1B8 of an MBR.
+    Mbr(u32),
+    /// The unique partition GUID from a GPT partition entry.
+    Gpt(Guid),
+}
+
+/// Hard drive partition device path: `HD(Partition,Type,Signature,Start,Size)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HardDrive {
+    pub partition_number: u32,
+    /// Starting LBA of the partition.
+    pub partition_start: u64,
+    /// Size of the partition in logical blocks.
+    pub partition_size: u64,
+    pub signature: PartitionSignature,
+    /// Partition format: 1 for a PC-AT MBR, 2 for a GPT.
+    pub mbr_type: u8,
+}
+
+impl HardDrive {
+    pub const MBR_TYPE_PCAT: u8 = 0x01;
+    pub const MBR_TYPE_GPT: u8 = 0x02;
+}
+
+impl TypedNode for HardDrive {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::HARD_DRIVE;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        let partition_number = r.u32()?;
+        let partition_start = r.u64()?;
+        let partition_size = r.u64()?;
+        let signature: [u8; 16] = r.array()?;
+        let mbr_type = r.u8()?;
+
+        let signature = match r.u8()? {
+            0 => PartitionSignature::None,
+            1 => PartitionSignature::Mbr(u32::from_le_bytes([
+                signature[0],
+                signature[1],
+                signature[2],
+                signature[3],
+            ])),
+            2 => PartitionSignature::Gpt(Guid::from_bytes(signature)),
+            _ => return None,
+        };
+
+        Some(Self {
+            partition_number,
+            partition_start,
+            partition_size,
+            signature,
+            mbr_type,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.partition_number.to_le_bytes());
+        buf.extend_from_slice(&self.partition_start.to_le_bytes());
+        buf.extend_from_slice(&self.partition_size.to_le_bytes());
+
+        let (signature, signature_type) = match self.signature {
+            PartitionSignature::None => ([0; 16], 0),
+            PartitionSignature::Mbr(sig) => {
+                let mut bytes = [0; 16];
+                bytes[..4].copy_from_slice(&sig.to_le_bytes());
+                (bytes, 1)
+            }
+            PartitionSignature::Gpt(guid) => (guid.to_bytes(), 2),
+        };
+
+        buf.extend_from_slice(&signature);
+        buf.extend_from_slice(&[self.mbr_type, signature_type]);
+    }
+}
+
+impl fmt::Display for HardDrive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HD({},", self.partition_number)?;
+        match self.signature {
+            PartitionSignature::None => f.write_str("0,0")?,
+            PartitionSignature::Mbr(sig) => write!(f, "MBR,{:#010X}", sig)?,
+            PartitionSignature::Gpt(guid) => write!(f, "GPT,{:X}", guid)?,
+        }
+        write!(f, ",{:#X},{:#X})", self.partition_start, self.partition_size)
+    }
+}
+
+/// CD-ROM "El Torito" boot entry device path: `CDROM(Entry,Start,Size)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CdRom {
+    pub boot_entry: u32,
+    /// Starting RBA of the partition.
+    pub partition_start: u64,
+    /// Size of the partition in sectors.
+    pub partition_size: u64,
+}
+
+impl TypedNode for CdRom {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::CDROM;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            boot_entry: r.u32()?,
+            partition_start: r.u64()?,
+            partition_size: r.u64()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.boot_entry.to_le_bytes());
+        buf.extend_from_slice(&self.partition_start.to_le_bytes());
+        buf.extend_from_slice(&self.partition_size.to_le_bytes());
+    }
+}
+
+impl fmt::Display for CdRom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CDROM({:#X},{:#X},{:#X})",
+            self.boot_entry, self.partition_start, self.partition_size
+        )
+    }
+}
+
+/// Vendor-defined media device path: `VenMedia(Guid,Data)`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VendorMedia {
+    pub guid: Guid,
+    pub data: Vec<u8>,
+}
+
+impl TypedNode for VendorMedia {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::VENDOR;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            guid: r.guid()?,
+            data: r.rest().to_vec(),
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.guid.to_bytes());
+        buf.extend_from_slice(&self.data);
+    }
+}
+
+impl fmt::Display for VendorMedia {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VenMedia({:X}", self.guid)?;
+        if !self.data.is_empty() {
+            f.write_str(",")?;
+            write_hex(f, &self.data)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// File path media device path.
+///
+/// The text representation is the path itself.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FilePath {
+    pub path: String,
+}
+
+impl TypedNode for FilePath {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::FILE_PATH;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        if data.len() % 2 != 0 {
+            return None;
+        }
+
+        let units =
+            data.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).take_while(|&c| c != 0);
+        let path = char::decode_utf16(units).collect::<core::result::Result<String, _>>().ok()?;
+        Some(Self {
+            path,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        for c in self.path.encode_utf16().chain([0]) {
+            buf.extend_from_slice(&c.to_le_bytes());
+        }
+    }
+}
+
+impl fmt::Display for FilePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.path)
+    }
+}
+
+/// Media protocol device path: `Media(Guid)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MediaProtocol {
+    pub protocol: Guid,
+}
+
+impl TypedNode for MediaProtocol {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::MEDIA_PROTOCOL;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            protocol: Reader::new(data).guid()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.protocol.to_bytes());
+    }
+}
+
+impl fmt::Display for MediaProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Media({:X})", self.protocol)
+    }
+}
+
+/// PI firmware file device path: `FvFile(Guid)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FirmwareFile {
+    pub name: Guid,
+}
+
+impl TypedNode for FirmwareFile {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::PIWG_FIRMWARE_FILE;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            name: Reader::new(data).guid()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.name.to_bytes());
+    }
+}
+
+impl fmt::Display for FirmwareFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FvFile({:X})", self.name)
+    }
+}
+
+/// PI firmware volume device path: `Fv(Guid)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FirmwareVolume {
+    pub name: Guid,
+}
+
+impl TypedNode for FirmwareVolume {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::PIWG_FIRMWARE_VOLUME;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            name: Reader::new(data).guid()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.name.to_bytes());
+    }
+}
+
+impl fmt::Display for FirmwareVolume {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Fv({:X})", self.name)
+    }
+}
+
+/// Relative offset range device path: `Offset(Start,End)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RelativeOffsetRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl TypedNode for RelativeOffsetRange {
+    const TYPE: DevicePathType = DevicePathType::MEDIA;
+    const SUB_TYPE: u8 = MediaSubType::RELATIVE_OFFSET_RANGE;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        let _reserved = r.u32()?;
+        Some(Self {
+            start: r.u64()?,
+            end: r.u64()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&0u32.to_le_bytes());
+        buf.extend_from_slice(&self.start.to_le_bytes());
+        buf.extend_from_slice(&self.end.to_le_bytes());
+    }
+}
+
+impl fmt::Display for RelativeOffsetRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Offset({:#X},{:#X})", self.start, self.end)
+    }
+}
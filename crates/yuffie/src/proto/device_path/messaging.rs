@@ -0,0 +1,597 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Messaging Device Path
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 10.3.4: Messaging Device Path
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::DevicePathType;
+use super::TypedNode;
+use super::text::write_hex;
+use crate::bytes::Reader;
+use crate::prelude::*;
+
+/// Sub-types of [`DevicePathType::MESSAGING`].
+pub struct MessagingSubType;
+
+impl MessagingSubType {
+    pub const ATAPI: u8 = 0x01;
+    pub const SCSI: u8 = 0x02;
+    pub const FIBRE_CHANNEL: u8 = 0x03;
+    pub const IEEE_1394: u8 = 0x04;
+    pub const USB: u8 = 0x05;
+    pub const I2O: u8 = 0x06;
+    pub const INFINIBAND: u8 = 0x09;
+    pub const VENDOR: u8 = 0x0A;
+    pub const MAC_ADDRESS: u8 = 0x0B;
+    pub const IPV4: u8 = 0x0C;
+    pub const IPV6: u8 = 0x0D;
+    pub const UART: u8 = 0x0E;
+    pub const USB_CLASS: u8 = 0x0F;
+    pub const USB_WWID: u8 = 0x10;
+    pub const DEVICE_LOGICAL_UNIT: u8 = 0x11;
+    pub const SATA: u8 = 0x12;
+    pub const ISCSI: u8 = 0x13;
+    pub const VLAN: u8 = 0x14;
+    pub const FIBRE_CHANNEL_EX: u8 = 0x15;
+    pub const SAS_EX: u8 = 0x16;
+    pub const NVME_NAMESPACE: u8 = 0x17;
+    pub const URI: u8 = 0x18;
+    pub const UFS: u8 = 0x19;
+    pub const SD: u8 = 0x1A;
+    pub const BLUETOOTH: u8 = 0x1B;
+    pub const WIFI: u8 = 0x1C;
+    pub const EMMC: u8 = 0x1D;
+}
+
+/// ATAPI device path: `Ata(PrimarySecondary,SlaveMaster,Lun)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Atapi {
+    /// 0 for the primary controller, 1 for the secondary controller.
+    pub primary_secondary: u8,
+    /// 0 for the master device, 1 for the slave device.
+    pub slave_master: u8,
+    pub lun: u16,
+}
+
+impl TypedNode for Atapi {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::ATAPI;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            primary_secondary: r.u8()?,
+            slave_master: r.u8()?,
+            lun: r.u16()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&[self.primary_secondary, self.slave_master]);
+        buf.extend_from_slice(&self.lun.to_le_bytes());
+    }
+}
+
+impl fmt::Display for Atapi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let channel = if self.primary_secondary == 0 {
+            "Primary"
+        } else {
+            "Secondary"
+        };
+        let device = if self.slave_master == 0 {
+            "Master"
+        } else {
+            "Slave"
+        };
+        write!(f, "Ata({},{},{:#X})", channel, device, self.lun)
+    }
+}
+
+/// SCSI device path: `Scsi(Pun,Lun)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Scsi {
+    pub pun: u16,
+    pub lun: u16,
+}
+
+impl TypedNode for Scsi {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::SCSI;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            pun: r.u16()?,
+            lun: r.u16()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.pun.to_le_bytes());
+        buf.extend_from_slice(&self.lun.to_le_bytes());
+    }
+}
+
+impl fmt::Display for Scsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Scsi({:#X},{:#X})", self.pun, self.lun)
+    }
+}
+
+/// USB device path: `USB(Port,Interface)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Usb {
+    pub parent_port: u8,
+    pub interface: u8,
+}
+
+impl TypedNode for Usb {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::USB;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            parent_port: r.u8()?,
+            interface: r.u8()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&[self.parent_port, self.interface]);
+    }
+}
+
+impl fmt::Display for Usb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "USB({:#X},{:#X})", self.parent_port, self.interface)
+    }
+}
+
+/// Vendor-defined messaging device path: `VenMsg(Guid,Data)`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VendorMessaging {
+    pub guid: Guid,
+    pub data: Vec<u8>,
+}
+
+impl TypedNode for VendorMessaging {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::VENDOR;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            guid: r.guid()?,
+            data: r.rest().to_vec(),
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.guid.to_bytes());
+        buf.extend_from_slice(&self.data);
+    }
+}
+
+impl fmt::Display for VendorMessaging {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VenMsg({:X}", self.guid)?;
+        if !self.data.is_empty() {
+            f.write_str(",")?;
+            write_hex(f, &self.data)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// MAC address device path: `MAC(MacAddr,IfType)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MacAddress {
+    pub address: [u8; 32],
+    pub if_type: u8,
+}
+
+impl MacAddress {
+    /// The number of bytes of the address that are used by the interface.
+    pub fn address_len(&self) -> usize {
+        // Ethernet (0) and IEEE 802 (1) use 6 byte addresses.
+        if self.if_type <= 1 {
+            6
+        } else {
+            self.address.len()
+        }
+    }
+}
+
+impl TypedNode for MacAddress {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::MAC_ADDRESS;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            address: r.array()?,
+            if_type: r.u8()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.address);
+        buf.push(self.if_type);
+    }
+}
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MAC(")?;
+        write_hex(f, &self.address[..self.address_len()])?;
+        write!(f, ",{:#X})", self.if_type)
+    }
+}
+
+/// IPv4 device path: `IPv4(RemoteIp,Protocol,Type,LocalIp,Gateway,SubnetMask)`
+///
+/// The ports are not part of the text representation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ipv4 {
+    pub local_ip: [u8; 4],
+    pub remote_ip: [u8; 4],
+    pub local_port: u16,
+    pub remote_port: u16,
+    pub protocol: u16,
+    pub static_ip: bool,
+    pub gateway_ip: [u8; 4],
+    pub subnet_mask: [u8; 4],
+}
+
+impl TypedNode for Ipv4 {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::IPV4;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            local_ip: r.array()?,
+            remote_ip: r.array()?,
+            local_port: r.u16()?,
+            remote_port: r.u16()?,
+            protocol: r.u16()?,
+            static_ip: r.u8()? != 0,
+            gateway_ip: r.array()?,
+            subnet_mask: r.array()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.local_ip);
+        buf.extend_from_slice(&self.remote_ip);
+        buf.extend_from_slice(&self.local_port.to_le_bytes());
+        buf.extend_from_slice(&self.remote_port.to_le_bytes());
+        buf.extend_from_slice(&self.protocol.to_le_bytes());
+        buf.push(u8::from(self.static_ip));
+        buf.extend_from_slice(&self.gateway_ip);
+        buf.extend_from_slice(&self.subnet_mask);
+    }
+}
+
+impl fmt::Display for Ipv4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ip = |a: [u8; 4]| core::net::Ipv4Addr::from(a);
+
+        write!(f, "IPv4({},", ip(self.remote_ip))?;
+        match self.protocol {
+            6 => f.write_str("TCP")?,
+            17 => f.write_str("UDP")?,
+            p => write!(f, "{:#X}", p)?,
+        }
+        let kind = if self.static_ip {
+            "Static"
+        } else {
+            "DHCP"
+        };
+        write!(
+            f,
+            ",{},{},{},{})",
+            kind,
+            ip(self.local_ip),
+            ip(self.gateway_ip),
+            ip(self.subnet_mask)
+        )
+    }
+}
+
+/// UART device path: `Uart(Baud,DataBits,Parity,StopBits)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Uart {
+    /// The baud rate, or 0 for the device's default.
+    pub baud_rate: u64,
+    /// The number of data bits, or 0 for the device's default.
+    pub data_bits: u8,
+    /// The parity, using the values of `EFI_PARITY_TYPE`.
+    pub parity: u8,
+    /// The number of stop bits, using the values of `EFI_STOP_BITS_TYPE`.
+    pub stop_bits: u8,
+}
+
+impl Uart {
+    pub(super) const PARITY: [&str; 6] = ["D", "N", "E", "O", "M", "S"];
+    pub(super) const STOP_BITS: [&str; 4] = ["D", "1", "1.5", "2"];
+}
+
+impl TypedNode for Uart {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::UART;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        let _reserved = r.u32()?;
+        Some(Self {
+            baud_rate: r.u64()?,
+            data_bits: r.u8()?,
+            parity: r.u8()?,
+            stop_bits: r.u8()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&0u32.to_le_bytes());
+        buf.extend_from_slice(&self.baud_rate.to_le_bytes());
+        buf.extend_from_slice(&[self.data_bits, self.parity, self.stop_bits]);
+    }
+}
+
+impl fmt::Display for Uart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Uart(")?;
+        match self.baud_rate {
+            0 => f.write_str("DEFAULT,")?,
+            b => write!(f, "{},", b)?,
+        }
+        match self.data_bits {
+            0 => f.write_str("DEFAULT,")?,
+            b => write!(f, "{},", b)?,
+        }
+        match Self::PARITY.get(usize::from(self.parity)) {
+            Some(p) => write!(f, "{},", p)?,
+            None => write!(f, "{:#X},", self.parity)?,
+        }
+        match Self::STOP_BITS.get(usize::from(self.stop_bits)) {
+            Some(s) => write!(f, "{})", s),
+            None => write!(f, "{:#X})", self.stop_bits),
+        }
+    }
+}
+
+/// USB class device path: `UsbClass(VID,PID,Class,SubClass,Protocol)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UsbClass {
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub class: u8,
+    pub subclass: u8,
+    pub protocol: u8,
+}
+
+impl TypedNode for UsbClass {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::USB_CLASS;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            vendor_id: r.u16()?,
+            product_id: r.u16()?,
+            class: r.u8()?,
+            subclass: r.u8()?,
+            protocol: r.u8()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.vendor_id.to_le_bytes());
+        buf.extend_from_slice(&self.product_id.to_le_bytes());
+        buf.extend_from_slice(&[self.class, self.subclass, self.protocol]);
+    }
+}
+
+impl fmt::Display for UsbClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "UsbClass({:#X},{:#X},{:#X},{:#X},{:#X})",
+            self.vendor_id, self.product_id, self.class, self.subclass, self.protocol
+        )
+    }
+}
+
+/// SATA device path: `Sata(HBA,PortMultiplier,Lun)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sata {
+    pub hba_port: u16,
+    /// The port multiplier port, or `0xFFFF` if connected directly.
+    pub port_multiplier_port: u16,
+    pub lun: u16,
+}
+
+impl TypedNode for Sata {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::SATA;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            hba_port: r.u16()?,
+            port_multiplier_port: r.u16()?,
+            lun: r.u16()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.hba_port.to_le_bytes());
+        buf.extend_from_slice(&self.port_multiplier_port.to_le_bytes());
+        buf.extend_from_slice(&self.lun.to_le_bytes());
+    }
+}
+
+impl fmt::Display for Sata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Sata({:#X},{:#X},{:#X})", self.hba_port, self.port_multiplier_port, self.lun)
+    }
+}
+
+/// VLAN device path: `Vlan(VlanId)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Vlan {
+    pub vlan_id: u16,
+}
+
+impl TypedNode for Vlan {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::VLAN;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            vlan_id: Reader::new(data).u16()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.vlan_id.to_le_bytes());
+    }
+}
+
+impl fmt::Display for Vlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vlan({})", self.vlan_id)
+    }
+}
+
+/// NVM Express namespace device path: `NVMe(NamespaceId,EUI-64)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NvmeNamespace {
+    pub namespace_id: u32,
+    /// The IEEE Extended Unique Identifier, as stored in the node.
+    pub eui64: [u8; 8],
+}
+
+impl TypedNode for NvmeNamespace {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::NVME_NAMESPACE;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        Some(Self {
+            namespace_id: r.u32()?,
+            eui64: r.array()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.namespace_id.to_le_bytes());
+        buf.extend_from_slice(&self.eui64);
+    }
+}
+
+impl fmt::Display for NvmeNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NVMe({:#X},", self.namespace_id)?;
+        // The EUI-64 is displayed most significant byte first.
+        for (i, b) in self.eui64.iter().rev().enumerate() {
+            if i > 0 {
+                f.write_str("-")?;
+            }
+            write!(f, "{:02X}", b)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// URI device path: `Uri(Uri)`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Uri {
+    pub uri: String,
+}
+
+impl TypedNode for Uri {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::URI;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            uri: String::from_utf8(data.to_vec()).ok()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.uri.as_bytes());
+    }
+}
+
+impl fmt::Display for Uri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Uri({})", self.uri)
+    }
+}
+
+/// SD device path: `SD(SlotNumber)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sd {
+    pub slot: u8,
+}
+
+impl TypedNode for Sd {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::SD;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            slot: Reader::new(data).u8()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.push(self.slot);
+    }
+}
+
+impl fmt::Display for Sd {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SD({:#X})", self.slot)
+    }
+}
+
+/// eMMC device path: `eMMC(SlotNumber)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Emmc {
+    pub slot: u8,
+}
+
+impl TypedNode for Emmc {
+    const TYPE: DevicePathType = DevicePathType::MESSAGING;
+    const SUB_TYPE: u8 = MessagingSubType::EMMC;
+
+    fn from_data(data: &[u8]) -> Option<Self> {
+        Some(Self {
+            slot: Reader::new(data).u8()?,
+        })
+    }
+
+    fn write_data(&self, buf: &mut Vec<u8>) {
+        buf.push(self.slot);
+    }
+}
+
+impl fmt::Display for Emmc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "eMMC({:#X})", self.slot)
+    }
+}
@@ -0,0 +1,636 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Device Path Protocol
+//!
+//! A device path is a packed, variable-length sequence of nodes that
+//! describes the location of a device. Each node starts with a 4 byte header
+//! giving its type, sub-type, and length. A path is terminated by an end node,
+//! and multiple instances are separated by end-of-instance nodes.
+//!
+//! ## References
+//!
+//! - [UEFI Specification, Version 2.10][UEFI Spec]
+//!   - 10: Device Path Protocol
+//!
+//! [UEFI Spec]: https://uefi.org/sites/default/files/resources/UEFI_Spec_2_10_Aug29.pdf
+
+#[cfg(feature = "alloc")]
+pub mod acpi;
+#[cfg(feature = "alloc")]
+pub mod bbs;
+#[cfg(feature = "alloc")]
+pub mod hardware;
+#[cfg(feature = "alloc")]
+pub mod media;
+#[cfg(feature = "alloc")]
+pub mod messaging;
+#[cfg(feature = "alloc")]
+mod text;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Borrow;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::ops;
+
+use crate::prelude::*;
+
+/// The size of a device path node header.
+const HEADER_LEN: usize = 4;
+
+/// Device path node type
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct DevicePathType(u8);
+
+impl DevicePathType {
+    pub const HARDWARE: Self = Self(0x01);
+    pub const ACPI: Self = Self(0x02);
+    pub const MESSAGING: Self = Self(0x03);
+    pub const MEDIA: Self = Self(0x04);
+    pub const BBS: Self = Self(0x05);
+    pub const END: Self = Self(0x7F);
+}
+
+impl From<u8> for DevicePathType {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DevicePathType> for u8 {
+    fn from(value: DevicePathType) -> Self {
+        value.0
+    }
+}
+
+/// Sub-types of [`DevicePathType::END`].
+pub struct EndSubType;
+
+impl EndSubType {
+    /// End of one instance of a multi-instance device path.
+    pub const INSTANCE: u8 = 0x01;
+    /// End of the entire device path.
+    pub const ENTIRE: u8 = 0xFF;
+}
+
+/// `EFI_DEVICE_PATH_PROTOCOL`
+///
+/// The header of a device path node, as passed to and from firmware.
+#[derive(Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct DevicePathProtocol {
+    pub Type: DevicePathType,
+    pub SubType: u8,
+    pub Length: [u8; 2],
+}
+
+unsafe impl Protocol for DevicePathProtocol {
+    const GUID: Guid = guid!("09576e91-6d3f-11d2-8e39-00a0c969723b");
+}
+
+/// A single device path node, including its header.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct DevicePathNode([u8]);
+
+impl DevicePathNode {
+    /// Creates a node from bytes that start with a valid header.
+    ///
+    /// Any bytes after the length given in the header are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Option<&Self> {
+        let len = node_len(bytes)?;
+        Some(unsafe { Self::from_bytes_unchecked(&bytes[..len]) })
+    }
+
+    /// # Safety
+    ///
+    /// `bytes` must be exactly one node.
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        unsafe { &*(core::ptr::from_ref(bytes) as *const Self) }
+    }
+
+    /// The type of the node.
+    pub fn kind(&self) -> DevicePathType {
+        DevicePathType(self.0[0])
+    }
+
+    /// The sub-type of the node.
+    pub fn sub_type(&self) -> u8 {
+        self.0[1]
+    }
+
+    /// The length of the node in bytes, including the header.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the node has no data after the header.
+    pub fn is_empty(&self) -> bool {
+        self.0.len() == HEADER_LEN
+    }
+
+    /// The data following the header.
+    pub fn data(&self) -> &[u8] {
+        &self.0[HEADER_LEN..]
+    }
+
+    /// The raw bytes of the node, including the header.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns `true` if the node is an end-of-instance or end-of-path node.
+    pub fn is_end(&self) -> bool {
+        self.kind() == DevicePathType::END
+    }
+
+    /// Returns `true` if the node is an end-of-instance node.
+    pub fn is_end_instance(&self) -> bool {
+        self.is_end() && self.sub_type() == EndSubType::INSTANCE
+    }
+
+    /// Returns `true` if the node is an end-of-path node.
+    pub fn is_end_entire(&self) -> bool {
+        self.is_end() && self.sub_type() == EndSubType::ENTIRE
+    }
+
+    /// Parses the node as a specific node type.
+    #[cfg(feature = "alloc")]
+    pub fn as_typed<N: TypedNode>(&self) -> Option<N> {
+        if self.kind() == N::TYPE && self.sub_type() == N::SUB_TYPE {
+            N::from_data(self.data())
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Debug for DevicePathNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DevicePathNode")
+            .field("kind", &self.kind())
+            .field("sub_type", &self.sub_type())
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+/// A borrowed device path.
+///
+/// The path is one or more instances, terminated by an end node.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct DevicePath([u8]);
+
+impl DevicePath {
+    /// Creates a device path from bytes.
+    ///
+    /// Returns `None` if a node has an invalid length or the path is not
+    /// terminated. Any bytes after the end node are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Option<&Self> {
+        let mut offset = 0;
+
+        loop {
+            let node = DevicePathNode::from_bytes(&bytes[offset..])?;
+            offset += node.len();
+            if node.is_end_entire() {
+                break;
+            }
+        }
+
+        Some(unsafe { Self::from_bytes_unchecked(&bytes[..offset]) })
+    }
+
+    /// # Safety
+    ///
+    /// `bytes` must be a sequence of valid nodes, ending with an end node.
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        unsafe { &*(core::ptr::from_ref(bytes) as *const Self) }
+    }
+
+    /// Creates a device path from a pointer provided by firmware.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid device path that is terminated by an end
+    /// node and lives for `'a`.
+    pub unsafe fn from_ptr<'a>(ptr: *const DevicePathProtocol) -> &'a Self {
+        let base = ptr.cast::<u8>();
+        let mut offset = 0;
+
+        loop {
+            let header = unsafe { core::slice::from_raw_parts(base.add(offset), HEADER_LEN) };
+            offset += usize::from(u16::from_le_bytes([header[2], header[3]]));
+            if header[0] == DevicePathType::END.0 && header[1] == EndSubType::ENTIRE {
+                break;
+            }
+        }
+
+        unsafe { Self::from_bytes_unchecked(core::slice::from_raw_parts(base, offset)) }
+    }
+
+    /// A pointer to the path to pass to firmware.
+    pub fn as_ptr(&self) -> *const DevicePathProtocol {
+        self.0.as_ptr().cast()
+    }
+
+    /// The raw bytes of the path, including the end node.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The size of the path in bytes, including the end node.
+    pub fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the nodes of the path.
+    ///
+    /// End-of-instance nodes are included, but the final end node is not.
+    pub fn nodes(&self) -> DevicePathNodes<'_> {
+        DevicePathNodes {
+            bytes: &self.0,
+        }
+    }
+
+    /// Returns an iterator over the instances of a multi-instance path.
+    pub fn instances(&self) -> DevicePathInstances<'_> {
+        DevicePathInstances {
+            bytes: &self.0,
+        }
+    }
+
+    /// Returns `true` if the path contains more than one instance.
+    pub fn is_multi_instance(&self) -> bool {
+        self.nodes().any(DevicePathNode::is_end_instance)
+    }
+}
+
+impl fmt::Debug for DevicePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.nodes()).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToOwned for DevicePath {
+    type Owned = DevicePathBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        DevicePathBuf(self.0.to_vec())
+    }
+}
+
+impl AsRef<DevicePath> for DevicePath {
+    fn as_ref(&self) -> &DevicePath {
+        self
+    }
+}
+
+/// An iterator over the nodes of a [`DevicePath`].
+#[derive(Clone, Debug)]
+pub struct DevicePathNodes<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for DevicePathNodes<'a> {
+    type Item = &'a DevicePathNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = node_len(self.bytes)?;
+        let (node, rest) = self.bytes.split_at(len);
+        // The final node always terminates the path.
+        if rest.is_empty() {
+            self.bytes = rest;
+            return None;
+        }
+
+        self.bytes = rest;
+        Some(unsafe { DevicePathNode::from_bytes_unchecked(node) })
+    }
+}
+
+/// An instance of a [`DevicePath`].
+///
+/// Instances other than the last end with an end-of-instance node, so an
+/// instance is not a device path by itself. Use
+/// [`DevicePathInstance::to_device_path()`] to get a path that can be passed
+/// to firmware.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct DevicePathInstance<'a> {
+    /// The nodes of the instance, including its end node.
+    bytes: &'a [u8],
+}
+
+impl<'a> DevicePathInstance<'a> {
+    /// Returns an iterator over the nodes of the instance, excluding its end
+    /// node.
+    pub fn nodes(&self) -> DevicePathNodes<'a> {
+        DevicePathNodes {
+            bytes: self.bytes,
+        }
+    }
+
+    /// Copies the instance into a device path terminated by an end-of-path
+    /// node.
+    #[cfg(feature = "alloc")]
+    pub fn to_device_path(&self) -> DevicePathBuf {
+        let mut builder = DevicePathBuilder::new();
+        for node in self.nodes() {
+            builder.push_node(node);
+        }
+        builder.finish()
+    }
+}
+
+impl fmt::Debug for DevicePathInstance<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.nodes()).finish()
+    }
+}
+
+/// An iterator over the instances of a [`DevicePath`].
+#[derive(Clone, Debug)]
+pub struct DevicePathInstances<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for DevicePathInstances<'a> {
+    type Item = DevicePathInstance<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut offset = 0;
+
+        loop {
+            let len = node_len(&self.bytes[offset..])?;
+            let end = self.bytes[offset] == DevicePathType::END.0;
+            offset += len;
+            if end {
+                break;
+            }
+        }
+
+        let (instance, rest) = self.bytes.split_at(offset);
+        self.bytes = rest;
+        Some(DevicePathInstance {
+            bytes: instance,
+        })
+    }
+}
+
+/// Returns the length of the node at the start of `bytes`, if valid.
+fn node_len(bytes: &[u8]) -> Option<usize> {
+    let header = bytes.get(..HEADER_LEN)?;
+    let len = usize::from(u16::from_le_bytes([header[2], header[3]]));
+    if len < HEADER_LEN || len > bytes.len() {
+        None
+    } else {
+        Some(len)
+    }
+}
+
+/// A device path node with a known type and sub-type.
+#[cfg(feature = "alloc")]
+pub trait TypedNode: Sized + fmt::Display {
+    /// The type of the node.
+    const TYPE: DevicePathType;
+    /// The sub-type of the node.
+    const SUB_TYPE: u8;
+
+    /// Parses the node from the data following the header.
+    fn from_data(data: &[u8]) -> Option<Self>;
+
+    /// Writes the data following the header.
+    fn write_data(&self, buf: &mut Vec<u8>);
+}
+
+/// An owned device path.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Eq, PartialEq)]
+pub struct DevicePathBuf(Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl DevicePathBuf {
+    /// Creates a device path containing only an end node.
+    pub fn new() -> Self {
+        DevicePathBuilder::new().finish()
+    }
+
+    /// Creates a device path by copying bytes.
+    ///
+    /// Returns `None` if the bytes are not a valid device path.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        DevicePath::from_bytes(bytes).map(ToOwned::to_owned)
+    }
+
+    /// Parses a device path from its text representation.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The text is not a valid device path.
+    pub fn from_text(text: &str) -> Result<Self> {
+        text::parse(text)
+    }
+
+    /// Returns a device path with the nodes of `other` appended.
+    #[must_use]
+    pub fn join(&self, other: &DevicePath) -> Self {
+        let mut builder = DevicePathBuilder::from(self.as_ref());
+        for node in other.nodes() {
+            builder.push_node(node);
+        }
+        builder.finish()
+    }
+
+    /// Returns the raw bytes of the path.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for DevicePathBuf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ops::Deref for DevicePathBuf {
+    type Target = DevicePath;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { DevicePath::from_bytes_unchecked(&self.0) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<DevicePath> for DevicePathBuf {
+    fn as_ref(&self) -> &DevicePath {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Borrow<DevicePath> for DevicePathBuf {
+    fn borrow(&self) -> &DevicePath {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for DevicePathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for DevicePathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for DevicePathBuf {
+    type Err = Status;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_text(s)
+    }
+}
+
+/// A builder for creating a device path by appending nodes.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct DevicePathBuilder {
+    buf: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl DevicePathBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a typed node.
+    pub fn push<N: TypedNode>(&mut self, node: &N) -> &mut Self {
+        let start = self.buf.len();
+        self.buf.extend_from_slice(&[N::TYPE.0, N::SUB_TYPE, 0, 0]);
+        node.write_data(&mut self.buf);
+        self.set_len(start);
+        self
+    }
+
+    /// Appends a copy of an existing node.
+    pub fn push_node(&mut self, node: &DevicePathNode) -> &mut Self {
+        self.buf.extend_from_slice(node.as_bytes());
+        self
+    }
+
+    /// Appends a node from its type, sub-type, and data.
+    pub fn push_raw(&mut self, kind: DevicePathType, sub_type: u8, data: &[u8]) -> &mut Self {
+        let start = self.buf.len();
+        self.buf.extend_from_slice(&[kind.0, sub_type, 0, 0]);
+        self.buf.extend_from_slice(data);
+        self.set_len(start);
+        self
+    }
+
+    /// Ends the current instance and starts a new one.
+    pub fn end_instance(&mut self) -> &mut Self {
+        self.push_raw(DevicePathType::END, EndSubType::INSTANCE, &[])
+    }
+
+    /// Terminates the path and returns it.
+    pub fn finish(&mut self) -> DevicePathBuf {
+        let mut buf = core::mem::take(&mut self.buf);
+        buf.extend_from_slice(&[DevicePathType::END.0, EndSubType::ENTIRE, 4, 0]);
+        DevicePathBuf(buf)
+    }
+
+    fn set_len(&mut self, start: usize) {
+        let len = u16::try_from(self.buf.len() - start).expect("device path node too large");
+        self.buf[start + 2..start + 4].copy_from_slice(&len.to_le_bytes());
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&DevicePath> for DevicePathBuilder {
+    fn from(path: &DevicePath) -> Self {
+        let mut builder = Self::new();
+        for node in path.nodes() {
+            builder.push_node(node);
+        }
+        builder
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+
+    /// `PciRoot(0x0)/Pci(0x1F,0x2)`
+    const PATH: &[u8] = &[
+        0x02, 0x01, 0x0C, 0x00, 0xD0, 0x41, 0x03, 0x0A, 0x00, 0x00, 0x00, 0x00, // ACPI
+        0x01, 0x01, 0x06, 0x00, 0x02, 0x1F, // PCI
+        0x7F, 0xFF, 0x04, 0x00, // End
+    ];
+
+    #[test]
+    fn iterate_nodes() {
+        let path = DevicePath::from_bytes(PATH).unwrap();
+        let nodes: Vec<_> = path.nodes().map(|n| (n.kind(), n.sub_type(), n.len())).collect();
+        assert_eq!(nodes, [(DevicePathType::ACPI, 1, 12), (DevicePathType::HARDWARE, 1, 6)]);
+        assert_eq!(path.size(), PATH.len());
+    }
+
+    #[test]
+    fn invalid_length() {
+        let mut bytes = PATH.to_vec();
+        bytes[14] = 0x02;
+        assert!(DevicePath::from_bytes(&bytes).is_none());
+        assert!(DevicePath::from_bytes(&PATH[..PATH.len() - 4]).is_none());
+    }
+
+    #[test]
+    fn instances() {
+        let a = DevicePathBuf::from_text("PciRoot(0x0)/Pci(0x1F,0x2)").unwrap();
+        let b = DevicePathBuf::from_text("PciRoot(0x1)").unwrap();
+        let mut builder = DevicePathBuilder::from(&*a);
+        builder.end_instance();
+        let path = builder.finish().join(&b);
+
+        assert!(path.is_multi_instance());
+        assert_eq!(path.instances().count(), 2);
+        assert_eq!(path.instances().nth(1).unwrap().nodes().count(), 1);
+
+        let first = path.instances().next().unwrap().to_device_path();
+        assert_eq!(first, a);
+        assert!(!first.is_multi_instance());
+    }
+
+    #[test]
+    fn builder() {
+        let mut builder = DevicePathBuilder::new();
+        builder.push(&acpi::Acpi::pci_root(0)).push(&hardware::Pci {
+            device: 0x1F,
+            function: 0x02,
+        });
+        let path = builder.finish();
+
+        assert_eq!(path.as_bytes(), PATH);
+        let pci = path.nodes().nth(1).unwrap().as_typed::<hardware::Pci>().unwrap();
+        assert_eq!(pci, hardware::Pci {
+            device: 0x1F,
+            function: 0x02
+        });
+    }
+}
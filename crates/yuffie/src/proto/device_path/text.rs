@@ -0,0 +1,577 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Device Path Text Representation
+//!
+//! Nodes without a known text form are written with the generic
+//! `Path(Type,SubType,Data)` forms so that every path can be round-tripped.
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 10.6: Text Device Path Representation
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::net::Ipv4Addr;
+
+use super::DevicePath;
+use super::DevicePathBuf;
+use super::DevicePathBuilder;
+use super::DevicePathNode;
+use super::DevicePathType;
+use super::TypedNode;
+use super::acpi::*;
+use super::bbs::*;
+use super::hardware::*;
+use super::media::*;
+use super::messaging::*;
+use crate::prelude::*;
+
+/// Writes bytes as pairs of upper case hex digits.
+pub(super) fn write_hex(f: &mut fmt::Formatter, data: &[u8]) -> fmt::Result {
+    data.iter().try_for_each(|b| write!(f, "{:02X}", b))
+}
+
+fn fmt_as<N: TypedNode>(f: &mut fmt::Formatter, node: &DevicePathNode) -> Option<fmt::Result> {
+    node.as_typed::<N>().map(|n| fmt::Display::fmt(&n, f))
+}
+
+impl fmt::Display for DevicePathNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DevicePathType as T;
+        use MediaSubType as Media;
+        use MessagingSubType as Msg;
+
+        #[rustfmt::skip]
+        let typed = match (self.kind(), self.sub_type()) {
+            (T::HARDWARE, HardwareSubType::PCI) => fmt_as::<Pci>(f, self),
+            (T::HARDWARE, HardwareSubType::PCCARD) => fmt_as::<PcCard>(f, self),
+            (T::HARDWARE, HardwareSubType::MEMORY_MAPPED) => fmt_as::<MemoryMapped>(f, self),
+            (T::HARDWARE, HardwareSubType::VENDOR) => fmt_as::<VendorHardware>(f, self),
+            (T::HARDWARE, HardwareSubType::CONTROLLER) => fmt_as::<Controller>(f, self),
+            (T::HARDWARE, HardwareSubType::BMC) => fmt_as::<Bmc>(f, self),
+            (T::ACPI, AcpiSubType::ACPI) => fmt_as::<Acpi>(f, self),
+            (T::ACPI, AcpiSubType::EXPANDED) => fmt_as::<AcpiExpanded>(f, self),
+            (T::ACPI, AcpiSubType::ADR) => fmt_as::<AcpiAdr>(f, self),
+            (T::MESSAGING, Msg::ATAPI) => fmt_as::<Atapi>(f, self),
+            (T::MESSAGING, Msg::SCSI) => fmt_as::<Scsi>(f, self),
+            (T::MESSAGING, Msg::USB) => fmt_as::<Usb>(f, self),
+            (T::MESSAGING, Msg::VENDOR) => fmt_as::<VendorMessaging>(f, self),
+            (T::MESSAGING, Msg::MAC_ADDRESS) => fmt_as::<MacAddress>(f, self),
+            (T::MESSAGING, Msg::IPV4) => fmt_as::<Ipv4>(f, self),
+            (T::MESSAGING, Msg::UART) => fmt_as::<Uart>(f, self),
+            (T::MESSAGING, Msg::USB_CLASS) => fmt_as::<UsbClass>(f, self),
+            (T::MESSAGING, Msg::SATA) => fmt_as::<Sata>(f, self),
+            (T::MESSAGING, Msg::VLAN) => fmt_as::<Vlan>(f, self),
+            (T::MESSAGING, Msg::NVME_NAMESPACE) => fmt_as::<NvmeNamespace>(f, self),
+            (T::MESSAGING, Msg::URI) => fmt_as::<Uri>(f, self),
+            (T::MESSAGING, Msg::SD) => fmt_as::<Sd>(f, self),
+            (T::MESSAGING, Msg::EMMC) => fmt_as::<Emmc>(f, self),
+            (T::MEDIA, Media::HARD_DRIVE) => fmt_as::<HardDrive>(f, self),
+            (T::MEDIA, Media::CDROM) => fmt_as::<CdRom>(f, self),
+            (T::MEDIA, Media::VENDOR) => fmt_as::<VendorMedia>(f, self),
+            (T::MEDIA, Media::FILE_PATH) => fmt_as::<FilePath>(f, self),
+            (T::MEDIA, Media::MEDIA_PROTOCOL) => fmt_as::<MediaProtocol>(f, self),
+            (T::MEDIA, Media::PIWG_FIRMWARE_FILE) => fmt_as::<FirmwareFile>(f, self),
+            (T::MEDIA, Media::PIWG_FIRMWARE_VOLUME) => fmt_as::<FirmwareVolume>(f, self),
+            (T::MEDIA, Media::RELATIVE_OFFSET_RANGE) => fmt_as::<RelativeOffsetRange>(f, self),
+            (T::BBS, BbsSubType::BBS) => fmt_as::<Bbs>(f, self),
+            _ => None,
+        };
+
+        if let Some(result) = typed {
+            return result;
+        }
+
+        match generic_name(self.kind()) {
+            Some(name) => write!(f, "{}({},", name, self.sub_type())?,
+            None => write!(f, "Path({},{},", u8::from(self.kind()), self.sub_type())?,
+        }
+        write_hex(f, self.data())?;
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for DevicePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        for node in self.nodes() {
+            if node.is_end_instance() {
+                f.write_str(",")?;
+                separator = "";
+            } else {
+                write!(f, "{}{}", separator, node)?;
+                separator = "/";
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of the generic text form of a node type.
+fn generic_name(kind: DevicePathType) -> Option<&'static str> {
+    match kind {
+        DevicePathType::HARDWARE => Some("HardwarePath"),
+        DevicePathType::ACPI => Some("AcpiPath"),
+        DevicePathType::MESSAGING => Some("Msg"),
+        DevicePathType::MEDIA => Some("MediaPath"),
+        DevicePathType::BBS => Some("BbsPath"),
+        _ => None,
+    }
+}
+
+/// Parses the text representation of a device path.
+pub(super) fn parse(text: &str) -> Result<DevicePathBuf> {
+    let mut builder = DevicePathBuilder::new();
+
+    for (i, instance) in split(text, ',').enumerate() {
+        if i > 0 {
+            builder.end_instance();
+        }
+
+        for node in split(instance, '/').filter(|n| !n.is_empty()) {
+            parse_node(&mut builder, node)?;
+        }
+    }
+
+    Ok(builder.finish())
+}
+
+/// Splits text on a separator that is not enclosed in parentheses.
+fn split(text: &str, sep: char) -> impl Iterator<Item = &str> {
+    let mut depth = 0u32;
+    text.split(move |c| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        c == sep && depth == 0
+    })
+}
+
+/// The arguments of a node in its text form.
+struct Args<'a>(Vec<&'a str>);
+
+impl<'a> Args<'a> {
+    /// Returns an argument, or an empty string if it is not present.
+    fn str(&self, index: usize) -> &'a str {
+        self.0.get(index).copied().unwrap_or_default()
+    }
+
+    fn int<T: TryFrom<u64>>(&self, index: usize) -> Result<T> {
+        parse_int(self.str(index))
+    }
+
+    fn guid(&self, index: usize) -> Result<Guid> {
+        Guid::try_parse_str(self.str(index)).ok_or(Status::INVALID_PARAMETER)
+    }
+
+    fn hex(&self, index: usize) -> Result<Vec<u8>> {
+        parse_hex(self.str(index))
+    }
+
+    /// Parses an EISA ID string, or an integer.
+    fn eisa_id(&self, index: usize) -> Result<u32> {
+        let s = self.str(index);
+        eisa_id_from_str(s).map_or_else(|| parse_int(s), Ok)
+    }
+
+    fn ipv4(&self, index: usize) -> Result<[u8; 4]> {
+        match self.str(index) {
+            "" => Ok([0; 4]),
+            s => s.parse::<Ipv4Addr>().map(|ip| ip.octets()).map_err(|_| Status::INVALID_PARAMETER),
+        }
+    }
+
+    /// Parses an argument that is either one of `names` or an integer.
+    fn named<T: TryFrom<u64>>(&self, index: usize, names: &[&str]) -> Result<T> {
+        let s = self.str(index);
+        match names.iter().position(|&n| n == s) {
+            Some(i) => u64::try_from(i)
+                .ok()
+                .and_then(|i| T::try_from(i).ok())
+                .ok_or(Status::INVALID_PARAMETER),
+            None => parse_int(s),
+        }
+    }
+}
+
+/// Parses an integer in either hexadecimal with a `0x` prefix or decimal.
+///
+/// An empty string is parsed as 0.
+fn parse_int<T: TryFrom<u64>>(s: &str) -> Result<T> {
+    let value = if s.is_empty() {
+        Ok(0)
+    } else if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16)
+    } else {
+        s.parse()
+    };
+
+    let value = value.map_err(|_| Status::INVALID_PARAMETER)?;
+    T::try_from(value).map_err(|_| Status::INVALID_PARAMETER)
+}
+
+/// Parses pairs of hex digits into bytes.
+fn parse_hex(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.is_ascii() {
+        return Err(Status::INVALID_PARAMETER);
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| Status::INVALID_PARAMETER))
+        .collect()
+}
+
+fn parse_node(b: &mut DevicePathBuilder, text: &str) -> Result<()> {
+    // Text that is not in the form `Name(Args)` is a file path.
+    let Some((name, raw)) = text.strip_suffix(')').and_then(|t| t.split_once('(')) else {
+        b.push(&FilePath {
+            path: String::from(text),
+        });
+        return Ok(());
+    };
+
+    let a = Args(split(raw, ',').collect());
+
+    let pnp_id = match name {
+        "PciRoot" => Some(0x0A03),
+        "PcieRoot" => Some(0x0A08),
+        "Floppy" => Some(0x0604),
+        "Keyboard" => Some(0x0301),
+        "Serial" => Some(0x0501),
+        "ParallelPort" => Some(0x0401),
+        _ => None,
+    };
+
+    if let Some(id) = pnp_id {
+        b.push(&Acpi {
+            hid: eisa_pnp_id(id),
+            uid: a.int(0)?,
+        });
+        return Ok(());
+    }
+    let handled = parse_hardware(b, name, &a)?
+        || parse_messaging(b, name, &a, raw)?
+        || parse_media(b, name, &a)?
+        || parse_generic(b, name, &a)?;
+
+    if handled {
+        Ok(())
+    } else {
+        Err(Status::INVALID_PARAMETER)
+    }
+}
+
+/// Parses hardware and ACPI nodes.
+///
+/// Returns `false` if the node name is not handled.
+fn parse_hardware(b: &mut DevicePathBuilder, name: &str, a: &Args) -> Result<bool> {
+    match name {
+        "Pci" => b.push(&Pci {
+            device: a.int(0)?,
+            function: a.int(1)?,
+        }),
+        "PcCard" => b.push(&PcCard {
+            function: a.int(0)?,
+        }),
+        "MemoryMapped" => b.push(&MemoryMapped {
+            memory_type: a.int(0)?,
+            start: a.int(1)?,
+            end: a.int(2)?,
+        }),
+        "VenHw" => b.push(&VendorHardware {
+            guid: a.guid(0)?,
+            data: a.hex(1)?,
+        }),
+        "Ctrl" => b.push(&Controller {
+            controller: a.int(0)?,
+        }),
+        "BMC" => b.push(&Bmc {
+            interface_type: a.int(0)?,
+            base_address: a.int(1)?,
+        }),
+        "Acpi" => b.push(&Acpi {
+            hid: a.eisa_id(0)?,
+            uid: a.int(1)?,
+        }),
+        "AcpiEx" => b.push(&AcpiExpanded {
+            hid: a.eisa_id(0)?,
+            cid: a.eisa_id(1)?,
+            uid: a.int(2)?,
+            hid_str: String::from(a.str(3)),
+            cid_str: String::from(a.str(4)),
+            uid_str: String::from(a.str(5)),
+        }),
+        "AcpiAdr" => {
+            let adr = (0..a.0.len()).map(|i| a.int(i)).collect::<Result<_>>()?;
+            b.push(&AcpiAdr {
+                adr,
+            })
+        }
+        _ => return Ok(false),
+    };
+
+    Ok(true)
+}
+
+/// Parses messaging nodes.
+///
+/// Returns `false` if the node name is not handled.
+fn parse_messaging(b: &mut DevicePathBuilder, name: &str, a: &Args, raw: &str) -> Result<bool> {
+    match name {
+        "Ata" => b.push(&Atapi {
+            primary_secondary: a.named(0, &["Primary", "Secondary"])?,
+            slave_master: a.named(1, &["Master", "Slave"])?,
+            lun: a.int(2)?,
+        }),
+        "Scsi" => b.push(&Scsi {
+            pun: a.int(0)?,
+            lun: a.int(1)?,
+        }),
+        "USB" => b.push(&Usb {
+            parent_port: a.int(0)?,
+            interface: a.int(1)?,
+        }),
+        "VenMsg" => b.push(&VendorMessaging {
+            guid: a.guid(0)?,
+            data: a.hex(1)?,
+        }),
+        "MAC" => {
+            let bytes = a.hex(0)?;
+            let mut address = [0; 32];
+            address
+                .get_mut(..bytes.len())
+                .ok_or(Status::INVALID_PARAMETER)?
+                .copy_from_slice(&bytes);
+            b.push(&MacAddress {
+                address,
+                if_type: a.int(1)?,
+            })
+        }
+        "IPv4" => b.push(&Ipv4 {
+            remote_ip: a.ipv4(0)?,
+            protocol: match a.str(1) {
+                "TCP" => 6,
+                "UDP" => 17,
+                s => parse_int(s)?,
+            },
+            static_ip: match a.str(2) {
+                "Static" => true,
+                "DHCP" | "" => false,
+                _ => return Err(Status::INVALID_PARAMETER),
+            },
+            local_ip: a.ipv4(3)?,
+            gateway_ip: a.ipv4(4)?,
+            subnet_mask: a.ipv4(5)?,
+            local_port: 0,
+            remote_port: 0,
+        }),
+        "Uart" => b.push(&Uart {
+            baud_rate: a.named(0, &["DEFAULT"])?,
+            data_bits: a.named(1, &["DEFAULT"])?,
+            parity: a.named(2, &Uart::PARITY)?,
+            stop_bits: a.named(3, &Uart::STOP_BITS)?,
+        }),
+        "UsbClass" => b.push(&UsbClass {
+            vendor_id: a.int(0)?,
+            product_id: a.int(1)?,
+            class: a.int(2)?,
+            subclass: a.int(3)?,
+            protocol: a.int(4)?,
+        }),
+        "Sata" => b.push(&Sata {
+            hba_port: a.int(0)?,
+            port_multiplier_port: a.int(1)?,
+            lun: a.int(2)?,
+        }),
+        "Vlan" => b.push(&Vlan {
+            vlan_id: a.int(0)?,
+        }),
+        "NVMe" => {
+            let mut eui64 = [0; 8];
+            let mut digits = a.str(1).split('-');
+            // The EUI-64 is written most significant byte first.
+            for byte in eui64.iter_mut().rev() {
+                let digit = digits.next().ok_or(Status::INVALID_PARAMETER)?;
+                *byte = u8::from_str_radix(digit, 16).map_err(|_| Status::INVALID_PARAMETER)?;
+            }
+            if digits.next().is_some() {
+                return Err(Status::INVALID_PARAMETER);
+            }
+            b.push(&NvmeNamespace {
+                namespace_id: a.int(0)?,
+                eui64,
+            })
+        }
+        "Uri" => b.push(&Uri {
+            uri: String::from(raw),
+        }),
+        "SD" => b.push(&Sd {
+            slot: a.int(0)?,
+        }),
+        "eMMC" => b.push(&Emmc {
+            slot: a.int(0)?,
+        }),
+        _ => return Ok(false),
+    };
+
+    Ok(true)
+}
+
+/// Parses media and BBS nodes.
+///
+/// Returns `false` if the node name is not handled.
+fn parse_media(b: &mut DevicePathBuilder, name: &str, a: &Args) -> Result<bool> {
+    match name {
+        "HD" => {
+            let (signature, mbr_type) = match a.str(1) {
+                "MBR" => (PartitionSignature::Mbr(a.int(2)?), HardDrive::MBR_TYPE_PCAT),
+                "GPT" => (PartitionSignature::Gpt(a.guid(2)?), HardDrive::MBR_TYPE_GPT),
+                s if parse_int::<u8>(s)? == 0 => (PartitionSignature::None, 0),
+                _ => return Err(Status::INVALID_PARAMETER),
+            };
+            b.push(&HardDrive {
+                partition_number: a.int(0)?,
+                partition_start: a.int(3)?,
+                partition_size: a.int(4)?,
+                signature,
+                mbr_type,
+            })
+        }
+        "CDROM" => b.push(&CdRom {
+            boot_entry: a.int(0)?,
+            partition_start: a.int(1)?,
+            partition_size: a.int(2)?,
+        }),
+        "VenMedia" => b.push(&VendorMedia {
+            guid: a.guid(0)?,
+            data: a.hex(1)?,
+        }),
+        "Media" => b.push(&MediaProtocol {
+            protocol: a.guid(0)?,
+        }),
+        "FvFile" => b.push(&FirmwareFile {
+            name: a.guid(0)?,
+        }),
+        "Fv" => b.push(&FirmwareVolume {
+            name: a.guid(0)?,
+        }),
+        "Offset" => b.push(&RelativeOffsetRange {
+            start: a.int(0)?,
+            end: a.int(1)?,
+        }),
+        "BBS" => {
+            // Device types start at 1.
+            let device_type: u16 = match Bbs::TYPE_NAMES.iter().position(|&n| n == a.str(0)) {
+                Some(i) => u16::try_from(i + 1).map_err(|_| Status::INVALID_PARAMETER)?,
+                None => a.int(0)?,
+            };
+            b.push(&Bbs {
+                device_type,
+                description: String::from(a.str(1)),
+                status_flag: a.int(2)?,
+            })
+        }
+        _ => return Ok(false),
+    };
+
+    Ok(true)
+}
+
+/// Parses nodes in the generic `Path(Type,SubType,Data)` forms.
+///
+/// Returns `false` if the node name is not handled.
+fn parse_generic(b: &mut DevicePathBuilder, name: &str, a: &Args) -> Result<bool> {
+    if name == "Path" {
+        b.push_raw(DevicePathType::from(a.int::<u8>(0)?), a.int(1)?, &a.hex(2)?);
+        return Ok(true);
+    }
+
+    let kinds = [
+        DevicePathType::HARDWARE,
+        DevicePathType::ACPI,
+        DevicePathType::MESSAGING,
+        DevicePathType::MEDIA,
+        DevicePathType::BBS,
+    ];
+
+    match kinds.into_iter().find(|&kind| generic_name(kind) == Some(name)) {
+        Some(kind) => {
+            b.push_raw(kind, a.int(0)?, &a.hex(1)?);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn round_trip(text: &str) {
+        let path = DevicePathBuf::from_text(text).unwrap();
+        assert_eq!(path.to_string(), text);
+        assert_eq!(DevicePathBuf::from_text(&path.to_string()).unwrap(), path);
+    }
+
+    #[test]
+    fn known_nodes() {
+        round_trip("PciRoot(0x0)/Pci(0x1F,0x2)/Sata(0x0,0xFFFF,0x0)");
+        round_trip("PciRoot(0x0)/Pci(0x1D,0x0)/NVMe(0x1,00-25-38-B5-71-B3-A1-D0)");
+        round_trip("PciRoot(0x0)/Pci(0x14,0x0)/USB(0x3,0x0)/USB(0x1,0x0)");
+        round_trip(concat!(
+            "PciRoot(0x0)/Pci(0x1D,0x0)/NVMe(0x1,00-00-00-00-00-00-00-00)/",
+            "HD(1,GPT,2A9E8B3C-1D4F-4A6B-9C8D-7E6F5A4B3C2D,0x800,0x100000)/",
+            "\\EFI\\BOOT\\BOOTX64.EFI",
+        ));
+        round_trip("HD(2,MBR,0x12345678,0x800,0x1000)");
+        round_trip("Acpi(PNP0A06,0x1)/AcpiEx(HWP0002,PNP0A03,0x0,,,)");
+        round_trip(concat!(
+            "MAC(001122334455,0x1)/",
+            "IPv4(192.168.0.1,TCP,Static,192.168.0.2,192.168.0.254,255.255.255.0)",
+        ));
+        round_trip("Uart(115200,8,N,1)/Vlan(100)/VenMsg(E0C14753-F9BE-11D2-9A0C-0090273FC14D)");
+        round_trip("BBS(HD,Primary Master,0x0)");
+        round_trip(
+            "Fv(7CB8BDC9-F8EB-4F34-AAEA-3EE4AF6516A1)/FvFile(462CAA21-7614-4503-836E-8AB6F4662331)",
+        );
+    }
+
+    #[test]
+    fn multi_instance() {
+        let path = DevicePathBuf::from_text("PciRoot(0x0)/Pci(0x2,0x0),PciRoot(0x0)/Pci(0x3,0x0)")
+            .unwrap();
+        assert_eq!(path.instances().count(), 2);
+        round_trip("PciRoot(0x0)/Pci(0x2,0x0),PciRoot(0x0)/Pci(0x3,0x0)");
+    }
+
+    #[test]
+    fn generic_nodes() {
+        round_trip("HardwarePath(255,0102)/Msg(200,)/Path(9,1,ABCD)");
+        assert_eq!(
+            DevicePathBuf::from_text("Path(1,1,001F)").unwrap().to_string(),
+            "Pci(0x1F,0x0)"
+        );
+    }
+
+    #[test]
+    fn lenient_integers() {
+        let path = DevicePathBuf::from_text("PciRoot(0)/Pci(31,2)/Sata(0,0,0)").unwrap();
+        assert_eq!(path.to_string(), "PciRoot(0x0)/Pci(0x1F,0x2)/Sata(0x0,0x0,0x0)");
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(DevicePathBuf::from_text("Pci(0x100,0x0)"), Err(Status::INVALID_PARAMETER));
+        assert_eq!(DevicePathBuf::from_text("Bogus(1)"), Err(Status::INVALID_PARAMETER));
+        assert_eq!(DevicePathBuf::from_text("VenHw(not-a-guid)"), Err(Status::INVALID_PARAMETER));
+        assert_eq!(DevicePathBuf::from_text("Path(1,1,0)"), Err(Status::INVALID_PARAMETER));
+    }
+}
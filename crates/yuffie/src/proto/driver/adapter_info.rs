@@ -14,6 +14,6 @@ pub struct AdapterInformation {
     pub GetSuppportedTypes: extern "efiapi" fn(*mut Self, *mut *mut Guid, *mut usize) -> Status,
 }
 
-impl AdapterInformation {
-    pub const GUID: Guid = guid!("e5dd1403-d622-c24e-8488-c71b17f5e802");
+unsafe impl Protocol for AdapterInformation {
+    const GUID: Guid = guid!("e5dd1403-d622-c24e-8488-c71b17f5e802");
 }
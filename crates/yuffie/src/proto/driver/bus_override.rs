@@ -11,6 +11,6 @@ pub struct BusSpecificDriverOverride {
     pub GetDriver: extern "efiapi" fn(*mut Self, *mut Handle) -> Status,
 }
 
-impl BusSpecificDriverOverride {
-    pub const GUID: Guid = guid!("3bc1b285-8a15-4a82-aabf-4d7d13fb3265");
+unsafe impl Protocol for BusSpecificDriverOverride {
+    const GUID: Guid = guid!("3bc1b285-8a15-4a82-aabf-4d7d13fb3265");
 }
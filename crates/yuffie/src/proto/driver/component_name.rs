@@ -11,9 +11,9 @@ use crate::prelude::*;
 pub struct ComponentName2 {
     pub GetDriverName: extern "efiapi" fn(*mut Self, *const u8, *mut *mut u16) -> Status,
     pub GetControllerName: extern "efiapi" fn(*mut Self, Handle, Option<Handle>, *const u8, *mut *mut u16) -> Status,
-    pub SupportedLanguages: extern "efiapi" fn() -> Status,
+    pub SupportedLanguages: *const u8,
 }
 
-impl ComponentName2 {
-    pub const GUID: Guid = guid!("6a7a5cff-e8d9-4f70-bada-75ab3025ce14");
+unsafe impl Protocol for ComponentName2 {
+    const GUID: Guid = guid!("6a7a5cff-e8d9-4f70-bada-75ab3025ce14");
 }
@@ -33,6 +33,6 @@ pub struct DriverDiagnostics2 {
     pub SupportedLanguages: *mut u8,
 }
 
-impl DriverDiagnostics2 {
-    pub const GUID: Guid = guid!("4d330321-025f-4aac-90d8-5ed900173b63");
+unsafe impl Protocol for DriverDiagnostics2 {
+    const GUID: Guid = guid!("4d330321-025f-4aac-90d8-5ed900173b63");
 }
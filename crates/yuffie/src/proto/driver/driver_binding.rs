@@ -4,18 +4,19 @@
 //! Driver binding protocol
 
 use crate::prelude::*;
+use crate::proto::device_path::DevicePathProtocol;
 
 /// `EFI_DRIVER_BINDING_PROTOCOL`
 #[repr(C)]
 pub struct DriverBinding {
-    pub Supported: extern "efiapi" fn(*mut Self, Handle, *mut u8) -> Status,
-    pub Start: extern "efiapi" fn(*mut Self, Handle, *mut u8) -> Status,
-    pub Stop: extern "efiapi" fn(*mut Self, Handle, usize, Option<Handle>) -> Status,
+    pub Supported: extern "efiapi" fn(*mut Self, Handle, *const DevicePathProtocol) -> Status,
+    pub Start: extern "efiapi" fn(*mut Self, Handle, *const DevicePathProtocol) -> Status,
+    pub Stop: extern "efiapi" fn(*mut Self, Handle, usize, *const Handle) -> Status,
     pub Version: u32,
     pub ImageHandle: Handle,
     pub DriverBindingHandle: Handle,
 }
 
-impl DriverBinding {
-    pub const GUID: Guid = guid!("18a031ab-b443-4d1a-a5c0-0c09261e9f71");
+unsafe impl Protocol for DriverBinding {
+    const GUID: Guid = guid!("18a031ab-b443-4d1a-a5c0-0c09261e9f71");
 }
@@ -11,6 +11,6 @@ pub struct DriverFamilyOverride {
     pub GetVersion: extern "efiapi" fn(*const Self) -> Status,
 }
 
-impl DriverFamilyOverride {
-    pub const GUID: Guid = guid!("b1ee129e-da36-4181-91f8-04a4923766a7");
+unsafe impl Protocol for DriverFamilyOverride {
+    const GUID: Guid = guid!("b1ee129e-da36-4181-91f8-04a4923766a7");
 }
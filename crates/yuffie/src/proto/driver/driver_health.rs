@@ -48,6 +48,6 @@ pub struct DriverHealth {
     ) -> Status,
 }
 
-impl DriverHealth {
-    pub const GUID: Guid = guid!("2a534210-9280-41d8-ae79-cada01a2b127");
+unsafe impl Protocol for DriverHealth {
+    const GUID: Guid = guid!("2a534210-9280-41d8-ae79-cada01a2b127");
 }
@@ -12,6 +12,17 @@ pub struct DriverSupportedUefiVersion {
     pub FirmwareVersion: u32,
 }
 
+unsafe impl Protocol for DriverSupportedUefiVersion {
+    const GUID: Guid = guid!("5c198761-16a8-4e69-972c-89d67954f81d");
+}
+
 impl DriverSupportedUefiVersion {
-    pub const GUID: Guid = guid!("5c198761-16a8-4e69-972c-89d67954f81d");
+    /// Creates the protocol for a driver that follows `firmware_version` of
+    /// the UEFI specification.
+    pub const fn new(firmware_version: u32) -> Self {
+        Self {
+            Length: 8,
+            FirmwareVersion: firmware_version,
+        }
+    }
 }
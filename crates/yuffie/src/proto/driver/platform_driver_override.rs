@@ -4,15 +4,18 @@
 //! Platform driver override protocol
 
 use crate::prelude::*;
+use crate::proto::device_path::DevicePathProtocol;
 
 /// `EFI_PLATFORM_DRIVER_OVERRIDE_PROTOCOL`
 #[repr(C)]
 pub struct PlatformDriverOverride {
     pub GetDriver: extern "efiapi" fn(*mut Self, Handle, *mut Handle) -> Status,
-    pub GetDriverPath: extern "efiapi" fn(*mut Self, Handle, *mut u8) -> Status,
-    pub DriverLoaded: extern "efiapi" fn(*mut Self, Handle, *mut u8, Handle) -> Status,
+    pub GetDriverPath:
+        extern "efiapi" fn(*mut Self, Handle, *mut *mut DevicePathProtocol) -> Status,
+    pub DriverLoaded:
+        extern "efiapi" fn(*mut Self, Handle, *const DevicePathProtocol, Handle) -> Status,
 }
 
-impl PlatformDriverOverride {
-    pub const GUID: Guid = guid!("6b30c738-a391-11d4-9a3b-0090273fc14d");
+unsafe impl Protocol for PlatformDriverOverride {
+    const GUID: Guid = guid!("6b30c738-a391-11d4-9a3b-0090273fc14d");
 }
@@ -43,6 +43,6 @@ pub struct PlatformToDriverConfig {
     ) -> Status,
 }
 
-impl PlatformToDriverConfig {
-    pub const GUID: Guid = guid!("642cd590-8059-4c0a-a958-c5ec07d23c4b");
+unsafe impl Protocol for PlatformToDriverConfig {
+    const GUID: Guid = guid!("642cd590-8059-4c0a-a958-c5ec07d23c4b");
 }
@@ -0,0 +1,383 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # FMP Capsules
+//!
+//! An FMP capsule is a UEFI capsule, identified by [`FMP_CAPSULE`], whose body
+//! is an `EFI_FIRMWARE_MANAGEMENT_CAPSULE_HEADER` followed by optional
+//! drivers and the payloads passed to `SetImage()`. Each payload starts with
+//! an `EFI_FIRMWARE_MANAGEMENT_CAPSULE_IMAGE_HEADER`.
+//!
+//! ```text
+//! CapsuleHeader
+//! FmpCapsuleHeader { Version, EmbeddedDriverCount, PayloadItemCount }
+//! ItemOffsetList[EmbeddedDriverCount + PayloadItemCount]
+//! Driver...
+//! FmpCapsuleImageHeader, Image, VendorCode...
+//! ```
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 23.3: Delivering Capsules Containing Updates to Firmware Management
+//!     Protocol
+
+use alloc::vec::Vec;
+use core::ops;
+
+use crate::bytes::Reader;
+use crate::prelude::*;
+use crate::table::runtime::CapsuleFlags;
+use crate::table::runtime::CapsuleHeader;
+
+/// `EFI_FIRMWARE_MANAGEMENT_CAPSULE_ID_GUID`
+pub const FMP_CAPSULE: Guid = guid!("6dcbd5ed-e82d-4c44-bda1-7194199ad92a");
+
+/// The version of `EFI_FIRMWARE_MANAGEMENT_CAPSULE_HEADER`.
+const FMP_CAPSULE_VERSION: u32 = 1;
+/// The version of `EFI_FIRMWARE_MANAGEMENT_CAPSULE_IMAGE_HEADER` written.
+const IMAGE_HEADER_VERSION: u32 = 3;
+/// The size of the capsule header written, padded so the FMP header and its
+/// offset list are aligned.
+const HEADER_SIZE: u32 = 32;
+
+/// Flags for [`FmpPayload::image_capsule_support`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ImageCapsuleSupport(u64);
+
+impl ImageCapsuleSupport {
+    /// The image is signed.
+    pub const AUTHENTICATION: Self = Self(1 << 0);
+    /// The image has dependencies on other images.
+    pub const DEPENDENCY: Self = Self(1 << 1);
+
+    /// Returns `true` if all of the flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl ops::BitOr for ImageCapsuleSupport {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// A payload of an FMP capsule, passed to `SetImage()` of the FMP instance
+/// that manages images of the given type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FmpPayload {
+    /// The type of image, matching the `image_type_id` of a descriptor.
+    pub image_type_id: Guid,
+    /// The index of the image to update, starting at 1.
+    pub image_index: u8,
+    /// The hardware instance to update, or 0 to update all instances.
+    pub hardware_instance: u64,
+    pub image_capsule_support: ImageCapsuleSupport,
+    pub image: Vec<u8>,
+    pub vendor_code: Vec<u8>,
+}
+
+impl FmpPayload {
+    /// Parses a payload, starting with its image header.
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(data);
+        let version = r.u32()?;
+        if version == 0 {
+            return None;
+        }
+
+        let image_type_id = r.guid()?;
+        let image_index = r.u8()?;
+        let _reserved = r.bytes(3)?;
+        let image_size = usize::try_from(r.u32()?).ok()?;
+        let vendor_code_size = usize::try_from(r.u32()?).ok()?;
+        let hardware_instance = if version >= 2 {
+            r.u64()?
+        } else {
+            0
+        };
+        let image_capsule_support = if version >= 3 {
+            ImageCapsuleSupport(r.u64()?)
+        } else {
+            ImageCapsuleSupport::default()
+        };
+
+        Some(Self {
+            image_type_id,
+            image_index,
+            hardware_instance,
+            image_capsule_support,
+            image: r.bytes(image_size)?.to_vec(),
+            vendor_code: r.bytes(vendor_code_size)?.to_vec(),
+        })
+    }
+
+    fn write(&self, buf: &mut Vec<u8>) {
+        let image_size = u32::try_from(self.image.len()).expect("FMP image too large");
+        let vendor_code_size =
+            u32::try_from(self.vendor_code.len()).expect("FMP vendor code too large");
+
+        buf.extend_from_slice(&IMAGE_HEADER_VERSION.to_le_bytes());
+        buf.extend_from_slice(&self.image_type_id.to_bytes());
+        buf.extend_from_slice(&[self.image_index, 0, 0, 0]);
+        buf.extend_from_slice(&image_size.to_le_bytes());
+        buf.extend_from_slice(&vendor_code_size.to_le_bytes());
+        buf.extend_from_slice(&self.hardware_instance.to_le_bytes());
+        buf.extend_from_slice(&self.image_capsule_support.0.to_le_bytes());
+        buf.extend_from_slice(&self.image);
+        buf.extend_from_slice(&self.vendor_code);
+    }
+}
+
+/// A capsule containing updates for Firmware Management Protocol instances.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FmpCapsule {
+    /// The flags of the UEFI capsule header.
+    pub flags: CapsuleFlags,
+    /// UEFI drivers loaded before the payloads are processed.
+    pub drivers: Vec<Vec<u8>>,
+    pub payloads: Vec<FmpPayload>,
+}
+
+impl FmpCapsule {
+    /// Parses an FMP capsule, including its UEFI capsule header.
+    ///
+    /// # Errors
+    ///
+    /// - `UNSUPPORTED`: The capsule is not an FMP capsule.
+    /// - `INCOMPATIBLE_VERSION`: The FMP capsule header is not version 1.
+    /// - `INVALID_PARAMETER`: The capsule is truncated or malformed.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let guid = r.guid().ok_or(Status::INVALID_PARAMETER)?;
+        let header_size = r.u32().ok_or(Status::INVALID_PARAMETER)? as usize;
+        let flags = r.u32().ok_or(Status::INVALID_PARAMETER)?;
+        let image_size = r.u32().ok_or(Status::INVALID_PARAMETER)? as usize;
+
+        if guid != FMP_CAPSULE {
+            return Err(Status::UNSUPPORTED);
+        }
+        if header_size < size_of::<CapsuleHeader>()
+            || header_size > image_size
+            || image_size > data.len()
+        {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        let mut capsule = Self::from_body(&data[header_size..image_size])?;
+        capsule.flags = CapsuleFlags::from(flags);
+        Ok(capsule)
+    }
+
+    /// Parses the body of an FMP capsule, after its UEFI capsule header.
+    ///
+    /// # Errors
+    ///
+    /// - `INCOMPATIBLE_VERSION`: The FMP capsule header is not version 1.
+    /// - `INVALID_PARAMETER`: The body is truncated or malformed.
+    pub fn from_body(body: &[u8]) -> Result<Self> {
+        let mut r = Reader::new(body);
+        let version = r.u32().ok_or(Status::INVALID_PARAMETER)?;
+        if version != FMP_CAPSULE_VERSION {
+            return Err(Status::INCOMPATIBLE_VERSION);
+        }
+
+        let driver_count = usize::from(r.u16().ok_or(Status::INVALID_PARAMETER)?);
+        let payload_count = usize::from(r.u16().ok_or(Status::INVALID_PARAMETER)?);
+
+        let mut offsets = Vec::with_capacity(driver_count + payload_count);
+        for _ in 0..driver_count + payload_count {
+            let offset = r.u64().ok_or(Status::INVALID_PARAMETER)?;
+            let offset = usize::try_from(offset).map_err(|_| Status::INVALID_PARAMETER)?;
+            if offset > body.len() || offsets.last().is_some_and(|&prev| offset < prev) {
+                return Err(Status::INVALID_PARAMETER);
+            }
+            offsets.push(offset);
+        }
+
+        // Each item extends to the start of the next, or the end of the body.
+        let item = |i: usize| {
+            let end = offsets.get(i + 1).copied().unwrap_or(body.len());
+            &body[offsets[i]..end]
+        };
+
+        let drivers = (0..driver_count).map(|i| item(i).to_vec()).collect();
+        let payloads = (driver_count..driver_count + payload_count)
+            .map(|i| FmpPayload::parse(item(i)).ok_or(Status::INVALID_PARAMETER))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            flags: CapsuleFlags::from(0),
+            drivers,
+            payloads,
+        })
+    }
+
+    /// Serializes the capsule, including its UEFI capsule header.
+    ///
+    /// Payloads are written with version 3 image headers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capsule is larger than 4 GiB, or has more than 65535
+    /// drivers or payloads.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let body = self.body_bytes();
+        let header_size = HEADER_SIZE as usize;
+        let image_size = u32::try_from(header_size + body.len()).expect("FMP capsule too large");
+
+        let mut data = Vec::with_capacity(header_size + body.len());
+        data.extend_from_slice(&FMP_CAPSULE.to_bytes());
+        data.extend_from_slice(&HEADER_SIZE.to_le_bytes());
+        data.extend_from_slice(&u32::from(self.flags).to_le_bytes());
+        data.extend_from_slice(&image_size.to_le_bytes());
+        data.resize(header_size, 0);
+        data.extend_from_slice(&body);
+        data
+    }
+
+    /// Serializes the body of the capsule, after its UEFI capsule header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capsule has more than 65535 drivers or payloads.
+    pub fn body_bytes(&self) -> Vec<u8> {
+        let driver_count = u16::try_from(self.drivers.len()).expect("too many FMP drivers");
+        let payload_count = u16::try_from(self.payloads.len()).expect("too many FMP payloads");
+        let count = self.drivers.len() + self.payloads.len();
+
+        let mut body = Vec::new();
+        body.extend_from_slice(&FMP_CAPSULE_VERSION.to_le_bytes());
+        body.extend_from_slice(&driver_count.to_le_bytes());
+        body.extend_from_slice(&payload_count.to_le_bytes());
+        // The offsets are filled in as the items are written.
+        body.resize(body.len() + count * size_of::<u64>(), 0);
+
+        for (i, driver) in self.drivers.iter().enumerate() {
+            set_item_offset(&mut body, i);
+            body.extend_from_slice(driver);
+        }
+        for (i, payload) in self.payloads.iter().enumerate() {
+            set_item_offset(&mut body, self.drivers.len() + i);
+            payload.write(&mut body);
+        }
+
+        body
+    }
+
+    /// Returns the payloads for images of the given type.
+    pub fn payloads_for<'a>(
+        &'a self,
+        image_type_id: &'a Guid,
+    ) -> impl Iterator<Item = &'a FmpPayload> {
+        self.payloads.iter().filter(move |p| p.image_type_id == *image_type_id)
+    }
+}
+
+/// Sets the offset of an item in the offset list to the end of the body.
+fn set_item_offset(body: &mut [u8], index: usize) {
+    let offset = (body.len() as u64).to_le_bytes();
+    let entry = 8 + index * size_of::<u64>();
+    body[entry..entry + 8].copy_from_slice(&offset);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const IMAGE_TYPE: Guid = guid!("b2f84b79-7b6e-4e45-885f-3fb9bb185402");
+
+    fn capsule() -> FmpCapsule {
+        FmpCapsule {
+            flags: CapsuleFlags::PERSIST_ACROSS_RESET,
+            drivers: alloc::vec![alloc::vec![0xAA; 5]],
+            payloads: alloc::vec![
+                FmpPayload {
+                    image_type_id: IMAGE_TYPE,
+                    image_index: 1,
+                    hardware_instance: 0,
+                    image_capsule_support: ImageCapsuleSupport::AUTHENTICATION
+                        | ImageCapsuleSupport::DEPENDENCY,
+                    image: alloc::vec![1, 2, 3],
+                    vendor_code: Vec::new(),
+                },
+                FmpPayload {
+                    image_type_id: Guid::NULL,
+                    image_index: 2,
+                    hardware_instance: 7,
+                    image_capsule_support: ImageCapsuleSupport::default(),
+                    image: alloc::vec![4; 10],
+                    vendor_code: alloc::vec![9, 9],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let capsule = capsule();
+        let data = capsule.to_bytes();
+
+        assert_eq!(Guid::from_bytes(data[..16].try_into().unwrap()), FMP_CAPSULE);
+        assert_eq!(u32::from_le_bytes(data[16..20].try_into().unwrap()), 32);
+        assert_eq!(u32::from_le_bytes(data[24..28].try_into().unwrap()) as usize, data.len());
+
+        // The first item follows the offset list of 3 items.
+        let body = &data[32..];
+        assert_eq!(u64::from_le_bytes(body[8..16].try_into().unwrap()), 32);
+        assert_eq!(u64::from_le_bytes(body[16..24].try_into().unwrap()), 37);
+
+        assert_eq!(FmpCapsule::from_bytes(&data), Ok(capsule.clone()));
+        assert_eq!(capsule.payloads_for(&IMAGE_TYPE).count(), 1);
+        assert!(
+            capsule.payloads[0].image_capsule_support.contains(ImageCapsuleSupport::DEPENDENCY)
+        );
+    }
+
+    #[test]
+    fn version_1_image_header() {
+        let mut body = Vec::new();
+        body.extend_from_slice(&1u32.to_le_bytes());
+        body.extend_from_slice(&0u16.to_le_bytes());
+        body.extend_from_slice(&1u16.to_le_bytes());
+        body.extend_from_slice(&16u64.to_le_bytes());
+        body.extend_from_slice(&1u32.to_le_bytes());
+        body.extend_from_slice(&IMAGE_TYPE.to_bytes());
+        body.extend_from_slice(&[3, 0, 0, 0]);
+        body.extend_from_slice(&2u32.to_le_bytes());
+        body.extend_from_slice(&0u32.to_le_bytes());
+        body.extend_from_slice(&[0xDE, 0xAD]);
+
+        let capsule = FmpCapsule::from_body(&body).unwrap();
+        assert!(capsule.drivers.is_empty());
+        assert_eq!(capsule.payloads[0].image_index, 3);
+        assert_eq!(capsule.payloads[0].hardware_instance, 0);
+        assert_eq!(capsule.payloads[0].image_capsule_support, ImageCapsuleSupport::default());
+        assert_eq!(capsule.payloads[0].image, [0xDE, 0xAD]);
+    }
+
+    #[test]
+    fn invalid() {
+        let data = capsule().to_bytes();
+
+        let mut other = data.clone();
+        other[0] ^= 1;
+        assert_eq!(FmpCapsule::from_bytes(&other), Err(Status::UNSUPPORTED));
+
+        assert_eq!(FmpCapsule::from_bytes(&data[..data.len() - 1]), Err(Status::INVALID_PARAMETER));
+
+        let mut other = data.clone();
+        other[32] = 2;
+        assert_eq!(FmpCapsule::from_bytes(&other), Err(Status::INCOMPATIBLE_VERSION));
+
+        // An image size past the end of the last payload.
+        let mut body = data[32..].to_vec();
+        let payload = usize::from(body[24]);
+        body[payload + 24] = 0xFF;
+        assert_eq!(FmpCapsule::from_body(&body), Err(Status::INVALID_PARAMETER));
+    }
+}
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # EFI System Resource Table
+//!
+//! The ESRT is a configuration table, identified by [`SYSTEM_RESOURCE_TABLE`],
+//! that lists the firmware resources that can be updated with capsules, and
+//! the result of the last update attempt of each.
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 23.4: EFI System Resource Table
+
+use alloc::vec::Vec;
+
+use super::LastAttemptStatus;
+use crate::bytes::Reader;
+use crate::prelude::*;
+
+/// `EFI_SYSTEM_RESOURCE_TABLE_GUID`
+pub const SYSTEM_RESOURCE_TABLE: Guid = crate::table::cfg::ESRT_TABLE;
+
+/// The version of `EFI_SYSTEM_RESOURCE_TABLE`.
+const ESRT_VERSION: u64 = 1;
+/// The size of the table header.
+const HEADER_SIZE: usize = 16;
+/// The size of `EFI_SYSTEM_RESOURCE_ENTRY`.
+const ENTRY_SIZE: usize = 40;
+
+/// The type of a firmware resource.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct FirmwareType(u32);
+
+impl FirmwareType {
+    pub const UNKNOWN: Self = Self(0);
+    pub const SYSTEM_FIRMWARE: Self = Self(1);
+    pub const DEVICE_FIRMWARE: Self = Self(2);
+    pub const UEFI_DRIVER: Self = Self(3);
+}
+
+/// `EFI_SYSTEM_RESOURCE_ENTRY`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SystemResourceEntry {
+    /// The firmware class, matching the `image_type_id` of the FMP image
+    /// descriptor and the FMP capsule payload.
+    pub fw_class: Guid,
+    pub fw_type: FirmwareType,
+    pub fw_version: u32,
+    pub lowest_supported_fw_version: u32,
+    /// The capsule flags required to update the resource.
+    pub capsule_flags: u32,
+    pub last_attempt_version: u32,
+    pub last_attempt_status: LastAttemptStatus,
+}
+
+impl SystemResourceEntry {
+    fn parse(r: &mut Reader) -> Option<Self> {
+        Some(Self {
+            fw_class: r.guid()?,
+            fw_type: FirmwareType(r.u32()?),
+            fw_version: r.u32()?,
+            lowest_supported_fw_version: r.u32()?,
+            capsule_flags: r.u32()?,
+            last_attempt_version: r.u32()?,
+            last_attempt_status: LastAttemptStatus::from(r.u32()?),
+        })
+    }
+
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.fw_class.to_bytes());
+        buf.extend_from_slice(&self.fw_type.0.to_le_bytes());
+        buf.extend_from_slice(&self.fw_version.to_le_bytes());
+        buf.extend_from_slice(&self.lowest_supported_fw_version.to_le_bytes());
+        buf.extend_from_slice(&self.capsule_flags.to_le_bytes());
+        buf.extend_from_slice(&self.last_attempt_version.to_le_bytes());
+        buf.extend_from_slice(&u32::from(self.last_attempt_status).to_le_bytes());
+    }
+}
+
+/// `EFI_SYSTEM_RESOURCE_TABLE`
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SystemResourceTable {
+    /// The number of entries the table has space for. When serialized, this
+    /// is raised to at least the number of entries.
+    pub count_max: u32,
+    pub entries: Vec<SystemResourceEntry>,
+}
+
+impl SystemResourceTable {
+    /// Parses a table.
+    ///
+    /// # Errors
+    ///
+    /// - `INCOMPATIBLE_VERSION`: The table is not version 1.
+    /// - `INVALID_PARAMETER`: The table is truncated, or has more entries than
+    ///   its maximum.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let count = r.u32().ok_or(Status::INVALID_PARAMETER)?;
+        let count_max = r.u32().ok_or(Status::INVALID_PARAMETER)?;
+        let version = r.u64().ok_or(Status::INVALID_PARAMETER)?;
+
+        if version != ESRT_VERSION {
+            return Err(Status::INCOMPATIBLE_VERSION);
+        }
+        if count > count_max {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        let entries = (0..count)
+            .map(|_| SystemResourceEntry::parse(&mut r).ok_or(Status::INVALID_PARAMETER))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            count_max,
+            entries,
+        })
+    }
+
+    /// Parses a table installed as a configuration table.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a table with a valid header, followed by the
+    /// number of entries it gives.
+    ///
+    /// # Errors
+    ///
+    /// - The errors of [`SystemResourceTable::from_bytes()`].
+    pub unsafe fn from_ptr(ptr: *const u8) -> Result<Self> {
+        let header = unsafe { core::slice::from_raw_parts(ptr, HEADER_SIZE) };
+        let count = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        let size = HEADER_SIZE + count as usize * ENTRY_SIZE;
+        Self::from_bytes(unsafe { core::slice::from_raw_parts(ptr, size) })
+    }
+
+    /// Serializes the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has more than `u32::MAX` entries.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let count = u32::try_from(self.entries.len()).expect("too many ESRT entries");
+
+        let mut data = Vec::with_capacity(HEADER_SIZE + self.entries.len() * ENTRY_SIZE);
+        data.extend_from_slice(&count.to_le_bytes());
+        data.extend_from_slice(&self.count_max.max(count).to_le_bytes());
+        data.extend_from_slice(&ESRT_VERSION.to_le_bytes());
+        for entry in &self.entries {
+            entry.write(&mut data);
+        }
+        data
+    }
+
+    /// Returns the entry for a firmware class.
+    pub fn find(&self, fw_class: &Guid) -> Option<&SystemResourceEntry> {
+        self.entries.iter().find(|e| e.fw_class == *fw_class)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FW_CLASS: Guid = guid!("b2f84b79-7b6e-4e45-885f-3fb9bb185402");
+
+    /// A table with one system firmware entry, as installed by firmware.
+    #[rustfmt::skip]
+    const ESRT: &[u8] = &[
+        0x01, 0x00, 0x00, 0x00, // FwResourceCount
+        0x02, 0x00, 0x00, 0x00, // FwResourceCountMax
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // FwResourceVersion
+        0x79, 0x4B, 0xF8, 0xB2, 0x6E, 0x7B, 0x45, 0x4E,
+        0x88, 0x5F, 0x3F, 0xB9, 0xBB, 0x18, 0x54, 0x02, // FwClass
+        0x01, 0x00, 0x00, 0x00, // FwType
+        0x05, 0x00, 0x00, 0x00, // FwVersion
+        0x02, 0x00, 0x00, 0x00, // LowestSupportedFwVersion
+        0x00, 0x00, 0x01, 0x00, // CapsuleFlags
+        0x04, 0x00, 0x00, 0x00, // LastAttemptVersion
+        0x03, 0x00, 0x00, 0x00, // LastAttemptStatus
+    ];
+
+    #[test]
+    fn parse() {
+        let esrt = SystemResourceTable::from_bytes(ESRT).unwrap();
+        assert_eq!(esrt.count_max, 2);
+
+        let entry = esrt.find(&FW_CLASS).unwrap();
+        assert_eq!(entry.fw_type, FirmwareType::SYSTEM_FIRMWARE);
+        assert_eq!(entry.fw_version, 5);
+        assert_eq!(entry.lowest_supported_fw_version, 2);
+        assert_eq!(entry.capsule_flags, 1 << 16);
+        assert_eq!(entry.last_attempt_version, 4);
+        assert_eq!(entry.last_attempt_status, LastAttemptStatus::ERROR_INCORRECT_VERSION);
+
+        assert_eq!(esrt.to_bytes(), ESRT);
+        assert_eq!(unsafe { SystemResourceTable::from_ptr(ESRT.as_ptr()) }, Ok(esrt));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            SystemResourceTable::from_bytes(&ESRT[..ESRT.len() - 1]),
+            Err(Status::INVALID_PARAMETER)
+        );
+
+        let mut data = ESRT.to_vec();
+        data[8] = 2;
+        assert_eq!(SystemResourceTable::from_bytes(&data), Err(Status::INCOMPATIBLE_VERSION));
+
+        let mut data = ESRT.to_vec();
+        data[4] = 0;
+        assert_eq!(SystemResourceTable::from_bytes(&data), Err(Status::INVALID_PARAMETER));
+    }
+}
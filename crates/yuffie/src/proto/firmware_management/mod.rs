@@ -0,0 +1,473 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Firmware Management Protocol
+//!
+//! The Firmware Management Protocol (FMP) is installed by drivers that can
+//! read and update the firmware images of a device. Updates are usually
+//! delivered in an FMP capsule, described in [`capsule`], and are reported in
+//! the EFI System Resource Table, described in [`esrt`].
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 23.1: Firmware Management Protocol
+//!   - 23.3: Delivering Capsules Containing Updates to Firmware Management
+//!     Protocol
+//!   - 23.4: EFI System Resource Table
+
+#[cfg(feature = "alloc")]
+pub mod capsule;
+#[cfg(feature = "alloc")]
+pub mod esrt;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ops;
+#[cfg(feature = "alloc")]
+use core::sync::atomic::AtomicPtr;
+#[cfg(feature = "alloc")]
+use core::sync::atomic::Ordering;
+
+use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use crate::string::CString16;
+#[cfg(feature = "alloc")]
+use crate::table::BootServices;
+
+/// `EFI_FIRMWARE_MANAGEMENT_UPDATE_IMAGE_PROGRESS`
+pub type UpdateImageProgressFn = extern "efiapi" fn(usize) -> Status;
+
+/// Attributes of a firmware image.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ImageAttributes(u64);
+
+impl ImageAttributes {
+    /// The image can be updated with `SetImage()`.
+    pub const IMAGE_UPDATABLE: Self = Self(1 << 0);
+    /// A reset is required for a new image to take effect.
+    pub const RESET_REQUIRED: Self = Self(1 << 1);
+    /// Updating the image requires authentication.
+    pub const AUTHENTICATION_REQUIRED: Self = Self(1 << 2);
+    /// The image is in use.
+    pub const IN_USE: Self = Self(1 << 3);
+    /// The image is a UEFI image.
+    pub const UEFI_IMAGE: Self = Self(1 << 4);
+    /// The image has dependencies on other images.
+    pub const DEPENDENCY: Self = Self(1 << 5);
+
+    /// Returns `true` if all of the attributes in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl ops::BitOr for ImageAttributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The result of `CheckImage()`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ImageUpdatable(u32);
+
+impl ImageUpdatable {
+    /// The image is valid for the device.
+    pub const VALID: Self = Self(1 << 0);
+    /// The image is not valid for the device.
+    pub const INVALID: Self = Self(1 << 1);
+    /// The image is not for the type of device.
+    pub const INVALID_TYPE: Self = Self(1 << 2);
+    /// The image is older than the lowest supported version.
+    pub const INVALID_OLD: Self = Self(1 << 3);
+    /// The image is valid only when used with vendor code.
+    pub const VALID_WITH_VENDOR_CODE: Self = Self(1 << 4);
+
+    /// Returns `true` if all of the flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// The status of the last attempt to update a firmware image.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct LastAttemptStatus(u32);
+
+impl LastAttemptStatus {
+    pub const SUCCESS: Self = Self(0);
+    pub const ERROR_UNSUCCESSFUL: Self = Self(1);
+    pub const ERROR_INSUFFICIENT_RESOURCES: Self = Self(2);
+    pub const ERROR_INCORRECT_VERSION: Self = Self(3);
+    pub const ERROR_INVALID_FORMAT: Self = Self(4);
+    pub const ERROR_AUTH_ERROR: Self = Self(5);
+    pub const ERROR_PWR_EVT_AC: Self = Self(6);
+    pub const ERROR_PWR_EVT_BATT: Self = Self(7);
+    pub const ERROR_UNSATISFIED_DEPENDENCIES: Self = Self(8);
+}
+
+impl From<u32> for LastAttemptStatus {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<LastAttemptStatus> for u32 {
+    fn from(value: LastAttemptStatus) -> Self {
+        value.0
+    }
+}
+
+/// `EFI_FIRMWARE_IMAGE_DESCRIPTOR`, version 4
+///
+/// Fields added after version 1 are only valid if the descriptor version
+/// returned by `GetImageInfo()` includes them.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct FirmwareImageDescriptor {
+    pub ImageIndex: u8,
+    pub ImageTypeId: Guid,
+    pub ImageId: u64,
+    pub ImageIdName: *const u16,
+    pub Version: u32,
+    pub VersionName: *const u16,
+    pub Size: usize,
+    pub AttributesSupported: ImageAttributes,
+    pub AttributesSetting: ImageAttributes,
+    pub Compatibilities: u64,
+    // Version 2
+    pub LowestSupportedImageVersion: u32,
+    // Version 3
+    pub LastAttemptVersion: u32,
+    pub LastAttemptStatus: LastAttemptStatus,
+    pub HardwareInstance: u64,
+    // Version 4
+    pub Dependencies: *const u8,
+}
+
+/// `EFI_FIRMWARE_MANAGEMENT_PROTOCOL`
+#[rustfmt::skip]
+#[repr(C)]
+pub struct FirmwareManagement {
+    pub GetImageInfo: extern "efiapi" fn(*mut Self, *mut usize, *mut FirmwareImageDescriptor, *mut u32, *mut u8, *mut usize, *mut u32, *mut *mut u16) -> Status,
+    pub GetImage: extern "efiapi" fn(*mut Self, u8, *mut c_void, *mut usize) -> Status,
+    pub SetImage: extern "efiapi" fn(*mut Self, u8, *const c_void, usize, *const c_void, Option<UpdateImageProgressFn>, *mut *mut u16) -> Status,
+    pub CheckImage: extern "efiapi" fn(*mut Self, u8, *const c_void, usize, *mut ImageUpdatable) -> Status,
+    pub GetPackageInfo: extern "efiapi" fn(*mut Self, *mut u32, *mut *mut u16, *mut u32, *mut u64, *mut u64) -> Status,
+    pub SetPackageInfo: extern "efiapi" fn(*mut Self, *const c_void, usize, *const c_void, u32, *const u16) -> Status,
+}
+
+unsafe impl Protocol for FirmwareManagement {
+    const GUID: Guid = guid!("86c77a67-0b97-4633-a187-49104d0685c7");
+}
+
+/// The images of a device, returned by `GetImageInfo()`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ImageInfo {
+    /// The version of the descriptors returned by the driver.
+    pub descriptor_version: u32,
+    /// The version of the package containing all images.
+    pub package_version: u32,
+    pub package_version_name: Option<CString16>,
+    pub descriptors: Vec<ImageDescriptor>,
+}
+
+/// An owned copy of a [`FirmwareImageDescriptor`].
+///
+/// Fields that are not present in the descriptor version returned by the
+/// driver are 0.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ImageDescriptor {
+    /// The index used to select the image in other functions, starting at 1.
+    pub image_index: u8,
+    /// Identifies the type of firmware, matching `fw_class` in the ESRT.
+    pub image_type_id: Guid,
+    /// A unique identifier of the image within the device.
+    pub image_id: u64,
+    pub image_id_name: Option<CString16>,
+    pub version: u32,
+    pub version_name: Option<CString16>,
+    /// The size of the image in bytes, or 0 if it cannot be read.
+    pub size: usize,
+    pub attributes_supported: ImageAttributes,
+    pub attributes_setting: ImageAttributes,
+    pub compatibilities: u64,
+    pub lowest_supported_image_version: u32,
+    pub last_attempt_version: u32,
+    pub last_attempt_status: LastAttemptStatus,
+    pub hardware_instance: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl ImageDescriptor {
+    /// Copies a descriptor returned by the driver.
+    ///
+    /// # Safety
+    ///
+    /// The string pointers of `desc` must be null or point to NUL-terminated
+    /// strings.
+    unsafe fn from_raw(desc: &FirmwareImageDescriptor) -> Self {
+        Self {
+            image_index: desc.ImageIndex,
+            image_type_id: desc.ImageTypeId,
+            image_id: desc.ImageId,
+            image_id_name: unsafe { copy_string(desc.ImageIdName) },
+            version: desc.Version,
+            version_name: unsafe { copy_string(desc.VersionName) },
+            size: desc.Size,
+            attributes_supported: desc.AttributesSupported,
+            attributes_setting: desc.AttributesSetting,
+            compatibilities: desc.Compatibilities,
+            lowest_supported_image_version: desc.LowestSupportedImageVersion,
+            last_attempt_version: desc.LastAttemptVersion,
+            last_attempt_status: desc.LastAttemptStatus,
+            hardware_instance: desc.HardwareInstance,
+        }
+    }
+}
+
+/// Copies a string returned by a driver.
+#[cfg(feature = "alloc")]
+unsafe fn copy_string(ptr: *const u16) -> Option<CString16> {
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { CStr16::from_ptr(ptr) }.ok().map(ToOwned::to_owned)
+    }
+}
+
+/// Copies and frees a string allocated from pool by a driver.
+#[cfg(feature = "alloc")]
+unsafe fn take_pool_string(bs: &BootServices, ptr: *mut u16) -> Option<CString16> {
+    let s = unsafe { copy_string(ptr) };
+    if !ptr.is_null() {
+        let _ = bs.free_pool(ptr.cast());
+    }
+    s
+}
+
+/// The progress closure of the `SetImage()` call in progress.
+///
+/// The progress function has no context parameter, so the closure is found
+/// through this pointer to a `&mut dyn FnMut(usize)`.
+#[cfg(feature = "alloc")]
+static PROGRESS: AtomicPtr<c_void> = AtomicPtr::new(core::ptr::null_mut());
+
+#[cfg(feature = "alloc")]
+extern "efiapi" fn progress(completion: usize) -> Status {
+    let progress = PROGRESS.load(Ordering::Acquire);
+    if !progress.is_null() {
+        let progress = unsafe { &mut *progress.cast::<&mut dyn FnMut(usize)>() };
+        progress(completion);
+    }
+    Status::SUCCESS
+}
+
+impl FirmwareManagement {
+    /// Returns information about the current firmware images of the device.
+    ///
+    /// The package version name allocated by the driver is freed with `bs`.
+    ///
+    /// # Errors
+    ///
+    /// - `DEVICE_ERROR`: Valid information could not be returned.
+    /// - `INCOMPATIBLE_VERSION`: The descriptors are not a version known to the
+    ///   driver.
+    /// - `BAD_BUFFER_SIZE`: The driver returned descriptors that do not fit in
+    ///   the buffer.
+    #[cfg(feature = "alloc")]
+    pub fn image_info(&mut self, bs: &BootServices) -> Result<ImageInfo> {
+        // `u64` elements keep the descriptors aligned.
+        let mut buffer: Vec<u64> = Vec::new();
+        let mut descriptor_version = 0;
+        let mut count = 0;
+        let mut descriptor_size = 0;
+        let mut package_version = 0;
+        let mut package_version_name = core::ptr::null_mut();
+
+        loop {
+            let mut size = buffer.len() * size_of::<u64>();
+            let status = (self.GetImageInfo)(
+                self,
+                &mut size,
+                buffer.as_mut_ptr().cast(),
+                &mut descriptor_version,
+                &mut count,
+                &mut descriptor_size,
+                &mut package_version,
+                &mut package_version_name,
+            );
+
+            match status {
+                Status::SUCCESS => break,
+                Status::BUFFER_TOO_SMALL => buffer.resize(size.div_ceil(size_of::<u64>()), 0),
+                e => return Err(e),
+            }
+        }
+
+        let package_version_name = unsafe { take_pool_string(bs, package_version_name) };
+
+        let count = usize::from(count);
+        let size = buffer.len() * size_of::<u64>();
+        if count > 0 && (descriptor_size == 0 || count * descriptor_size > size) {
+            return Err(Status::BAD_BUFFER_SIZE);
+        }
+
+        let base = buffer.as_ptr().cast::<u8>();
+        let descriptors = (0..count)
+            .map(|i| {
+                // Descriptors of older versions are smaller, and the
+                // missing fields are left as 0.
+                let mut desc: FirmwareImageDescriptor = unsafe { core::mem::zeroed() };
+                let len = descriptor_size.min(size_of::<FirmwareImageDescriptor>());
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        base.add(i * descriptor_size),
+                        core::ptr::from_mut(&mut desc).cast(),
+                        len,
+                    );
+                    ImageDescriptor::from_raw(&desc)
+                }
+            })
+            .collect();
+
+        Ok(ImageInfo {
+            descriptor_version,
+            package_version,
+            package_version_name,
+            descriptors,
+        })
+    }
+
+    /// Reads a copy of a firmware image from the device.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: `index` is not valid.
+    /// - `UNSUPPORTED`: The operation is not supported.
+    /// - `SECURITY_VIOLATION`: The operation could not be performed due to an
+    ///   authentication failure.
+    /// - `DEVICE_ERROR`: The image could not be read.
+    #[cfg(feature = "alloc")]
+    pub fn get_image(&mut self, index: u8) -> Result<Vec<u8>> {
+        let mut image: Vec<u8> = Vec::new();
+
+        loop {
+            let mut size = image.len();
+            let status = (self.GetImage)(self, index, image.as_mut_ptr().cast(), &mut size);
+
+            match status {
+                Status::SUCCESS => {
+                    image.truncate(size);
+                    return Ok(image);
+                }
+                Status::BUFFER_TOO_SMALL => image.resize(size, 0),
+                e => return Err(e),
+            }
+        }
+    }
+
+    /// Updates a firmware image of the device.
+    ///
+    /// `progress` is called with the completion percentage, from 1 to 100,
+    /// while the image is written.
+    ///
+    /// On failure, `abort_reason` is set to the reason given by the driver for
+    /// aborting the update, if any. The string allocated by the driver is freed
+    /// with `bs`.
+    ///
+    /// # Errors
+    ///
+    /// - `ABORTED`: The operation was aborted.
+    /// - `INVALID_PARAMETER`: `index` is not valid, or `image` is empty.
+    /// - `UNSUPPORTED`: The operation is not supported.
+    /// - `SECURITY_VIOLATION`: The operation could not be performed due to an
+    ///   authentication failure.
+    /// - `DEVICE_ERROR`: The image could not be written.
+    #[cfg(feature = "alloc")]
+    pub fn set_image(
+        &mut self,
+        bs: &BootServices,
+        index: u8,
+        image: &[u8],
+        vendor_code: Option<&[u8]>,
+        progress: Option<&mut dyn FnMut(usize)>,
+        abort_reason: &mut Option<CString16>,
+    ) -> Result<()> {
+        let vendor_code = vendor_code.map_or(core::ptr::null(), <[u8]>::as_ptr);
+        let mut reason = core::ptr::null_mut();
+
+        let mut progress = progress;
+        let (progress_fn, context) = match progress.as_mut() {
+            Some(p) => (Some(self::progress as UpdateImageProgressFn), core::ptr::from_mut(p)),
+            None => (None, core::ptr::null_mut()),
+        };
+        let prev = PROGRESS.swap(context.cast(), Ordering::AcqRel);
+
+        let status = (self.SetImage)(
+            self,
+            index,
+            image.as_ptr().cast(),
+            image.len(),
+            vendor_code.cast(),
+            progress_fn,
+            &mut reason,
+        );
+
+        PROGRESS.store(prev, Ordering::Release);
+
+        *abort_reason = unsafe { take_pool_string(bs, reason) };
+        match status {
+            Status::SUCCESS => Ok(()),
+            e => Err(e),
+        }
+    }
+
+    /// Checks whether an image is valid for the device before calling
+    /// `SetImage()`.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: `index` is not valid.
+    /// - `UNSUPPORTED`: The operation is not supported.
+    /// - `SECURITY_VIOLATION`: The image could not be checked due to an
+    ///   authentication failure.
+    pub fn check_image(&mut self, index: u8, image: &[u8]) -> Result<ImageUpdatable> {
+        let mut updatable = ImageUpdatable::default();
+        let status =
+            (self.CheckImage)(self, index, image.as_ptr().cast(), image.len(), &mut updatable);
+
+        match status {
+            Status::SUCCESS => Ok(updatable),
+            e => Err(e),
+        }
+    }
+
+    // TODO: GetPackageInfo
+    // TODO: SetPackageInfo
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn descriptor_layout() {
+        assert_eq!(core::mem::offset_of!(FirmwareImageDescriptor, ImageId), 24);
+        assert_eq!(core::mem::offset_of!(FirmwareImageDescriptor, Size), 56);
+        assert_eq!(core::mem::offset_of!(FirmwareImageDescriptor, LowestSupportedImageVersion), 88);
+        assert_eq!(core::mem::offset_of!(FirmwareImageDescriptor, HardwareInstance), 104);
+        assert_eq!(size_of::<FirmwareImageDescriptor>(), 120);
+    }
+}
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Loaded Image Protocols
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 9.1: EFI Loaded Image Protocol
+//!   - 9.2: EFI Loaded Image Device Path Protocol
+
+use core::ffi::c_void;
+
+use crate::mem::MemoryType;
+use crate::prelude::*;
+use crate::proto::device_path::DevicePath;
+use crate::proto::device_path::DevicePathProtocol;
+
+/// `EFI_IMAGE_UNLOAD`
+pub type ImageUnloadFn = extern "efiapi" fn(Handle) -> Status;
+
+/// `EFI_LOADED_IMAGE_PROTOCOL`
+#[repr(C)]
+pub struct LoadedImage {
+    pub Revision: u32,
+    pub ParentHandle: Option<Handle>,
+    pub SystemTable: *mut SystemTable,
+    pub DeviceHandle: Option<Handle>,
+    pub FilePath: *const DevicePathProtocol,
+    pub Reserved: *mut c_void,
+    pub LoadOptionsSize: u32,
+    pub LoadOptions: *const c_void,
+    pub ImageBase: *mut c_void,
+    pub ImageSize: u64,
+    pub ImageCodeType: MemoryType,
+    pub ImageDataType: MemoryType,
+    pub Unload: Option<ImageUnloadFn>,
+}
+
+unsafe impl Protocol for LoadedImage {
+    const GUID: Guid = guid!("5b1b31a1-9562-11d2-8e3f-00a0c969723b");
+}
+
+impl LoadedImage {
+    /// The handle of the image that loaded this image, if any.
+    pub fn parent_handle(&self) -> Option<Handle> {
+        self.ParentHandle
+    }
+
+    /// The handle of the device the image was loaded from, if any.
+    pub fn device_handle(&self) -> Option<Handle> {
+        self.DeviceHandle
+    }
+
+    /// The file path of the image, relative to the device it was loaded from.
+    pub fn file_path(&self) -> Option<&DevicePath> {
+        if self.FilePath.is_null() {
+            return None;
+        }
+
+        Some(unsafe { DevicePath::from_ptr(self.FilePath) })
+    }
+
+    /// The raw load options passed to the image.
+    pub fn load_options(&self) -> &[u8] {
+        if self.LoadOptions.is_null() {
+            return &[];
+        }
+
+        let size = self.LoadOptionsSize as usize;
+        unsafe { core::slice::from_raw_parts(self.LoadOptions.cast(), size) }
+    }
+
+    /// The load options as a UCS-2 string, such as the command line of an
+    /// image started by the shell.
+    ///
+    /// Returns `None` if the load options are not a null-terminated string.
+    pub fn load_options_str(&self) -> Option<&CStr16> {
+        let options = self.load_options();
+        if self.LoadOptions.align_offset(align_of::<u16>()) != 0 || options.len() < 2 {
+            return None;
+        }
+
+        let units =
+            unsafe { core::slice::from_raw_parts(self.LoadOptions.cast(), options.len() / 2) };
+        let nul = units.iter().position(|&c| c == 0)?;
+        CStr16::from_u16_with_nul(&units[..=nul]).ok()
+    }
+
+    /// Sets the load options passed to the image when it is started.
+    ///
+    /// # Safety
+    ///
+    /// `options` must remain valid until the image has exited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `options` is larger than 4 GiB.
+    pub unsafe fn set_load_options(&mut self, options: &[u8]) {
+        self.LoadOptionsSize = u32::try_from(options.len()).expect("load options too large");
+        self.LoadOptions = options.as_ptr().cast();
+    }
+
+    /// The base address the image was loaded at.
+    pub fn image_base(&self) -> *const u8 {
+        self.ImageBase.cast()
+    }
+
+    /// The size of the loaded image in bytes.
+    pub fn image_size(&self) -> u64 {
+        self.ImageSize
+    }
+
+    /// The memory type of the code sections of the image.
+    pub fn image_code_type(&self) -> MemoryType {
+        self.ImageCodeType
+    }
+
+    /// The memory type of the data sections of the image.
+    pub fn image_data_type(&self) -> MemoryType {
+        self.ImageDataType
+    }
+
+    /// Sets the function called when the image is unloaded with
+    /// `UnloadImage()`.
+    ///
+    /// An image without an unload function cannot be unloaded after it has
+    /// been started.
+    pub fn set_unload(&mut self, unload: Option<ImageUnloadFn>) {
+        self.Unload = unload;
+    }
+}
+
+/// `EFI_LOADED_IMAGE_DEVICE_PATH_PROTOCOL`
+///
+/// The device path the image was loaded from, if it was loaded from a device
+/// path.
+#[repr(transparent)]
+pub struct LoadedImageDevicePath(DevicePathProtocol);
+
+unsafe impl Protocol for LoadedImageDevicePath {
+    const GUID: Guid = guid!("bc62157e-3e33-4fec-9920-2d3b36d750df");
+}
+
+impl LoadedImageDevicePath {
+    /// The device path of the image.
+    pub fn device_path(&self) -> &DevicePath {
+        unsafe { DevicePath::from_ptr(&self.0) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bytes(units: &[u16]) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(units.as_ptr().cast(), size_of_val(units)) }
+    }
+
+    fn image(options: &[u8]) -> LoadedImage {
+        let mut image: LoadedImage = unsafe { core::mem::zeroed() };
+        unsafe { image.set_load_options(options) };
+        image
+    }
+
+    #[test]
+    fn load_options_str() {
+        let ok = Some(cstr16!("ab"));
+
+        assert_eq!(image(bytes(&[0x0061, 0x0062, 0x0000])).load_options_str(), ok);
+        // Binary data after the string.
+        let options = bytes(&[0x0061, 0x0062, 0x0000, 0xD800, 0xFFFF]);
+        assert_eq!(image(options).load_options_str(), ok);
+        // A trailing odd byte.
+        let options = &bytes(&[0x0061, 0x0062, 0x0000, 0x0063])[..7];
+        assert_eq!(image(options).load_options_str(), ok);
+        // Missing the null terminator.
+        assert_eq!(image(bytes(&[0x0061, 0x0062])).load_options_str(), None);
+        assert_eq!(image(&bytes(&[0x0061, 0x0062, 0x0000])[..5]).load_options_str(), None);
+        assert_eq!(image(&[]).load_options_str(), None);
+        // A lone surrogate.
+        assert_eq!(image(bytes(&[0x0061, 0xD800, 0x0000])).load_options_str(), None);
+        assert_eq!(image(bytes(&[0xDC00, 0x0000])).load_options_str(), None);
+        // An unaligned buffer.
+        let options = &bytes(&[0x6100, 0x6200, 0x0000, 0x0000])[1..7];
+        assert_eq!(image(options).load_options_str(), None);
+    }
+}
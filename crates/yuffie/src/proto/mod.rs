@@ -4,4 +4,19 @@
 //! Protocols
 
 pub mod console;
+pub mod device_path;
 pub mod driver;
+pub mod firmware_management;
+pub mod loaded_image;
+
+use crate::guid::Guid;
+
+/// An interface identified by a GUID that can be installed on a handle.
+///
+/// # Safety
+///
+/// `GUID` must identify an interface with the same layout as the type.
+pub unsafe trait Protocol {
+    /// The GUID that identifies the protocol.
+    const GUID: Guid;
+}
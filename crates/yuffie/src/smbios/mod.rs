@@ -0,0 +1,478 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # SMBIOS
+//!
+//! Read-only views over the SMBIOS structure table, found through the entry
+//! point installed as the [`SMBIOS3_TABLE`](crate::table::cfg::SMBIOS3_TABLE)
+//! or [`SMBIOS_TABLE`](crate::table::cfg::SMBIOS_TABLE) configuration table.
+//!
+//! ```ignore
+//! let entry = st
+//!     .find_config_table(&cfg::SMBIOS3_TABLE)
+//!     .or_else(|| st.find_config_table(&cfg::SMBIOS_TABLE))
+//!     .ok_or(Status::NOT_FOUND)?;
+//! let mem = unsafe { PhysicalMemory::new() };
+//! let smbios = Smbios::new(&mem, entry as u64)?;
+//! if let Some(board) = smbios.find::<Baseboard>() {
+//!     println!("{:?}", board.serial_number);
+//! }
+//! ```
+//!
+//! ## References
+//!
+//! - [System Management BIOS (SMBIOS) Reference Specification, Version
+//!   3.7.0][SMBIOS Spec]
+//!   - 5: Accessing SMBIOS information
+//!   - 6: SMBIOS structures
+//!   - 7: Structure definitions
+//!
+//! [SMBIOS Spec]: https://www.dmtf.org/dsp/DSP0134
+
+pub mod types;
+
+pub use self::types::*;
+use crate::bytes::checksum_ok;
+use crate::bytes::read_u8;
+use crate::bytes::read_u16;
+use crate::bytes::read_u32;
+use crate::bytes::read_u64;
+use crate::mem::Memory;
+use crate::prelude::*;
+
+/// The size of the SMBIOS 2.1 (32-bit) entry point.
+const ENTRY_POINT_SIZE: usize = 0x1F;
+/// The size of the SMBIOS 3.0 (64-bit) entry point.
+const ENTRY_POINT3_SIZE: usize = 0x18;
+/// The size of the header of a structure.
+pub const HEADER_SIZE: usize = 4;
+
+/// An SMBIOS 2.1 (32-bit) or 3.0 (64-bit) entry point.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EntryPoint {
+    pub major_version: u8,
+    pub minor_version: u8,
+    /// The docrev of the specification, which is always 0 for the 32-bit entry
+    /// point.
+    pub docrev: u8,
+    /// The physical address of the structure table.
+    pub table_address: u64,
+    /// The length of the structure table. For the 64-bit entry point, this is
+    /// the maximum length.
+    pub table_length: u32,
+    /// The number of structures, given only by the 32-bit entry point.
+    pub structure_count: Option<u16>,
+}
+
+impl EntryPoint {
+    /// The anchor string of the 32-bit entry point.
+    pub const ANCHOR: &'static [u8; 4] = b"_SM_";
+    /// The anchor string of the 64-bit entry point.
+    pub const ANCHOR3: &'static [u8; 5] = b"_SM3_";
+    /// The intermediate anchor string of the 32-bit entry point.
+    const INTERMEDIATE_ANCHOR: &'static [u8; 5] = b"_DMI_";
+
+    /// Parses and checks an entry point.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The anchor string or length is not valid.
+    /// - `CRC_ERROR`: A checksum is not valid.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        if data.starts_with(Self::ANCHOR3) {
+            Self::parse_64(data)
+        } else if data.starts_with(Self::ANCHOR) {
+            Self::parse_32(data)
+        } else {
+            Err(Status::INVALID_PARAMETER)
+        }
+    }
+
+    fn parse_32(data: &[u8]) -> Result<Self> {
+        let len = usize::from(read_u8(data, 5).ok_or(Status::INVALID_PARAMETER)?);
+        // Some SMBIOS 2.1 implementations report a length of 0x1E.
+        if len < ENTRY_POINT_SIZE - 1 || data.len() < ENTRY_POINT_SIZE.max(len) {
+            return Err(Status::INVALID_PARAMETER);
+        }
+        if &data[16..21] != Self::INTERMEDIATE_ANCHOR {
+            return Err(Status::INVALID_PARAMETER);
+        }
+        if !checksum_ok(&data[..len]) || !checksum_ok(&data[16..ENTRY_POINT_SIZE]) {
+            return Err(Status::CRC_ERROR);
+        }
+
+        Ok(Self {
+            major_version: data[6],
+            minor_version: data[7],
+            docrev: 0,
+            table_address: u64::from(read_u32(data, 24).unwrap_or_default()),
+            table_length: u32::from(read_u16(data, 22).unwrap_or_default()),
+            structure_count: read_u16(data, 28),
+        })
+    }
+
+    fn parse_64(data: &[u8]) -> Result<Self> {
+        let len = usize::from(read_u8(data, 6).ok_or(Status::INVALID_PARAMETER)?);
+        if len < ENTRY_POINT3_SIZE || len > data.len() {
+            return Err(Status::INVALID_PARAMETER);
+        }
+        if !checksum_ok(&data[..len]) {
+            return Err(Status::CRC_ERROR);
+        }
+
+        Ok(Self {
+            major_version: data[7],
+            minor_version: data[8],
+            docrev: data[9],
+            table_address: read_u64(data, 16).unwrap_or_default(),
+            table_length: read_u32(data, 12).unwrap_or_default(),
+            structure_count: None,
+        })
+    }
+
+    /// Reads and checks an entry point.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The entry point could not be read.
+    /// - The errors of [`EntryPoint::from_bytes()`].
+    pub fn read<M: Memory + ?Sized>(mem: &M, addr: u64) -> Result<Self> {
+        let header = mem.read(addr, 7).ok_or(Status::NOT_FOUND)?;
+        let len = if header.starts_with(Self::ANCHOR3) {
+            usize::from(header[6]).max(ENTRY_POINT3_SIZE)
+        } else {
+            usize::from(header[5]).max(ENTRY_POINT_SIZE)
+        };
+
+        let data = mem.read(addr, len).ok_or(Status::NOT_FOUND)?;
+        Self::from_bytes(data)
+    }
+}
+
+/// A structure of the structure table, with its string set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Structure<'a> {
+    data: &'a [u8],
+    strings: &'a [u8],
+}
+
+impl<'a> Structure<'a> {
+    /// The type of the structure.
+    pub fn kind(&self) -> u8 {
+        self.data[0]
+    }
+
+    /// The length of the formatted area of the structure.
+    pub fn length(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn handle(&self) -> u16 {
+        read_u16(self.data, 2).unwrap_or_default()
+    }
+
+    /// The formatted area of the structure, including the header.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the string set of the structure.
+    pub fn strings(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.strings.split(|&b| b == 0).filter(|s| !s.is_empty())
+    }
+
+    /// Returns a string by its 1-based number, as used in the formatted area.
+    /// Number 0 means no string.
+    pub fn string_bytes(&self, number: u8) -> Option<&'a [u8]> {
+        let index = usize::from(number).checked_sub(1)?;
+        self.strings().nth(index)
+    }
+
+    /// Returns a string by its number, if it is valid UTF-8.
+    pub fn string(&self, number: u8) -> Option<&'a str> {
+        core::str::from_utf8(self.string_bytes(number)?).ok()
+    }
+
+    fn u8(&self, offset: usize) -> Option<u8> {
+        read_u8(self.data, offset)
+    }
+
+    fn u16(&self, offset: usize) -> Option<u16> {
+        read_u16(self.data, offset)
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        read_u32(self.data, offset)
+    }
+
+    fn u64(&self, offset: usize) -> Option<u64> {
+        read_u64(self.data, offset)
+    }
+
+    /// Returns the string referenced by the number at `offset`.
+    fn str(&self, offset: usize) -> Option<&'a str> {
+        self.string(self.u8(offset)?)
+    }
+
+    /// Returns a typed view of the structure, if it has the type and minimum
+    /// length of `T`.
+    pub fn as_typed<T: TypedStructure<'a>>(&self) -> Option<T> {
+        if self.kind() != T::TYPE {
+            return None;
+        }
+
+        T::from_structure(*self)
+    }
+}
+
+/// A structure with a typed view.
+pub trait TypedStructure<'a>: Sized {
+    /// The type of the structure.
+    const TYPE: u8;
+
+    /// Creates a view of a structure with the type of the view.
+    ///
+    /// Returns `None` if the structure is too short.
+    fn from_structure(structure: Structure<'a>) -> Option<Self>;
+}
+
+/// An iterator over the structures of a structure table.
+///
+/// Iteration stops after the end-of-table structure, or at a structure that
+/// is not valid.
+#[derive(Clone, Debug)]
+pub struct Structures<'a>(&'a [u8]);
+
+impl<'a> Iterator for Structures<'a> {
+    type Item = Structure<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let table = core::mem::take(&mut self.0);
+        let len = usize::from(read_u8(table, 1)?);
+        if len < HEADER_SIZE || len > table.len() {
+            return None;
+        }
+
+        // The string set ends with two NULs, which are the whole set if the
+        // structure has no strings.
+        let end = table[len..].windows(2).position(|w| w == [0, 0])? + len;
+        let structure = Structure {
+            data: &table[..len],
+            strings: &table[len..end],
+        };
+
+        if structure.kind() != EndOfTable::TYPE {
+            self.0 = &table[end + 2..];
+        }
+        Some(structure)
+    }
+}
+
+/// The SMBIOS structure table.
+#[derive(Clone, Copy, Debug)]
+pub struct Smbios<'a> {
+    entry_point: EntryPoint,
+    table: &'a [u8],
+}
+
+impl<'a> Smbios<'a> {
+    /// Creates a view of a structure table.
+    pub fn from_bytes(entry_point: EntryPoint, table: &'a [u8]) -> Self {
+        Self {
+            entry_point,
+            table,
+        }
+    }
+
+    /// Reads the entry point at `entry_point_addr` and the structure table.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The entry point or table could not be read.
+    /// - `INVALID_PARAMETER`: The entry point is not valid.
+    /// - `CRC_ERROR`: A checksum of the entry point is not valid.
+    pub fn new<M: Memory + ?Sized>(mem: &'a M, entry_point_addr: u64) -> Result<Self> {
+        let entry_point = EntryPoint::read(mem, entry_point_addr)?;
+        let table = mem
+            .read(entry_point.table_address, entry_point.table_length as usize)
+            .ok_or(Status::NOT_FOUND)?;
+        Ok(Self::from_bytes(entry_point, table))
+    }
+
+    pub fn entry_point(&self) -> &EntryPoint {
+        &self.entry_point
+    }
+
+    /// Returns an iterator over the structures.
+    pub fn structures(&self) -> Structures<'a> {
+        Structures(self.table)
+    }
+
+    /// Returns the structure with a handle.
+    pub fn find_by_handle(&self, handle: u16) -> Option<Structure<'a>> {
+        self.structures().find(|s| s.handle() == handle)
+    }
+
+    /// Returns the structures of a type.
+    pub fn find_by_type(&self, kind: u8) -> impl Iterator<Item = Structure<'a>> + 'a {
+        self.structures().filter(move |s| s.kind() == kind)
+    }
+
+    /// Returns typed views of the structures of a type.
+    pub fn find_all<T: TypedStructure<'a>>(&self) -> impl Iterator<Item = T> + 'a {
+        self.find_by_type(T::TYPE).filter_map(|s| s.as_typed())
+    }
+
+    /// Returns a typed view of the first structure of a type.
+    pub fn find<T: TypedStructure<'a>>(&self) -> Option<T> {
+        self.find_all().next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A structure table built in a fixed buffer.
+    pub(super) struct TableBuilder {
+        buf: [u8; 1024],
+        len: usize,
+    }
+
+    impl TableBuilder {
+        pub(super) fn new() -> Self {
+            Self {
+                buf: [0; 1024],
+                len: 0,
+            }
+        }
+
+        fn push(&mut self, bytes: &[u8]) {
+            self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+            self.len += bytes.len();
+        }
+
+        /// Appends a structure from its formatted area after the header.
+        pub(super) fn structure(
+            &mut self,
+            kind: u8,
+            handle: u16,
+            data: &[u8],
+            strings: &[&str],
+        ) -> &mut Self {
+            self.push(&[kind, u8::try_from(HEADER_SIZE + data.len()).unwrap()]);
+            self.push(&handle.to_le_bytes());
+            self.push(data);
+            for string in strings {
+                self.push(string.as_bytes());
+                self.push(&[0]);
+            }
+            if strings.is_empty() {
+                self.push(&[0]);
+            }
+            self.push(&[0]);
+            self
+        }
+
+        /// Appends the end-of-table structure.
+        pub(super) fn end(&mut self) -> &mut Self {
+            self.structure(EndOfTable::TYPE, 0xFEFF, &[], &[])
+        }
+
+        pub(super) fn as_bytes(&self) -> &[u8] {
+            &self.buf[..self.len]
+        }
+    }
+
+    fn checksum(data: &[u8]) -> u8 {
+        0u8.wrapping_sub(data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)))
+    }
+
+    fn entry_point_32(table_len: u16, table_addr: u32, count: u16) -> [u8; ENTRY_POINT_SIZE] {
+        let mut data = [0; ENTRY_POINT_SIZE];
+        data[..4].copy_from_slice(EntryPoint::ANCHOR);
+        data[5..8].copy_from_slice(&[0x1F, 2, 8]);
+        data[16..21].copy_from_slice(EntryPoint::INTERMEDIATE_ANCHOR);
+        data[22..24].copy_from_slice(&table_len.to_le_bytes());
+        data[24..28].copy_from_slice(&table_addr.to_le_bytes());
+        data[28..30].copy_from_slice(&count.to_le_bytes());
+        data[30] = 0x28;
+        data[21] = checksum(&data[16..]);
+        data[4] = checksum(&data);
+        data
+    }
+
+    fn entry_point_64(table_len: u32, table_addr: u64) -> [u8; ENTRY_POINT3_SIZE] {
+        let mut data = [0; ENTRY_POINT3_SIZE];
+        data[..5].copy_from_slice(EntryPoint::ANCHOR3);
+        data[6..10].copy_from_slice(&[0x18, 3, 7, 0]);
+        data[10] = 1;
+        data[12..16].copy_from_slice(&table_len.to_le_bytes());
+        data[16..24].copy_from_slice(&table_addr.to_le_bytes());
+        data[5] = checksum(&data);
+        data
+    }
+
+    #[test]
+    fn entry_points() {
+        let ep = EntryPoint::from_bytes(&entry_point_32(0x100, 0x000F_0000, 3)).unwrap();
+        assert_eq!((ep.major_version, ep.minor_version), (2, 8));
+        assert_eq!(ep.table_address, 0x000F_0000);
+        assert_eq!(ep.table_length, 0x100);
+        assert_eq!(ep.structure_count, Some(3));
+
+        let ep = EntryPoint::from_bytes(&entry_point_64(0x2000, 0x7F00_0000)).unwrap();
+        assert_eq!((ep.major_version, ep.minor_version, ep.docrev), (3, 7, 0));
+        assert_eq!(ep.table_address, 0x7F00_0000);
+        assert_eq!(ep.structure_count, None);
+
+        let mut data = entry_point_32(0x100, 0x000F_0000, 3);
+        data[24] ^= 1;
+        assert_eq!(EntryPoint::from_bytes(&data), Err(Status::CRC_ERROR));
+
+        let mut data = entry_point_64(0x2000, 0x7F00_0000);
+        data[0] = b'-';
+        assert_eq!(EntryPoint::from_bytes(&data), Err(Status::INVALID_PARAMETER));
+        assert_eq!(EntryPoint::from_bytes(&data[..8]), Err(Status::INVALID_PARAMETER));
+    }
+
+    #[test]
+    fn structures() {
+        let mut builder = TableBuilder::new();
+        builder
+            .structure(0x80, 0x10, &[1, 2], &["first", "second"])
+            .structure(0x81, 0x11, &[], &[])
+            .structure(0x80, 0x12, &[], &["third"])
+            .end();
+        let table = builder.as_bytes();
+
+        // Entry point at 0, table at 64.
+        let mut mem = [0; 256];
+        mem[..ENTRY_POINT3_SIZE]
+            .copy_from_slice(&entry_point_64(u32::try_from(table.len()).unwrap(), 64));
+        mem[64..64 + table.len()].copy_from_slice(table);
+        // Data after the end-of-table structure is ignored.
+        mem[64 + table.len()..64 + table.len() + 8].fill(0xFF);
+
+        let smbios = Smbios::new(mem.as_slice(), 0).unwrap();
+        let kinds = smbios.structures().map(|s| s.kind());
+        assert!(kinds.eq([0x80, 0x81, 0x80, EndOfTable::TYPE]));
+
+        let s = smbios.find_by_handle(0x10).unwrap();
+        assert_eq!(s.length(), 6);
+        assert_eq!(s.data(), [0x80, 6, 0x10, 0, 1, 2]);
+        assert_eq!(s.string(1), Some("first"));
+        assert_eq!(s.string(2), Some("second"));
+        assert_eq!(s.string(0), None);
+        assert_eq!(s.string(3), None);
+
+        assert_eq!(smbios.find_by_handle(0x11).unwrap().strings().count(), 0);
+        assert_eq!(smbios.find_by_type(0x80).count(), 2);
+        assert!(smbios.find::<EndOfTable>().is_some());
+        assert!(smbios.find_by_handle(0x13).is_none());
+
+        // A truncated string set ends the iteration.
+        let smbios = Smbios::from_bytes(smbios.entry_point, &table[..table.len() - 1]);
+        assert_eq!(smbios.structures().count(), 3);
+    }
+}
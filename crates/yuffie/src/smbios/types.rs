@@ -0,0 +1,657 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Typed SMBIOS structures
+//!
+//! Fields added in later versions of the specification are `None` if the
+//! structure is too short to contain them. Strings are `None` if not set, or
+//! not valid UTF-8.
+//!
+//! ## References
+//!
+//! - System Management BIOS (SMBIOS) Reference Specification, Version 3.7.0
+//!   - 7.1: BIOS Information (Type 0)
+//!   - 7.2: System Information (Type 1)
+//!   - 7.3: Baseboard Information (Type 2)
+//!   - 7.4: System Enclosure or Chassis (Type 3)
+//!   - 7.5: Processor Information (Type 4)
+//!   - 7.18: Memory Device (Type 17)
+//!   - 7.46: End-of-Table (Type 127)
+
+use super::Structure;
+use super::TypedStructure;
+use crate::bytes::array;
+use crate::prelude::*;
+
+/// BIOS Information (Type 0)
+#[derive(Clone, Copy, Debug)]
+pub struct Bios<'a> {
+    structure: Structure<'a>,
+    pub vendor: Option<&'a str>,
+    pub version: Option<&'a str>,
+    /// The segment of the runtime BIOS image, or 0 for UEFI systems.
+    pub starting_address_segment: u16,
+    pub release_date: Option<&'a str>,
+    /// The ROM size in 64 KiB blocks, minus 1, or `0xFF` if the extended size
+    /// is used.
+    pub rom_size: u8,
+    pub characteristics: u64,
+    /// The major and minor release of the system firmware.
+    pub system_bios_release: Option<(u8, u8)>,
+    /// The major and minor release of the embedded controller firmware.
+    pub ec_firmware_release: Option<(u8, u8)>,
+    /// The extended ROM size, with the unit in bits 15:14.
+    pub extended_rom_size: Option<u16>,
+}
+
+impl<'a> TypedStructure<'a> for Bios<'a> {
+    const TYPE: u8 = 0;
+
+    fn from_structure(s: Structure<'a>) -> Option<Self> {
+        Some(Self {
+            vendor: s.str(0x04),
+            version: s.str(0x05),
+            starting_address_segment: s.u16(0x06)?,
+            release_date: s.str(0x08),
+            rom_size: s.u8(0x09)?,
+            characteristics: s.u64(0x0A)?,
+            system_bios_release: s.u8(0x14).zip(s.u8(0x15)),
+            ec_firmware_release: s.u8(0x16).zip(s.u8(0x17)),
+            extended_rom_size: s.u16(0x18),
+            structure: s,
+        })
+    }
+}
+
+impl<'a> Bios<'a> {
+    pub fn structure(&self) -> &Structure<'a> {
+        &self.structure
+    }
+
+    /// The size of the BIOS ROM in bytes.
+    pub fn rom_size_bytes(&self) -> u64 {
+        match (self.rom_size, self.extended_rom_size) {
+            (0xFF, Some(ext)) => {
+                let size = u64::from(ext & 0x3FFF);
+                match ext >> 14 {
+                    0 => size << 20,
+                    1 => size << 30,
+                    _ => 0,
+                }
+            }
+            (size, _) => (u64::from(size) + 1) << 16,
+        }
+    }
+}
+
+/// The event that caused the system to power up.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WakeUpType(u8);
+
+impl WakeUpType {
+    pub const OTHER: Self = Self(0x01);
+    pub const UNKNOWN: Self = Self(0x02);
+    pub const APM_TIMER: Self = Self(0x03);
+    pub const MODEM_RING: Self = Self(0x04);
+    pub const LAN_REMOTE: Self = Self(0x05);
+    pub const POWER_SWITCH: Self = Self(0x06);
+    pub const PCI_PME: Self = Self(0x07);
+    pub const AC_POWER_RESTORED: Self = Self(0x08);
+}
+
+/// System Information (Type 1)
+#[derive(Clone, Copy, Debug)]
+pub struct System<'a> {
+    structure: Structure<'a>,
+    pub manufacturer: Option<&'a str>,
+    pub product_name: Option<&'a str>,
+    pub version: Option<&'a str>,
+    pub serial_number: Option<&'a str>,
+    /// The system UUID, with the first three fields in little-endian order as
+    /// specified by SMBIOS 2.6 and later.
+    pub uuid: Option<Guid>,
+    pub wake_up_type: Option<WakeUpType>,
+    pub sku_number: Option<&'a str>,
+    pub family: Option<&'a str>,
+}
+
+impl<'a> TypedStructure<'a> for System<'a> {
+    const TYPE: u8 = 1;
+
+    fn from_structure(s: Structure<'a>) -> Option<Self> {
+        if s.length() < 0x08 {
+            return None;
+        }
+
+        Some(Self {
+            manufacturer: s.str(0x04),
+            product_name: s.str(0x05),
+            version: s.str(0x06),
+            serial_number: s.str(0x07),
+            uuid: array(s.data(), 0x08).map(Guid::from_bytes),
+            wake_up_type: s.u8(0x18).map(WakeUpType),
+            sku_number: s.str(0x19),
+            family: s.str(0x1A),
+            structure: s,
+        })
+    }
+}
+
+impl<'a> System<'a> {
+    pub fn structure(&self) -> &Structure<'a> {
+        &self.structure
+    }
+}
+
+/// The type of a baseboard.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct BoardType(u8);
+
+impl BoardType {
+    pub const UNKNOWN: Self = Self(0x01);
+    pub const OTHER: Self = Self(0x02);
+    pub const SERVER_BLADE: Self = Self(0x03);
+    pub const CONNECTIVITY_SWITCH: Self = Self(0x04);
+    pub const SYSTEM_MANAGEMENT_MODULE: Self = Self(0x05);
+    pub const PROCESSOR_MODULE: Self = Self(0x06);
+    pub const IO_MODULE: Self = Self(0x07);
+    pub const MEMORY_MODULE: Self = Self(0x08);
+    pub const DAUGHTER_BOARD: Self = Self(0x09);
+    pub const MOTHERBOARD: Self = Self(0x0A);
+    pub const PROCESSOR_MEMORY_MODULE: Self = Self(0x0B);
+    pub const PROCESSOR_IO_MODULE: Self = Self(0x0C);
+    pub const INTERCONNECT_BOARD: Self = Self(0x0D);
+}
+
+/// Baseboard (or Module) Information (Type 2)
+#[derive(Clone, Copy, Debug)]
+pub struct Baseboard<'a> {
+    structure: Structure<'a>,
+    pub manufacturer: Option<&'a str>,
+    pub product: Option<&'a str>,
+    pub version: Option<&'a str>,
+    pub serial_number: Option<&'a str>,
+    pub asset_tag: Option<&'a str>,
+    pub feature_flags: Option<u8>,
+    pub location_in_chassis: Option<&'a str>,
+    /// The handle of the chassis the board is in.
+    pub chassis_handle: Option<u16>,
+    pub board_type: Option<BoardType>,
+}
+
+impl<'a> TypedStructure<'a> for Baseboard<'a> {
+    const TYPE: u8 = 2;
+
+    fn from_structure(s: Structure<'a>) -> Option<Self> {
+        if s.length() < 0x08 {
+            return None;
+        }
+
+        Some(Self {
+            manufacturer: s.str(0x04),
+            product: s.str(0x05),
+            version: s.str(0x06),
+            serial_number: s.str(0x07),
+            asset_tag: s.str(0x08),
+            feature_flags: s.u8(0x09),
+            location_in_chassis: s.str(0x0A),
+            chassis_handle: s.u16(0x0B),
+            board_type: s.u8(0x0D).map(BoardType),
+            structure: s,
+        })
+    }
+}
+
+impl<'a> Baseboard<'a> {
+    pub fn structure(&self) -> &Structure<'a> {
+        &self.structure
+    }
+}
+
+/// The type of a chassis.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ChassisType(u8);
+
+impl ChassisType {
+    pub const OTHER: Self = Self(0x01);
+    pub const UNKNOWN: Self = Self(0x02);
+    pub const DESKTOP: Self = Self(0x03);
+    pub const LOW_PROFILE_DESKTOP: Self = Self(0x04);
+    pub const PIZZA_BOX: Self = Self(0x05);
+    pub const MINI_TOWER: Self = Self(0x06);
+    pub const TOWER: Self = Self(0x07);
+    pub const PORTABLE: Self = Self(0x08);
+    pub const LAPTOP: Self = Self(0x09);
+    pub const NOTEBOOK: Self = Self(0x0A);
+    pub const HAND_HELD: Self = Self(0x0B);
+    pub const DOCKING_STATION: Self = Self(0x0C);
+    pub const ALL_IN_ONE: Self = Self(0x0D);
+    pub const SUB_NOTEBOOK: Self = Self(0x0E);
+    pub const MAIN_SERVER_CHASSIS: Self = Self(0x11);
+    pub const RACK_MOUNT_CHASSIS: Self = Self(0x17);
+    pub const TABLET: Self = Self(0x1E);
+    pub const CONVERTIBLE: Self = Self(0x1F);
+    pub const DETACHABLE: Self = Self(0x20);
+    pub const MINI_PC: Self = Self(0x23);
+    pub const STICK_PC: Self = Self(0x24);
+}
+
+/// System Enclosure or Chassis (Type 3)
+#[derive(Clone, Copy, Debug)]
+pub struct Chassis<'a> {
+    structure: Structure<'a>,
+    pub manufacturer: Option<&'a str>,
+    pub chassis_type: ChassisType,
+    /// Set if the chassis has a lock.
+    pub lock: bool,
+    pub version: Option<&'a str>,
+    pub serial_number: Option<&'a str>,
+    pub asset_tag: Option<&'a str>,
+    pub boot_up_state: Option<u8>,
+    pub power_supply_state: Option<u8>,
+    pub thermal_state: Option<u8>,
+    pub security_status: Option<u8>,
+}
+
+impl<'a> TypedStructure<'a> for Chassis<'a> {
+    const TYPE: u8 = 3;
+
+    fn from_structure(s: Structure<'a>) -> Option<Self> {
+        let kind = s.u8(0x05)?;
+        if s.length() < 0x09 {
+            return None;
+        }
+
+        Some(Self {
+            manufacturer: s.str(0x04),
+            chassis_type: ChassisType(kind & 0x7F),
+            lock: kind & 0x80 != 0,
+            version: s.str(0x06),
+            serial_number: s.str(0x07),
+            asset_tag: s.str(0x08),
+            boot_up_state: s.u8(0x09),
+            power_supply_state: s.u8(0x0A),
+            thermal_state: s.u8(0x0B),
+            security_status: s.u8(0x0C),
+            structure: s,
+        })
+    }
+}
+
+impl<'a> Chassis<'a> {
+    pub fn structure(&self) -> &Structure<'a> {
+        &self.structure
+    }
+}
+
+/// Processor Information (Type 4)
+#[derive(Clone, Copy, Debug)]
+pub struct Processor<'a> {
+    structure: Structure<'a>,
+    pub socket_designation: Option<&'a str>,
+    pub processor_type: u8,
+    /// The processor family, or `0xFE` if given by the second family field.
+    pub processor_family: u8,
+    pub manufacturer: Option<&'a str>,
+    /// The raw processor ID. On x86, this is CPUID leaf 1 EAX and EDX.
+    pub processor_id: u64,
+    pub version: Option<&'a str>,
+    pub voltage: u8,
+    /// The external clock in MHz, or 0 if unknown.
+    pub external_clock: u16,
+    /// The maximum speed in MHz, or 0 if unknown.
+    pub max_speed: u16,
+    /// The speed at boot in MHz, or 0 if unknown.
+    pub current_speed: u16,
+    pub status: u8,
+    pub upgrade: u8,
+    pub serial_number: Option<&'a str>,
+    pub asset_tag: Option<&'a str>,
+    pub part_number: Option<&'a str>,
+    pub characteristics: Option<u16>,
+    pub processor_family_2: Option<u16>,
+    core_count: Option<u8>,
+    core_enabled: Option<u8>,
+    thread_count: Option<u8>,
+    core_count_2: Option<u16>,
+    core_enabled_2: Option<u16>,
+    thread_count_2: Option<u16>,
+}
+
+impl<'a> TypedStructure<'a> for Processor<'a> {
+    const TYPE: u8 = 4;
+
+    fn from_structure(s: Structure<'a>) -> Option<Self> {
+        Some(Self {
+            socket_designation: s.str(0x04),
+            processor_type: s.u8(0x05)?,
+            processor_family: s.u8(0x06)?,
+            manufacturer: s.str(0x07),
+            processor_id: s.u64(0x08)?,
+            version: s.str(0x10),
+            voltage: s.u8(0x11)?,
+            external_clock: s.u16(0x12)?,
+            max_speed: s.u16(0x14)?,
+            current_speed: s.u16(0x16)?,
+            status: s.u8(0x18)?,
+            upgrade: s.u8(0x19)?,
+            serial_number: s.str(0x20),
+            asset_tag: s.str(0x21),
+            part_number: s.str(0x22),
+            characteristics: s.u16(0x26),
+            processor_family_2: s.u16(0x28),
+            core_count: s.u8(0x23),
+            core_enabled: s.u8(0x24),
+            thread_count: s.u8(0x25),
+            core_count_2: s.u16(0x2A),
+            core_enabled_2: s.u16(0x2C),
+            thread_count_2: s.u16(0x2E),
+            structure: s,
+        })
+    }
+}
+
+impl<'a> Processor<'a> {
+    pub fn structure(&self) -> &Structure<'a> {
+        &self.structure
+    }
+
+    /// Picks the 8-bit count, or the 16-bit count if the 8-bit count is
+    /// `0xFF`. A count of 0 is unknown.
+    fn count(count: Option<u8>, count_2: Option<u16>) -> Option<u16> {
+        match count? {
+            0xFF => count_2.or(Some(0xFF)),
+            count => Some(u16::from(count)),
+        }
+        .filter(|&count| count != 0)
+    }
+
+    /// The number of cores per socket.
+    pub fn core_count(&self) -> Option<u16> {
+        Self::count(self.core_count, self.core_count_2)
+    }
+
+    /// The number of enabled cores per socket.
+    pub fn core_enabled(&self) -> Option<u16> {
+        Self::count(self.core_enabled, self.core_enabled_2)
+    }
+
+    /// The number of threads per socket.
+    pub fn thread_count(&self) -> Option<u16> {
+        Self::count(self.thread_count, self.thread_count_2)
+    }
+
+    /// Returns `true` if the socket is populated.
+    pub fn is_populated(&self) -> bool {
+        self.status & 0x40 != 0
+    }
+}
+
+/// The type of a memory device.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct MemoryType(u8);
+
+impl MemoryType {
+    pub const OTHER: Self = Self(0x01);
+    pub const UNKNOWN: Self = Self(0x02);
+    pub const DRAM: Self = Self(0x03);
+    pub const SDRAM: Self = Self(0x0F);
+    pub const DDR: Self = Self(0x12);
+    pub const DDR2: Self = Self(0x13);
+    pub const DDR3: Self = Self(0x18);
+    pub const DDR4: Self = Self(0x1A);
+    pub const LPDDR: Self = Self(0x1B);
+    pub const LPDDR2: Self = Self(0x1C);
+    pub const LPDDR3: Self = Self(0x1D);
+    pub const LPDDR4: Self = Self(0x1E);
+    pub const DDR5: Self = Self(0x22);
+    pub const LPDDR5: Self = Self(0x23);
+}
+
+/// Memory Device (Type 17)
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryDevice<'a> {
+    structure: Structure<'a>,
+    /// The handle of the physical memory array the device is in.
+    pub physical_memory_array_handle: u16,
+    pub memory_error_information_handle: u16,
+    /// The total width in bits, including error correction, or `0xFFFF` if
+    /// unknown.
+    pub total_width: u16,
+    /// The data width in bits, or `0xFFFF` if unknown.
+    pub data_width: u16,
+    size: u16,
+    pub form_factor: u8,
+    pub device_set: u8,
+    pub device_locator: Option<&'a str>,
+    pub bank_locator: Option<&'a str>,
+    pub memory_type: MemoryType,
+    pub type_detail: u16,
+    /// The maximum speed in MT/s, or 0 if unknown.
+    pub speed: Option<u16>,
+    pub manufacturer: Option<&'a str>,
+    pub serial_number: Option<&'a str>,
+    pub asset_tag: Option<&'a str>,
+    pub part_number: Option<&'a str>,
+    pub attributes: Option<u8>,
+    extended_size: Option<u32>,
+    /// The configured speed in MT/s, or 0 if unknown.
+    pub configured_memory_speed: Option<u16>,
+}
+
+impl<'a> TypedStructure<'a> for MemoryDevice<'a> {
+    const TYPE: u8 = 17;
+
+    fn from_structure(s: Structure<'a>) -> Option<Self> {
+        Some(Self {
+            physical_memory_array_handle: s.u16(0x04)?,
+            memory_error_information_handle: s.u16(0x06)?,
+            total_width: s.u16(0x08)?,
+            data_width: s.u16(0x0A)?,
+            size: s.u16(0x0C)?,
+            form_factor: s.u8(0x0E)?,
+            device_set: s.u8(0x0F)?,
+            device_locator: s.str(0x10),
+            bank_locator: s.str(0x11),
+            memory_type: MemoryType(s.u8(0x12)?),
+            type_detail: s.u16(0x13)?,
+            speed: s.u16(0x15),
+            manufacturer: s.str(0x17),
+            serial_number: s.str(0x18),
+            asset_tag: s.str(0x19),
+            part_number: s.str(0x1A),
+            attributes: s.u8(0x1B),
+            extended_size: s.u32(0x1C),
+            configured_memory_speed: s.u16(0x20),
+            structure: s,
+        })
+    }
+}
+
+impl<'a> MemoryDevice<'a> {
+    pub fn structure(&self) -> &Structure<'a> {
+        &self.structure
+    }
+
+    /// The size of the device in bytes, 0 if no device is installed, or
+    /// `None` if unknown.
+    pub fn size(&self) -> Option<u64> {
+        match self.size {
+            0xFFFF => None,
+            0x7FFF => {
+                let size = self.extended_size? & 0x7FFF_FFFF;
+                Some(u64::from(size) << 20)
+            }
+            size if size & 0x8000 != 0 => Some(u64::from(size & 0x7FFF) << 10),
+            size => Some(u64::from(size) << 20),
+        }
+    }
+
+    /// Returns `true` if a device is installed.
+    pub fn is_installed(&self) -> bool {
+        self.size != 0
+    }
+}
+
+/// End-of-Table (Type 127)
+#[derive(Clone, Copy, Debug)]
+pub struct EndOfTable<'a>(Structure<'a>);
+
+impl<'a> TypedStructure<'a> for EndOfTable<'a> {
+    const TYPE: u8 = 127;
+
+    fn from_structure(s: Structure<'a>) -> Option<Self> {
+        Some(Self(s))
+    }
+}
+
+impl<'a> EndOfTable<'a> {
+    pub fn structure(&self) -> &Structure<'a> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Smbios;
+    use super::super::test::TableBuilder;
+    use super::*;
+
+    #[rustfmt::skip]
+    fn fixture() -> TableBuilder {
+        let mut builder = TableBuilder::new();
+        builder
+            .structure(Bios::TYPE, 0x00, &[
+                1, 2, 0x00, 0xF0, 3, 0xFF,
+                0x80, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Characteristics
+                0x03, 0x0D, // Characteristics extension
+                1, 14, 0xFF, 0xFF, // Releases
+                0x20, 0x00, // Extended ROM size
+            ], &["System76", "2026-10-01_abcdef0", "10/01/2026"])
+            .structure(System::TYPE, 0x01, &[
+                1, 2, 3, 4,
+                0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66,
+                0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF, // UUID
+                0x06, 5, 6,
+            ], &["System76", "Lemur Pro", "lemp13", "SERIAL1", "Not Applicable", "Not Applicable"])
+            .structure(Baseboard::TYPE, 0x02, &[
+                1, 2, 3, 4, 0, 0x09, 0, 0x03, 0x00, 0x0A, 0,
+            ], &["System76", "Lemur Pro", "lemp13", "BOARD1"])
+            .structure(Chassis::TYPE, 0x03, &[
+                1, 0x8A, 0, 2, 0, 0x03, 0x03, 0x03, 0x03,
+            ], &["System76", "CHASSIS1"])
+            .structure(Processor::TYPE, 0x04, &[
+                1, 0x03, 0xFE, 2,
+                0xA3, 0x06, 0x0A, 0x00, 0xFF, 0xFB, 0xEB, 0xBF, // Processor ID
+                3, 0x8B, 100, 0, 0x88, 0x13, 0xB0, 0x04, 0x41, 0x01,
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // Cache handles
+                0, 0, 0,
+                0xFF, 0xFF, 0xFF, // Core, enabled and thread counts
+                0xFC, 0x00, 0xC6, 0x00, // Characteristics and family 2
+                0x00, 0x01, 0x00, 0x01, 0x00, 0x02, // Counts 2
+            ], &["U3E1", "Intel(R) Corporation", "Intel(R) Core(TM) Ultra 7"])
+            .structure(MemoryDevice::TYPE, 0x11, &[
+                0x10, 0x00, 0xFE, 0xFF, 64, 0, 64, 0,
+                0xFF, 0x7F, // Size
+                0x0D, 0, 1, 2, MemoryType::LPDDR5.0, 0x80, 0x00,
+                0x80, 0x1A, // Speed
+                3, 0, 0, 4, 2,
+                0x00, 0x80, 0x00, 0x00, // Extended size
+                0x80, 0x1A,
+            ], &["Channel-0-DIMM-0", "BANK 0", "Samsung", "M425R1GB4BB0-CQKOL"])
+            .end();
+        builder
+    }
+
+    #[test]
+    fn typed() {
+        let builder = fixture();
+        let data = builder.as_bytes();
+        let ep = crate::smbios::EntryPoint {
+            major_version: 3,
+            minor_version: 7,
+            docrev: 0,
+            table_address: 0,
+            table_length: u32::try_from(data.len()).unwrap(),
+            structure_count: None,
+        };
+        let smbios = Smbios::from_bytes(ep, data);
+
+        let bios = smbios.find::<Bios>().unwrap();
+        assert_eq!(bios.vendor, Some("System76"));
+        assert_eq!(bios.version, Some("2026-10-01_abcdef0"));
+        assert_eq!(bios.release_date, Some("10/01/2026"));
+        assert_eq!(bios.system_bios_release, Some((1, 14)));
+        assert_eq!(bios.rom_size_bytes(), 32 << 20);
+
+        let system = smbios.find::<System>().unwrap();
+        assert_eq!(system.product_name, Some("Lemur Pro"));
+        assert_eq!(system.serial_number, Some("SERIAL1"));
+        assert_eq!(system.uuid, Some(guid!("00112233-4455-6677-8899-aabbccddeeff")));
+        assert_eq!(system.wake_up_type, Some(WakeUpType::POWER_SWITCH));
+
+        let board = smbios.find::<Baseboard>().unwrap();
+        assert_eq!(board.serial_number, Some("BOARD1"));
+        assert_eq!(board.asset_tag, None);
+        assert_eq!(board.chassis_handle, Some(0x03));
+        assert_eq!(board.board_type, Some(BoardType::MOTHERBOARD));
+
+        let chassis = smbios.find::<Chassis>().unwrap();
+        assert_eq!(chassis.chassis_type, ChassisType::NOTEBOOK);
+        assert!(chassis.lock);
+        assert_eq!(chassis.serial_number, Some("CHASSIS1"));
+        assert_eq!(chassis.security_status, Some(0x03));
+
+        let cpu = smbios.find::<Processor>().unwrap();
+        assert_eq!(cpu.socket_designation, Some("U3E1"));
+        assert_eq!(cpu.version, Some("Intel(R) Core(TM) Ultra 7"));
+        assert_eq!(cpu.max_speed, 5000);
+        assert_eq!(cpu.processor_family_2, Some(0xC6));
+        assert_eq!(cpu.core_count(), Some(256));
+        assert_eq!(cpu.thread_count(), Some(512));
+        assert!(cpu.is_populated());
+
+        let dimm = smbios.find::<MemoryDevice>().unwrap();
+        assert_eq!(dimm.device_locator, Some("Channel-0-DIMM-0"));
+        assert_eq!(dimm.memory_type, MemoryType::LPDDR5);
+        assert_eq!(dimm.speed, Some(6784));
+        assert_eq!(dimm.part_number, Some("M425R1GB4BB0-CQKOL"));
+        assert_eq!(dimm.size(), Some(32 << 30));
+
+        assert_eq!(smbios.find::<EndOfTable>().unwrap().structure().handle(), 0xFEFF);
+    }
+
+    #[test]
+    fn short() {
+        let mut builder = TableBuilder::new();
+        builder
+            .structure(System::TYPE, 0x01, &[1, 0, 0, 0], &["System76"])
+            .structure(MemoryDevice::TYPE, 0x11, &[0; 0x11], &[])
+            .structure(MemoryDevice::TYPE, 0x12, &[0; 8], &[])
+            .end();
+        let data = builder.as_bytes();
+        let ep = crate::smbios::EntryPoint {
+            major_version: 2,
+            minor_version: 0,
+            docrev: 0,
+            table_address: 0,
+            table_length: u32::try_from(data.len()).unwrap(),
+            structure_count: None,
+        };
+        let smbios = Smbios::from_bytes(ep, data);
+
+        let system = smbios.find::<System>().unwrap();
+        assert_eq!(system.manufacturer, Some("System76"));
+        assert_eq!(system.uuid, None);
+        assert_eq!(system.family, None);
+
+        let dimm = smbios.find::<MemoryDevice>().unwrap();
+        assert!(!dimm.is_installed());
+        assert_eq!(dimm.speed, None);
+        assert_eq!(smbios.find_all::<MemoryDevice>().count(), 1);
+    }
+}
@@ -0,0 +1,464 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # UCS-2 Strings
+//!
+//! UEFI strings are NUL-terminated arrays of UCS-2 characters. UCS-2 can only
+//! represent characters in the Basic Multilingual Plane (BMP), so converting
+//! a `&str` that contains other characters fails.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Borrow;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::ops;
+
+use crate::prelude::*;
+
+/// Converts a string literal to a `&'static CStr16`.
+///
+/// Fails to compile if the literal contains a NUL or a character outside the
+/// Basic Multilingual Plane.
+#[macro_export]
+macro_rules! cstr16 {
+    ($s:literal) => {{
+        const LEN: usize = $crate::string::ucs2_len($s) + 1;
+        const BUF: [u16; LEN] = $crate::string::ucs2_encode::<LEN>($s);
+        unsafe { $crate::string::CStr16::from_u16_with_nul_unchecked(&BUF) }
+    }};
+}
+
+/// Decodes the UTF-8 character at `i`, returning it and the index of the next
+/// character.
+const fn decode_utf8(bytes: &[u8], i: usize) -> (u32, usize) {
+    let b = bytes[i] as u32;
+    if b < 0x80 {
+        (b, i + 1)
+    } else if b < 0xE0 {
+        (((b & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F), i + 2)
+    } else if b < 0xF0 {
+        let c =
+            ((b & 0x0F) << 12) | ((bytes[i + 1] as u32 & 0x3F) << 6) | (bytes[i + 2] as u32 & 0x3F);
+        (c, i + 3)
+    } else {
+        // 4 byte sequences are always outside the BMP.
+        (0x1_0000, i + 4)
+    }
+}
+
+/// Returns the number of UCS-2 characters needed to encode a string literal.
+#[doc(hidden)]
+pub const fn ucs2_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    let mut len = 0;
+
+    while i < bytes.len() {
+        let (c, next) = decode_utf8(bytes, i);
+        assert!(c != 0, "string contains an interior NUL");
+        assert!(c <= 0xFFFF, "string contains a character outside the BMP");
+        i = next;
+        len += 1;
+    }
+
+    len
+}
+
+/// Encodes a string literal as a NUL-terminated UCS-2 array.
+#[doc(hidden)]
+pub const fn ucs2_encode<const N: usize>(s: &str) -> [u16; N] {
+    let bytes = s.as_bytes();
+    let mut buf = [0; N];
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < bytes.len() {
+        // `ucs2_len()` has already checked that the character fits.
+        let (c, next) = decode_utf8(bytes, i);
+        let [lo, hi, _, _] = c.to_le_bytes();
+        buf[j] = u16::from_le_bytes([lo, hi]);
+        i = next;
+        j += 1;
+    }
+
+    buf
+}
+
+/// Converts a character to UCS-2, rejecting NUL and characters outside the
+/// BMP.
+fn encode_char(c: char) -> Result<u16> {
+    match u16::try_from(u32::from(c)) {
+        Ok(0) | Err(_) => Err(Status::INVALID_PARAMETER),
+        Ok(c) => Ok(c),
+    }
+}
+
+/// Returns `true` if `c` is a surrogate code unit, which is not valid UCS-2.
+const fn is_surrogate(c: u16) -> bool {
+    matches!(c, 0xD800..=0xDFFF)
+}
+
+/// A borrowed NUL-terminated UCS-2 string.
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct CStr16([u16]);
+
+impl CStr16 {
+    /// Creates a string from a slice that ends with a NUL character.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The slice is not NUL-terminated, or it contains
+    ///   an interior NUL or a surrogate code unit.
+    pub fn from_u16_with_nul(s: &[u16]) -> Result<&Self> {
+        if s.iter().copied().any(is_surrogate) {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        match s.iter().position(|&c| c == 0) {
+            Some(nul) if nul == s.len() - 1 => Ok(unsafe { Self::from_u16_with_nul_unchecked(s) }),
+            _ => Err(Status::INVALID_PARAMETER),
+        }
+    }
+
+    /// Creates a string from a slice without checking its contents.
+    ///
+    /// # Safety
+    ///
+    /// The slice must end with a NUL character and contain no other NUL
+    /// characters. It should not contain surrogate code units.
+    pub const unsafe fn from_u16_with_nul_unchecked(s: &[u16]) -> &Self {
+        unsafe { &*(core::ptr::from_ref(s) as *const Self) }
+    }
+
+    /// Creates a string from a pointer provided by firmware.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a NUL-terminated string that lives for `'a`.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The string contains a surrogate code unit.
+    pub unsafe fn from_ptr<'a>(ptr: *const u16) -> Result<&'a Self> {
+        let mut len = 0;
+        while unsafe { *ptr.add(len) } != 0 {
+            len += 1;
+        }
+
+        Self::from_u16_with_nul(unsafe { core::slice::from_raw_parts(ptr, len + 1) })
+    }
+
+    /// Encodes a string into a buffer, without allocating.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The string contains a NUL or a character outside
+    ///   the BMP.
+    /// - `BUFFER_TOO_SMALL`: `buf` cannot hold the string and its NUL
+    ///   terminator.
+    pub fn from_str_with_buf<'a>(s: &str, buf: &'a mut [u16]) -> Result<&'a Self> {
+        let mut len = 0;
+        for c in s.chars() {
+            let c = encode_char(c)?;
+            *buf.get_mut(len).ok_or(Status::BUFFER_TOO_SMALL)? = c;
+            len += 1;
+        }
+        *buf.get_mut(len).ok_or(Status::BUFFER_TOO_SMALL)? = 0;
+
+        Ok(unsafe { Self::from_u16_with_nul_unchecked(&buf[..=len]) })
+    }
+
+    /// Returns a pointer to the string for passing to UEFI interfaces.
+    pub const fn as_ptr(&self) -> *const u16 {
+        self.0.as_ptr()
+    }
+
+    /// Returns the characters of the string, without the NUL terminator.
+    pub fn as_slice(&self) -> &[u16] {
+        &self.0[..self.len()]
+    }
+
+    /// Returns the characters of the string, including the NUL terminator.
+    pub const fn as_slice_with_nul(&self) -> &[u16] {
+        &self.0
+    }
+
+    /// Returns the number of characters, excluding the NUL terminator.
+    pub const fn len(&self) -> usize {
+        self.0.len() - 1
+    }
+
+    /// Returns `true` if the string has no characters.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the size of the string in bytes, including the NUL
+    /// terminator.
+    pub const fn size(&self) -> usize {
+        size_of_val(&self.0)
+    }
+
+    /// Returns an iterator over the characters of the string.
+    ///
+    /// Surrogate code units in strings created without checking are replaced
+    /// with `U+FFFD`.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.as_slice()
+            .iter()
+            .map(|&c| char::from_u32(u32::from(c)).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+}
+
+impl fmt::Display for CStr16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.chars().try_for_each(|c| fmt::Write::write_char(f, c))
+    }
+}
+
+impl PartialEq<str> for CStr16 {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for CStr16 {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl fmt::Debug for CStr16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.chars() {
+            write!(f, "{}", c.escape_debug())?;
+        }
+        f.write_str("\"")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToOwned for CStr16 {
+    type Owned = CString16;
+
+    fn to_owned(&self) -> Self::Owned {
+        CString16(self.0.to_vec())
+    }
+}
+
+impl AsRef<CStr16> for CStr16 {
+    fn as_ref(&self) -> &CStr16 {
+        self
+    }
+}
+
+/// An owned NUL-terminated UCS-2 string.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CString16(Vec<u16>);
+
+#[cfg(feature = "alloc")]
+impl CString16 {
+    /// Creates an empty string.
+    pub fn new() -> Self {
+        Self(alloc::vec![0])
+    }
+
+    /// Creates a string from a vector that ends with a NUL character.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The vector is not NUL-terminated, or it contains
+    ///   an interior NUL.
+    pub fn from_vec_with_nul(v: Vec<u16>) -> Result<Self> {
+        CStr16::from_u16_with_nul(&v)?;
+        Ok(Self(v))
+    }
+
+    /// Returns the characters of the string, including the NUL terminator.
+    pub fn into_vec_with_nul(self) -> Vec<u16> {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for CString16 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ops::Deref for CString16 {
+    type Target = CStr16;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { CStr16::from_u16_with_nul_unchecked(&self.0) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<CStr16> for CString16 {
+    fn as_ref(&self) -> &CStr16 {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Borrow<CStr16> for CString16 {
+    fn borrow(&self) -> &CStr16 {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&str> for CString16 {
+    type Error = Status;
+
+    /// Encodes a string as UCS-2.
+    ///
+    /// Fails with `INVALID_PARAMETER` if the string contains a NUL or a
+    /// character outside the BMP.
+    fn try_from(s: &str) -> Result<Self> {
+        let mut v = s.chars().map(encode_char).collect::<Result<Vec<_>>>()?;
+        v.push(0);
+        Ok(Self(v))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for CString16 {
+    type Err = Status;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::try_from(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&CStr16> for CString16 {
+    fn from(s: &CStr16) -> Self {
+        s.to_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for CString16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for CString16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<str> for CString16 {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<&str> for CString16 {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<CStr16> for CString16 {
+    fn eq(&self, other: &CStr16) -> bool {
+        **self == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<&CStr16> for CString16 {
+    fn eq(&self, other: &&CStr16) -> bool {
+        **self == **other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<CString16> for CStr16 {
+    fn eq(&self, other: &CString16) -> bool {
+        *self == **other
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_u16_with_nul() {
+        let s = CStr16::from_u16_with_nul(&[0x41, 0x42, 0]).unwrap();
+        assert_eq!(s.as_slice(), &[0x41, 0x42]);
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.size(), 6);
+
+        assert!(CStr16::from_u16_with_nul(&[0]).unwrap().is_empty());
+        assert_eq!(CStr16::from_u16_with_nul(&[]), Err(Status::INVALID_PARAMETER));
+        assert_eq!(CStr16::from_u16_with_nul(&[0x41]), Err(Status::INVALID_PARAMETER));
+        assert_eq!(CStr16::from_u16_with_nul(&[0x41, 0, 0x42, 0]), Err(Status::INVALID_PARAMETER));
+        assert_eq!(CStr16::from_u16_with_nul(&[0xD83D, 0xDE00, 0]), Err(Status::INVALID_PARAMETER));
+        assert_eq!(CStr16::from_u16_with_nul(&[0x41, 0xDFFF, 0]), Err(Status::INVALID_PARAMETER));
+        assert!(CStr16::from_u16_with_nul(&[0xD7FF, 0xE000, 0]).is_ok());
+    }
+
+    #[test]
+    fn literal() {
+        const S: &CStr16 = cstr16!("Héllo ✓");
+        assert_eq!(S.as_slice_with_nul(), &[0x48, 0xE9, 0x6C, 0x6C, 0x6F, 0x20, 0x2713, 0]);
+        assert_eq!(S, "Héllo ✓");
+        assert!(cstr16!("").is_empty());
+    }
+
+    #[test]
+    fn from_str_with_buf() {
+        let mut buf = [0; 4];
+        assert_eq!(CStr16::from_str_with_buf("abc", &mut buf).unwrap(), cstr16!("abc"));
+        assert_eq!(CStr16::from_str_with_buf("abcd", &mut buf), Err(Status::BUFFER_TOO_SMALL));
+        assert_eq!(CStr16::from_str_with_buf("a\0", &mut buf), Err(Status::INVALID_PARAMETER));
+    }
+
+    #[test]
+    fn from_ptr() {
+        let s = cstr16!("firmware");
+        let s = unsafe { CStr16::from_ptr(s.as_ptr()) }.unwrap();
+        assert_eq!(s, "firmware");
+
+        let s = [0x41, 0xDC00, 0];
+        assert_eq!(unsafe { CStr16::from_ptr(s.as_ptr()) }, Err(Status::INVALID_PARAMETER));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn convert() {
+        use alloc::string::ToString;
+
+        let s = CString16::try_from("Boot0001").unwrap();
+        assert_eq!(s, "Boot0001");
+        assert_eq!(s, cstr16!("Boot0001"));
+        assert_eq!(s.to_string(), "Boot0001");
+        assert_eq!(alloc::format!("{:?}", s), "\"Boot0001\"");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn outside_bmp() {
+        assert_eq!(CString16::try_from("\u{1F600}"), Err(Status::INVALID_PARAMETER));
+        assert_eq!(CString16::try_from("a\0b"), Err(Status::INVALID_PARAMETER));
+    }
+}
@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Synchronization
+//!
+//! UEFI has a single thread of execution, but event notification functions
+//! interrupt code running at a lower task priority level. State shared with
+//! notification functions is protected by raising the TPL to at least the
+//! level of those notification functions while it is accessed.
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::ops;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+
+use crate::Tpl;
+use crate::table::BootServices;
+
+/// The task priority services used by [`TplMutex`].
+///
+/// This is implemented by [`BootServices`], and allows the mutex to be tested
+/// with a fake source.
+pub trait TplSource {
+    /// Raises the TPL, returning the previous level.
+    fn raise(&self, tpl: Tpl) -> Tpl;
+
+    /// Restores the TPL to a level returned by [`raise()`].
+    ///
+    /// [`raise()`]: Self::raise
+    fn restore(&self, tpl: Tpl);
+}
+
+impl TplSource for BootServices {
+    fn raise(&self, tpl: Tpl) -> Tpl {
+        (self.RaiseTpl)(tpl)
+    }
+
+    fn restore(&self, tpl: Tpl) {
+        self.restore_tpl(tpl);
+    }
+}
+
+/// A mutual exclusion primitive that raises the TPL while locked.
+///
+/// The TPL of the mutex must be at least the level of any event notification
+/// function that locks it, so that they cannot run while it is held.
+pub struct TplMutex<T: ?Sized> {
+    tpl: Tpl,
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for TplMutex<T> {
+}
+unsafe impl<T: ?Sized + Send> Sync for TplMutex<T> {
+}
+
+impl<T> TplMutex<T> {
+    /// Creates a mutex that raises to `tpl` while locked.
+    pub const fn new(tpl: Tpl, value: T) -> Self {
+        Self {
+            tpl,
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the mutex, returning the value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> TplMutex<T> {
+    /// The TPL raised to while the mutex is locked.
+    pub fn tpl(&self) -> Tpl {
+        self.tpl
+    }
+
+    /// Raises the TPL and locks the mutex.
+    ///
+    /// The caller must be running at or below the TPL of the mutex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is already locked, which would otherwise deadlock
+    /// as there is no other thread to release it.
+    pub fn lock<'a, S: TplSource + ?Sized>(&'a self, source: &'a S) -> TplMutexGuard<'a, T, S> {
+        let old_tpl = source.raise(self.tpl);
+        if self.locked.swap(true, Ordering::Acquire) {
+            source.restore(old_tpl);
+            panic!("TplMutex is already locked");
+        }
+
+        TplMutexGuard {
+            mutex: self,
+            source,
+            old_tpl,
+        }
+    }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// No locking is needed as the mutex is borrowed mutably.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for TplMutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TplMutex")
+            .field("tpl", &self.tpl)
+            .field("locked", &self.locked.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+/// A locked [`TplMutex`].
+///
+/// The mutex is unlocked and the previous TPL restored when dropped.
+#[must_use = "the mutex is unlocked when the guard is dropped"]
+pub struct TplMutexGuard<'a, T: ?Sized, S: TplSource + ?Sized = BootServices> {
+    mutex: &'a TplMutex<T>,
+    source: &'a S,
+    old_tpl: Tpl,
+}
+
+impl<T: ?Sized, S: TplSource + ?Sized> ops::Deref for TplMutexGuard<'_, T, S> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized, S: TplSource + ?Sized> ops::DerefMut for TplMutexGuard<'_, T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized, S: TplSource + ?Sized> Drop for TplMutexGuard<'_, T, S> {
+    fn drop(&mut self) {
+        // Unlock before restoring the TPL, as notification functions that
+        // lock the mutex may run as soon as the TPL is lowered.
+        self.mutex.locked.store(false, Ordering::Release);
+        self.source.restore(self.old_tpl);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use super::*;
+
+    /// A TPL source that checks the state of a mutex when the TPL is
+    /// restored.
+    struct FakeTpl<'a, T> {
+        tpl: Cell<Tpl>,
+        mutex: &'a TplMutex<T>,
+        /// Whether the mutex was locked each time the TPL was restored.
+        restores: Cell<[Option<bool>; 2]>,
+    }
+
+    impl<'a, T> FakeTpl<'a, T> {
+        fn new(mutex: &'a TplMutex<T>) -> Self {
+            Self {
+                tpl: Cell::new(Tpl::APPLICATION),
+                mutex,
+                restores: Cell::new([None; 2]),
+            }
+        }
+    }
+
+    impl<T> TplSource for FakeTpl<'_, T> {
+        fn raise(&self, tpl: Tpl) -> Tpl {
+            assert!(tpl >= self.tpl.get(), "TPL lowered by raise");
+            self.tpl.replace(tpl)
+        }
+
+        fn restore(&self, tpl: Tpl) {
+            assert!(tpl <= self.tpl.get(), "TPL raised by restore");
+            self.tpl.set(tpl);
+
+            let mut restores = self.restores.get();
+            let slot = restores.iter_mut().find(|r| r.is_none()).expect("too many restores");
+            *slot = Some(self.mutex.locked.load(Ordering::Relaxed));
+            self.restores.set(restores);
+        }
+    }
+
+    #[test]
+    fn lock() {
+        let mutex = TplMutex::new(Tpl::NOTIFY, 1);
+        let tpl = FakeTpl::new(&mutex);
+
+        let mut guard = mutex.lock(&tpl);
+        assert_eq!(tpl.tpl.get(), Tpl::NOTIFY);
+        assert!(mutex.locked.load(Ordering::Relaxed));
+        *guard += 1;
+
+        drop(guard);
+        assert_eq!(tpl.tpl.get(), Tpl::APPLICATION);
+        assert_eq!(tpl.restores.get(), [Some(false), None]);
+
+        let guard = mutex.lock(&tpl);
+        assert_eq!(*guard, 2);
+        drop(guard);
+        assert_eq!(tpl.restores.get(), [Some(false), Some(false)]);
+    }
+
+    #[test]
+    fn nested() {
+        let outer = TplMutex::new(Tpl::CALLBACK, ());
+        let inner = TplMutex::new(Tpl::NOTIFY, ());
+        let tpl = FakeTpl::new(&outer);
+
+        let outer_guard = outer.lock(&tpl);
+        let inner_guard = inner.lock(&tpl);
+        assert_eq!(tpl.tpl.get(), Tpl::NOTIFY);
+
+        // The outer mutex stays locked at the outer TPL.
+        drop(inner_guard);
+        assert_eq!(tpl.tpl.get(), Tpl::CALLBACK);
+        assert!(!inner.locked.load(Ordering::Relaxed));
+        assert_eq!(tpl.restores.get(), [Some(true), None]);
+
+        drop(outer_guard);
+        assert_eq!(tpl.tpl.get(), Tpl::APPLICATION);
+        assert_eq!(tpl.restores.get(), [Some(true), Some(false)]);
+    }
+
+    #[test]
+    #[should_panic = "TplMutex is already locked"]
+    fn relock() {
+        let mutex = TplMutex::new(Tpl::NOTIFY, ());
+        let tpl = FakeTpl::new(&mutex);
+
+        let _guard = mutex.lock(&tpl);
+        let _ = mutex.lock(&tpl);
+    }
+}
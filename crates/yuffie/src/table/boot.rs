@@ -13,16 +13,34 @@
 //!
 //! [UEFI Spec]: https://uefi.org/sites/default/files/resources/UEFI_Spec_2_10_Aug29.pdf
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::ops;
+use core::ptr::NonNull;
+use core::time::Duration;
+
 use super::Header;
 use crate::Event;
 use crate::Tpl;
 use crate::mem::AllocateType;
 use crate::mem::MemoryDescriptor;
+#[cfg(feature = "alloc")]
+use crate::mem::MemoryMap;
 use crate::mem::MemoryType;
 use crate::mem::PhysicalAddress;
 use crate::prelude::*;
+use crate::proto::device_path::DevicePath;
+use crate::proto::device_path::DevicePathProtocol;
+
+pub type EventNotifyFn = extern "efiapi" fn(Event, *mut c_void);
 
-pub type EventNotifyFn = extern "efiapi" fn(Event, *const u8);
+/// The number of drivers that can be passed to
+/// [`BootServices::connect_controller()`] without the `alloc` feature.
+pub const CONNECT_DRIVERS_MAX: usize = 16;
 
 #[repr(transparent)]
 pub struct InterfaceType(u32);
@@ -31,6 +49,7 @@ impl InterfaceType {
     pub const NATIVE: Self = Self(0);
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct LocateSearchType(u32);
 
@@ -40,6 +59,54 @@ impl LocateSearchType {
     pub const BY_PROTOCOL: Self = Self(2);
 }
 
+/// A registration returned by `RegisterProtocolNotify()`, used as the search
+/// key for `BY_REGISTER_NOTIFY`.
+///
+/// The registration is removed when its event is closed, so it borrows the
+/// event.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct ProtocolRegistration<'a>(NonNull<u8>, PhantomData<&'a EventHandle<'a>>);
+
+/// Attributes for opening a protocol interface with `OpenProtocol()`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct OpenProtocolAttributes(u32);
+
+impl OpenProtocolAttributes {
+    /// Used in the implementation of `HandleProtocol()`.
+    pub const BY_HANDLE_PROTOCOL: Self = Self(0x01);
+    /// Used by a driver to get a protocol interface from a handle.
+    pub const GET_PROTOCOL: Self = Self(0x02);
+    /// Used by a driver to test for the existence of a protocol interface on
+    /// a handle. The interface is not returned.
+    pub const TEST_PROTOCOL: Self = Self(0x04);
+    /// Used by bus drivers to show that a protocol interface is being used by
+    /// one of the child controllers of a bus.
+    pub const BY_CHILD_CONTROLLER: Self = Self(0x08);
+    /// Used by a driver to gain access to a protocol interface. Drivers that
+    /// already have the interface open will be disconnected if they did not
+    /// open it with `EXCLUSIVE`.
+    pub const BY_DRIVER: Self = Self(0x10);
+    /// Used by applications to gain exclusive access to a protocol interface.
+    /// Drivers that have the interface open with `BY_DRIVER` will be stopped.
+    pub const EXCLUSIVE: Self = Self(0x20);
+
+    /// Returns `true` if all the attributes in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl ops::BitOr for OpenProtocolAttributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct TimerDelay(u32);
 
@@ -48,10 +115,57 @@ impl TimerDelay {
     pub const CANCEL: Self = Self(0);
     /// The event is to be signaled periodically.
     pub const PERIODIC: Self = Self(1);
-    /// The event is to be triggered in 100ms units.
+    /// The event is to be triggered in 100ns units.
     pub const RELATIVE: Self = Self(2);
 }
 
+/// The type and trigger time of a timer, for use with
+/// [`EventHandle::set_timer()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timer {
+    kind: TimerDelay,
+    /// Trigger time in 100ns units.
+    time: u64,
+}
+
+impl Timer {
+    /// Cancels the timer.
+    pub const CANCEL: Self = Self {
+        kind: TimerDelay::CANCEL,
+        time: 0,
+    };
+
+    /// Signals the event every `period`.
+    pub fn periodic(period: Duration) -> Self {
+        Self {
+            kind: TimerDelay::PERIODIC,
+            time: Self::to_100ns(period),
+        }
+    }
+
+    /// Signals the event once after `delay`.
+    pub fn oneshot(delay: Duration) -> Self {
+        Self {
+            kind: TimerDelay::RELATIVE,
+            time: Self::to_100ns(delay),
+        }
+    }
+
+    /// Returns the type of timer.
+    pub fn kind(&self) -> TimerDelay {
+        self.kind
+    }
+
+    /// Returns the trigger time in the 100ns units used by `SetTimer()`.
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    fn to_100ns(duration: Duration) -> u64 {
+        u64::try_from(duration.as_nanos() / 100).unwrap_or(u64::MAX)
+    }
+}
+
 /// The UEFI Boot Services table
 #[rustfmt::skip]
 #[repr(C)]
@@ -70,7 +184,7 @@ pub struct BootServices {
     pub FreePool: extern "efiapi" fn(*mut u8) -> Status,
 
     // Event & Timer Services
-    pub CreateEvent: extern "efiapi" fn(EventType, Tpl, Option<EventNotifyFn>, *const u8, *mut Event) -> Status,
+    pub CreateEvent: extern "efiapi" fn(EventType, Tpl, Option<EventNotifyFn>, *mut c_void, *mut Event) -> Status,
     pub SetTimer: extern "efiapi" fn(Event, TimerDelay, u64) -> Status,
     pub WaitForEvent: extern "efiapi" fn(usize, *const Event, *mut usize) -> Status,
     pub SignalEvent: extern "efiapi" fn(Event) -> Status,
@@ -78,18 +192,18 @@ pub struct BootServices {
     pub CheckEvent: extern "efiapi" fn(Event) -> Status,
 
     // Protocol Handler Services
-    pub InstallProtocolInterface: extern "efiapi" fn(*mut Handle, *const Guid, InterfaceType, *const u8) -> Status,
+    pub InstallProtocolInterface: extern "efiapi" fn(*mut Option<Handle>, *const Guid, InterfaceType, *const u8) -> Status,
     pub ReinstallProtocolInterface: extern "efiapi" fn(Handle, *const Guid, *const u8, *const u8) -> Status,
     pub UninstallProtocolInterface: extern "efiapi" fn(Handle, *const Guid, *const u8) -> Status,
     pub HandleProtocol: extern "efiapi" fn(Handle, *const Guid, *mut *mut u8) -> Status,
     _Reserved: usize,
     pub RegisterProtocolNotify: extern "efiapi" fn(*const Guid, Event, *mut *mut u8) -> Status,
     pub LocateHandle: extern "efiapi" fn(LocateSearchType, *const Guid, *const u8, *mut usize, *mut Handle) -> Status,
-    pub LocateDevicePath: extern "efiapi" fn(*const Guid, *mut *mut u8, *mut Handle) -> Status,
+    pub LocateDevicePath: extern "efiapi" fn(*const Guid, *mut *const DevicePathProtocol, *mut Handle) -> Status,
     pub InstallConfigurationTable: extern "efiapi" fn(*const Guid, *const u8) -> Status,
 
     // Image Services
-    pub LoadImage: extern "efiapi" fn(bool, Handle,  *mut u8, *mut u8, usize, *mut Handle) -> Status,
+    pub LoadImage: extern "efiapi" fn(bool, Handle, *const DevicePathProtocol, *mut u8, usize, *mut Handle) -> Status,
     pub StartImage: extern "efiapi" fn(Handle, *mut usize, *mut *mut u16) -> Status,
     pub Exit: extern "efiapi" fn(Handle, Status, usize, *const u16) -> Status,
     pub UnloadImage: extern "efiapi" fn(Handle) -> Status,
@@ -101,12 +215,12 @@ pub struct BootServices {
     pub SetWatchdogTimer: extern "efiapi" fn(usize, u64, usize, *const u16) -> Status,
 
     // Driver Support Services
-    pub ConnectController: extern "efiapi" fn(Handle, *const Handle, *const u8, bool) -> Status,
-    pub DisconnectController: extern "efiapi" fn(Handle, *const Handle, *const Handle) -> Status,
+    pub ConnectController: extern "efiapi" fn(Handle, *const Option<Handle>, *const DevicePathProtocol, bool) -> Status,
+    pub DisconnectController: extern "efiapi" fn(Handle, Option<Handle>, Option<Handle>) -> Status,
 
     // Open and Close Protocol Services
-    pub OpenProtocol: extern "efiapi" fn(Handle, *const Guid, *mut *mut u8, Handle, Handle, u32) -> Status,
-    pub CloseProtocol: extern "efiapi" fn(Handle, *const Guid, Handle, Handle) -> Status,
+    pub OpenProtocol: extern "efiapi" fn(Handle, *const Guid, *mut *mut u8, Handle, Option<Handle>, OpenProtocolAttributes) -> Status,
+    pub CloseProtocol: extern "efiapi" fn(Handle, *const Guid, Handle, Option<Handle>) -> Status,
     pub OpenProtocolInformation: extern "efiapi" fn(Handle, *const Guid, *mut *mut u8, *mut usize) -> Status,
 
     // Library Services
@@ -124,7 +238,7 @@ pub struct BootServices {
     // Misc Services
     pub CopyMem: extern "efiapi" fn(*mut u8, *mut u8, usize),
     pub SetMem: extern "efiapi" fn(*mut u8, usize, u8),
-    pub CreateEventEx: extern "efiapi" fn(EventType, Tpl, Option<EventNotifyFn>, *const u8, *const Guid, *mut Event) -> Status,
+    pub CreateEventEx: extern "efiapi" fn(EventType, Tpl, Option<EventNotifyFn>, *mut c_void, *const Guid, *mut Event) -> Status,
 }
 
 impl BootServices {
@@ -132,9 +246,16 @@ impl BootServices {
         &self.Hdr
     }
 
-    /// Raises a task's priority level and returns its previous level.
-    pub fn raise_tpl(&self, tpl: Tpl) -> Tpl {
-        (self.RaiseTpl)(tpl)
+    /// Raises a task's priority level.
+    ///
+    /// The previous level is restored when the returned guard is dropped.
+    /// `tpl` must not be lower than the current level.
+    pub fn raise_tpl(&self, tpl: Tpl) -> TplGuard<'_> {
+        let old = (self.RaiseTpl)(tpl);
+        TplGuard {
+            bs: self,
+            old,
+        }
     }
 
     /// Restores a task's priority level to its previous value.
@@ -144,17 +265,22 @@ impl BootServices {
 
     /// Allocates memory pages from the system.
     ///
+    /// `address` is the maximum address for `MAX_ADDRESS` or the requested
+    /// address for `ADDRESS`. It is ignored for `ANY_PAGES`.
+    ///
     /// # Errors
     ///
     /// - `OUT_OF_RESOURCES`: The pages could not be allocated.
     /// - `INVALID_PARAMETER`: `mem_type` is `PERSISTENT` or `UNACCEPTED`.
+    /// - `NOT_FOUND`: The requested pages could not be found.
     pub fn allocate_pages(
         &self,
         alloc_type: AllocateType,
         mem_type: MemoryType,
         pages: usize,
+        address: PhysicalAddress,
     ) -> Result<PhysicalAddress> {
-        let mut memory = PhysicalAddress::from(0);
+        let mut memory = address;
         let status = (self.AllocatePages)(alloc_type, mem_type, pages, &mut memory);
 
         match status {
@@ -175,7 +301,39 @@ impl BootServices {
         (self.FreePages)(memory, pages).into()
     }
 
-    // TODO: GetMemoryMap
+    /// Returns the current memory map.
+    ///
+    /// The buffer holding the map is grown until it is large enough to hold
+    /// the entire map.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The buffer could not be provided.
+    #[cfg(feature = "alloc")]
+    pub fn memory_map(&self) -> Result<MemoryMap> {
+        let mut map = MemoryMap::empty();
+
+        loop {
+            match self.get_memory_map(&mut map) {
+                Ok(()) => return Ok(map),
+                Err(Status::BUFFER_TOO_SMALL) => map.grow(),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Fills an existing memory map without allocating.
+    ///
+    /// # Errors
+    ///
+    /// - `BUFFER_TOO_SMALL`: The map buffer was too small. The required size is
+    ///   stored in the map.
+    /// - `INVALID_PARAMETER`: The buffer could not be provided.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn get_memory_map(&self, map: &mut MemoryMap) -> Result<()> {
+        let (map_size, buffer, key, desc_size, desc_version) = map.raw_parts_mut();
+        (self.GetMemoryMap)(map_size, buffer, key, desc_size, desc_version).into()
+    }
 
     /// Allocates pool memory.
     ///
@@ -202,6 +360,9 @@ impl BootServices {
 
     /// Creates an event.
     ///
+    /// The `notify` closure is called when the event is notified, and is
+    /// freed after the returned handle closes the event.
+    ///
     /// # Errors
     ///
     /// - `OUT_OF_RESOURCES`: The event could not be allocated.
@@ -213,20 +374,43 @@ impl BootServices {
     ///   `NOTIFY_WAIT` set and `notify` is `None`.
     /// - `INVALID_PARAMETER`: `event_type` has either `NOTIFY_SIGNAL` or
     ///   `NOTIFY_WAIT` set and `tpl` is not a supported level.
+    #[cfg(feature = "alloc")]
     pub fn create_event(
+        &self,
+        event_type: EventType,
+        tpl: Tpl,
+        notify: Option<Box<dyn FnMut(Event)>>,
+    ) -> Result<EventHandle<'_>> {
+        let (notify_fn, context, notify) = EventHandle::trampoline(notify);
+        let event = unsafe { self.create_event_raw(event_type, tpl, notify_fn, context)? };
+
+        Ok(EventHandle {
+            bs: self,
+            event,
+            notify,
+        })
+    }
+
+    /// Creates an event with a raw notification function and context.
+    ///
+    /// # Safety
+    ///
+    /// `context` must be valid for `notify` to use until the event is closed.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: The event could not be allocated.
+    /// - `INVALID_PARAMETER`: One of the parameters has an invalid value.
+    pub unsafe fn create_event_raw(
         &self,
         event_type: EventType,
         tpl: Tpl,
         notify: Option<EventNotifyFn>,
-        context: Option<&[u8]>,
+        context: *mut c_void,
     ) -> Result<Event> {
         let mut event = unsafe { Event::uninit() };
-        let ctx = match context {
-            Some(c) => c.as_ptr(),
-            None => core::ptr::null(),
-        };
 
-        let status = (self.CreateEvent)(event_type, tpl, notify, ctx, &mut event);
+        let status = (self.CreateEvent)(event_type, tpl, notify, context, &mut event);
         match status {
             Status::SUCCESS => Ok(event),
             e => Err(e),
@@ -288,16 +472,19 @@ impl BootServices {
 
     /// Installs a protocol interface on a device handle.
     ///
+    /// If `handle` is `None`, a new handle is created.
+    ///
     /// # Errors
     ///
     /// - `INVALID_PARAMETER`: `protocol` is already installed on `handle`.
     /// - `OUT_OF_RESOURCES`: Space for a new handle could not be allocated.
     pub fn install_protocol_interface(
         &self,
+        handle: Option<Handle>,
         protocol: &Guid,
         interface: *const u8,
     ) -> Result<Handle> {
-        let mut handle = unsafe { Handle::uninit() };
+        let mut handle = handle;
         let status = (self.InstallProtocolInterface)(
             &mut handle,
             protocol,
@@ -306,7 +493,7 @@ impl BootServices {
         );
 
         match status {
-            Status::SUCCESS => Ok(handle),
+            Status::SUCCESS => handle.ok_or(Status::INVALID_PARAMETER),
             e => Err(e),
         }
     }
@@ -329,10 +516,145 @@ impl BootServices {
         (self.UninstallProtocolInterface)(handle, protocol, interface).into()
     }
 
-    // TODO; HandleProtocol
-    // TODO; RegisterProtocolNotify
-    // TODO: LocateHandle
-    // TODO: LocateDevicePath
+    /// Queries a handle to determine if it supports a protocol.
+    ///
+    /// New code should use [`BootServices::open_protocol`].
+    ///
+    /// The interface is not tracked by firmware, so a pointer is returned
+    /// instead of a reference. The caller must ensure it is not aliased
+    /// mutably when dereferencing it.
+    ///
+    /// # Errors
+    ///
+    /// - `UNSUPPORTED`: The device does not support the protocol.
+    /// - `INVALID_PARAMETER`: `handle` is invalid.
+    pub fn handle_protocol<P: Protocol>(&self, handle: Handle) -> Result<NonNull<P>> {
+        let mut interface = core::ptr::null_mut();
+        let status = (self.HandleProtocol)(handle, &P::GUID, &mut interface);
+
+        match status {
+            Status::SUCCESS => NonNull::new(interface.cast::<P>()).ok_or(Status::UNSUPPORTED),
+            e => Err(e),
+        }
+    }
+
+    /// Registers an event to be signaled whenever an interface is installed
+    /// for a protocol.
+    ///
+    /// The registration is removed when the event is closed.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: Space for the registration could not be allocated.
+    /// - `INVALID_PARAMETER`: `event` is not valid.
+    pub fn register_protocol_notify<'e>(
+        &self,
+        protocol: &Guid,
+        event: &'e EventHandle<'_>,
+    ) -> Result<ProtocolRegistration<'e>> {
+        let mut registration = core::ptr::null_mut();
+
+        match (self.RegisterProtocolNotify)(protocol, event.event(), &mut registration) {
+            Status::SUCCESS => NonNull::new(registration)
+                .map(|r| ProtocolRegistration(r, PhantomData))
+                .ok_or(Status::INVALID_PARAMETER),
+            e => Err(e),
+        }
+    }
+
+    /// Watches for interfaces of a protocol being installed.
+    ///
+    /// The event of the watch is signaled once after registering, so that
+    /// handles installed before the call are also returned by
+    /// [`ProtocolWatch::next_handle()`].
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: The event or registration could not be allocated.
+    pub fn watch_protocol<P: Protocol>(&self) -> Result<ProtocolWatch<'_, P>> {
+        let event = unsafe {
+            let event = self.create_event_raw(
+                EventType::NONE,
+                Tpl::APPLICATION,
+                None,
+                core::ptr::null_mut(),
+            )?;
+            EventHandle::from_raw(self, event)
+        };
+        let registration = self.register_protocol_notify(&P::GUID, &event)?.0;
+        event.signal()?;
+
+        Ok(ProtocolWatch {
+            event,
+            registration,
+            _protocol: PhantomData,
+        })
+    }
+
+    /// Returns an array of handles that support a specified protocol.
+    ///
+    /// With `BY_REGISTER_NOTIFY`, the next handle that is new for the
+    /// registration is returned.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: No handles match the search.
+    /// - `INVALID_PARAMETER`: `search_type` is not a valid value, or the
+    ///   protocol or search key required by `search_type` is missing.
+    #[cfg(feature = "alloc")]
+    pub fn locate_handle(
+        &self,
+        search_type: LocateSearchType,
+        protocol: Option<&Guid>,
+        search_key: Option<ProtocolRegistration<'_>>,
+    ) -> Result<Vec<Handle>> {
+        let protocol = protocol.map_or(core::ptr::null(), core::ptr::from_ref);
+        let search_key = search_key.map_or(core::ptr::null(), |k| k.0.as_ptr().cast_const());
+        let mut handles = Vec::new();
+
+        loop {
+            let mut size = handles.capacity() * size_of::<Handle>();
+            let status = (self.LocateHandle)(
+                search_type,
+                protocol,
+                search_key,
+                &mut size,
+                handles.as_mut_ptr(),
+            );
+
+            match status {
+                Status::SUCCESS => {
+                    unsafe { handles.set_len(size / size_of::<Handle>()) };
+                    return Ok(handles);
+                }
+                Status::BUFFER_TOO_SMALL => handles.reserve_exact(size / size_of::<Handle>()),
+                e => return Err(e),
+            }
+        }
+    }
+
+    /// Locates the handle to a device on the device path that supports the
+    /// specified protocol.
+    ///
+    /// On success, `path` is updated to the remaining part of the path after
+    /// the matched device.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: No handles match the search.
+    pub fn locate_device_path<P: Protocol>(&self, path: &mut &DevicePath) -> Result<Handle> {
+        let mut remaining = path.as_ptr();
+        let mut handle = unsafe { Handle::uninit() };
+
+        match (self.LocateDevicePath)(&P::GUID, &mut remaining, &mut handle) {
+            Status::SUCCESS => {
+                // The remaining path is a suffix of the original path.
+                *path = unsafe { DevicePath::from_ptr(remaining) };
+                Ok(handle)
+            }
+            e => Err(e),
+        }
+    }
 
     /// Adds, updates, or removes a configuration table entry from the system
     /// table.
@@ -351,8 +673,74 @@ impl BootServices {
         (self.InstallConfigurationTable)(guid, table).into()
     }
 
-    // TODO: LoadImage
-    // TODO: StartImage
+    /// Loads an image into memory, returning its handle.
+    ///
+    /// If `boot_policy` is `true`, the image is being loaded by the boot
+    /// manager and `source` is a device path that may be matched loosely.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The device path could not be found.
+    /// - `INVALID_PARAMETER`: `parent` is not a valid image handle.
+    /// - `UNSUPPORTED`: The image type is not supported.
+    /// - `OUT_OF_RESOURCES`: The image could not be loaded due to a lack of
+    ///   resources.
+    /// - `LOAD_ERROR`: The image format was corrupt or not understood.
+    /// - `DEVICE_ERROR`: The image could not be read from the device.
+    /// - `ACCESS_DENIED`: The platform policy prohibits loading the image.
+    /// - `SECURITY_VIOLATION`: The image failed authentication.
+    pub fn load_image(
+        &self,
+        boot_policy: bool,
+        parent: Handle,
+        source: ImageSource<'_>,
+    ) -> Result<Handle> {
+        let (path, buffer, size) = match source {
+            ImageSource::DevicePath(path) => (path.as_ptr(), core::ptr::null(), 0),
+            ImageSource::Buffer {
+                buffer,
+                file_path,
+            } => {
+                let path = file_path.map_or(core::ptr::null(), DevicePath::as_ptr);
+                (path, buffer.as_ptr(), buffer.len())
+            }
+        };
+
+        let mut image = unsafe { Handle::uninit() };
+        let status =
+            (self.LoadImage)(boot_policy, parent, path, buffer.cast_mut(), size, &mut image);
+
+        match status {
+            Status::SUCCESS => Ok(image),
+            e => Err(e),
+        }
+    }
+
+    /// Transfers control to a loaded image's entry point.
+    ///
+    /// Returns the exit status of the image and the data it passed to
+    /// `Exit()`, if any.
+    ///
+    /// # Errors
+    ///
+    /// The returned status is an error if the image could not be started:
+    ///
+    /// - `INVALID_PARAMETER`: `image` is not a valid image handle or has
+    ///   already been started.
+    /// - `SECURITY_VIOLATION`: The image failed authentication when loaded.
+    pub fn start_image(&self, image: Handle) -> (Status, Option<ExitData<'_>>) {
+        let mut size = 0;
+        let mut data = core::ptr::null_mut();
+        let status = (self.StartImage)(image, &mut size, &mut data);
+
+        let data = (!data.is_null()).then_some(ExitData {
+            bs: self,
+            buffer: data,
+            size,
+        });
+
+        (status, data)
+    }
 
     /// Terminates a loaded EFI image and returns control to boot services.
     ///
@@ -360,9 +748,9 @@ impl BootServices {
     ///
     /// - `INVALID_PARAMETER`: The image specified has been loaded and started,
     ///   but the image is not the currently executing image.
-    pub fn exit(&self, image: Handle, exit_status: Status, data: Option<&[u16]>) -> Result<()> {
+    pub fn exit(&self, image: Handle, exit_status: Status, data: Option<&CStr16>) -> Result<()> {
         let (data, data_size) = match data {
-            Some(d) => (d.as_ptr(), d.len()),
+            Some(d) => (d.as_ptr(), d.size()),
             None => (core::ptr::null(), 0),
         };
         (self.Exit)(image, exit_status, data_size, data).into()
@@ -382,11 +770,19 @@ impl BootServices {
 
     /// Terminates all boot services.
     ///
+    /// Prefer [`SystemTable::exit_boot_services`], which handles retrieving
+    /// the memory map and invalidating the system table.
+    ///
     /// # Errors
     ///
     /// - `INVALID_PARAMETER`: `key` is incorrect
-    pub fn exit_boot_services(&self, image: Handle, key: usize) -> Result<()> {
-        // TODO: Update SystemTable to invalidate fields
+    ///
+    /// # Safety
+    ///
+    /// On success, no boot services may be used, including memory allocation.
+    /// On failure, only `GetMemoryMap()` and `ExitBootServices()` may be
+    /// called.
+    pub unsafe fn exit_boot_services(&self, image: Handle, key: usize) -> Result<()> {
         (self.ExitBootServices)(image, key).into()
     }
 
@@ -426,26 +822,392 @@ impl BootServices {
         &self,
         timeout: usize,
         code: u64,
-        data: Option<&[u16]>,
+        data: Option<&CStr16>,
     ) -> Result<()> {
         let (data, size) = match data {
-            Some(d) => (d.as_ptr(), d.len()),
+            Some(d) => (d.as_ptr(), d.size()),
             None => (core::ptr::null(), 0),
         };
 
         (self.SetWatchdogTimer)(timeout, code, size, data).into()
     }
 
-    // TODO: ConnectController
-    // TODO: DisconnectController
-    // TODO: OpenProtocol
-    // TODO: CloseProtocol
+    /// Connects one or more drivers to a controller.
+    ///
+    /// If `drivers` is not empty, the driver images are tried in order before
+    /// the usual driver selection. If `recursive` is `true`, the children
+    /// created by the drivers are also connected.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: No drivers were connected to the controller.
+    /// - `INVALID_PARAMETER`: Without the `alloc` feature, `drivers` has more
+    ///   than [`CONNECT_DRIVERS_MAX`] entries.
+    /// - `SECURITY_VIOLATION`: The user has no permission to start drivers for
+    ///   the device path of the controller.
+    pub fn connect_controller(
+        &self,
+        controller: Handle,
+        drivers: &[Handle],
+        remaining: Option<&DevicePath>,
+        recursive: bool,
+    ) -> Result<()> {
+        let path = remaining.map_or(core::ptr::null(), DevicePath::as_ptr);
+        if drivers.is_empty() {
+            return (self.ConnectController)(controller, core::ptr::null(), path, recursive).into();
+        }
+
+        // The list of drivers is terminated by `None`.
+        #[cfg(feature = "alloc")]
+        let list: Vec<Option<Handle>> = drivers.iter().copied().map(Some).chain([None]).collect();
+        #[cfg(not(feature = "alloc"))]
+        let list = {
+            if drivers.len() > CONNECT_DRIVERS_MAX {
+                return Err(Status::INVALID_PARAMETER);
+            }
+            let mut list = [None; CONNECT_DRIVERS_MAX + 1];
+            for (entry, driver) in list.iter_mut().zip(drivers) {
+                *entry = Some(*driver);
+            }
+            list
+        };
+
+        (self.ConnectController)(controller, list.as_ptr(), path, recursive).into()
+    }
+
+    /// Disconnects one or more drivers from a controller.
+    ///
+    /// If `driver` is `None`, all drivers managing the controller are
+    /// disconnected. If `child` is `None`, all children of the controller are
+    /// destroyed.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: `driver` or `child` is not a valid handle.
+    /// - `OUT_OF_RESOURCES`: There are not enough resources to disconnect.
+    /// - `DEVICE_ERROR`: The controller could not be disconnected because of a
+    ///   device error.
+    pub fn disconnect_controller(
+        &self,
+        controller: Handle,
+        driver: Option<Handle>,
+        child: Option<Handle>,
+    ) -> Result<()> {
+        (self.DisconnectController)(controller, driver, child).into()
+    }
+
+    /// Recursively connects all drivers to all controllers.
+    ///
+    /// This is repeated until connecting no longer creates new handles, like
+    /// `EfiBootManagerConnectAll()` in edk2. Failures to connect individual
+    /// controllers are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - The error from `LocateHandleBuffer()`.
+    pub fn connect_all(&self) -> Result<()> {
+        let mut count = 0;
+
+        loop {
+            let handles = self.locate_handle_buffer(LocateSearchType::ALL_HANDLES, None, None)?;
+            if handles.len() == count {
+                return Ok(());
+            }
+            count = handles.len();
+
+            for handle in handles.iter() {
+                let _ = self.connect_controller(*handle, &[], None, true);
+            }
+        }
+    }
+
+    /// Opens a protocol interface on a handle. The protocol is closed when the
+    /// returned guard is dropped.
+    ///
+    /// `attributes` must contain `BY_DRIVER` or `EXCLUSIVE`, as the firmware
+    /// refuses to open the interface with either of these twice, so the guard
+    /// is the only reference to it. Other attributes require
+    /// [`BootServices::open_protocol_unchecked`].
+    ///
+    /// # Errors
+    ///
+    /// - `UNSUPPORTED`: `handle` does not support the protocol.
+    /// - `INVALID_PARAMETER`: A handle is invalid, or `attributes` contains
+    ///   neither `BY_DRIVER` nor `EXCLUSIVE`.
+    /// - `ACCESS_DENIED`: The interface is already opened by the controller, by
+    ///   another driver, or with exclusive access.
+    /// - `ALREADY_STARTED`: The interface is already opened by the agent with
+    ///   the same attributes.
+    pub fn open_protocol<P: Protocol>(
+        &self,
+        handle: Handle,
+        agent: Handle,
+        controller: Option<Handle>,
+        attributes: OpenProtocolAttributes,
+    ) -> Result<ScopedProtocol<'_, P>> {
+        if !attributes.contains(OpenProtocolAttributes::BY_DRIVER)
+            && !attributes.contains(OpenProtocolAttributes::EXCLUSIVE)
+        {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        unsafe { self.open_protocol_unchecked(handle, agent, controller, attributes) }
+    }
+
+    /// Opens a protocol interface on a handle with any attributes. The protocol
+    /// is closed when the returned guard is dropped.
+    ///
+    /// `TEST_PROTOCOL` does not return an interface; use
+    /// [`BootServices::test_protocol`] instead.
+    ///
+    /// # Safety
+    ///
+    /// With `GET_PROTOCOL` or `BY_HANDLE_PROTOCOL` the same interface can be
+    /// opened more than once. The interface must not be accessed through
+    /// another guard or pointer while the returned guard is dereferenced.
+    ///
+    /// # Errors
+    ///
+    /// - `UNSUPPORTED`: `handle` does not support the protocol.
+    /// - `INVALID_PARAMETER`: A handle is invalid, or `attributes` is not a
+    ///   legal value.
+    /// - `ACCESS_DENIED`: The interface is already opened by the controller, by
+    ///   another driver, or with exclusive access.
+    /// - `ALREADY_STARTED`: The interface is already opened by the agent with
+    ///   the same attributes.
+    pub unsafe fn open_protocol_unchecked<P: Protocol>(
+        &self,
+        handle: Handle,
+        agent: Handle,
+        controller: Option<Handle>,
+        attributes: OpenProtocolAttributes,
+    ) -> Result<ScopedProtocol<'_, P>> {
+        if attributes.contains(OpenProtocolAttributes::TEST_PROTOCOL) {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        let mut interface = core::ptr::null_mut();
+        let status =
+            (self.OpenProtocol)(handle, &P::GUID, &mut interface, agent, controller, attributes);
+
+        match status {
+            Status::SUCCESS => {
+                let interface = NonNull::new(interface.cast::<P>()).ok_or(Status::UNSUPPORTED)?;
+                Ok(ScopedProtocol {
+                    bs: self,
+                    interface,
+                    handle,
+                    agent,
+                    controller,
+                })
+            }
+            e => Err(e),
+        }
+    }
+
+    /// Tests whether a protocol interface is installed on a handle.
+    ///
+    /// # Errors
+    ///
+    /// - `UNSUPPORTED`: `handle` does not support the protocol.
+    /// - `INVALID_PARAMETER`: A handle is invalid.
+    pub fn test_protocol<P: Protocol>(
+        &self,
+        handle: Handle,
+        agent: Handle,
+        controller: Option<Handle>,
+    ) -> Result<()> {
+        (self.OpenProtocol)(
+            handle,
+            &P::GUID,
+            core::ptr::null_mut(),
+            agent,
+            controller,
+            OpenProtocolAttributes::TEST_PROTOCOL,
+        )
+        .into()
+    }
+
+    /// Closes a protocol on a handle that was opened using `OpenProtocol()`.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: A handle is invalid.
+    /// - `NOT_FOUND`: `handle` does not support the protocol, or the protocol
+    ///   is not opened by the agent and controller.
+    pub fn close_protocol(
+        &self,
+        handle: Handle,
+        protocol: &Guid,
+        agent: Handle,
+        controller: Option<Handle>,
+    ) -> Result<()> {
+        (self.CloseProtocol)(handle, protocol, agent, controller).into()
+    }
+
     // TODO: OpenProtocolInformation
-    // TODO: ProtocolsPerHandle
-    // TODO: LocateHandleBuffer
-    // TODO: LocateProtocol
-    // TODO: InstallMultipleProtocolInterfaces
-    // TODO: UninstallMultipleProtocolInterfaces
+
+    /// Retrieves the list of protocol interface GUIDs that are installed on a
+    /// handle.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: There is not enough pool memory to store the
+    ///   result.
+    /// - `INVALID_PARAMETER`: `handle` is invalid.
+    pub fn protocols_per_handle(&self, handle: Handle) -> Result<ProtocolsBuffer<'_>> {
+        let mut buffer = core::ptr::null_mut();
+        let mut count = 0;
+        let status = (self.ProtocolsPerHandle)(handle, &mut buffer, &mut count);
+
+        match status {
+            Status::SUCCESS => Ok(ProtocolsBuffer {
+                bs: self,
+                buffer: buffer.cast(),
+                count,
+            }),
+            e => Err(e),
+        }
+    }
+
+    /// Returns an array of handles that support the requested protocol in a
+    /// buffer allocated from pool.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: No handles match the search.
+    /// - `OUT_OF_RESOURCES`: There is not enough pool memory to store the
+    ///   matching results.
+    /// - `INVALID_PARAMETER`: `search_type` is not a valid value, or the
+    ///   protocol or search key required by `search_type` is missing.
+    pub fn locate_handle_buffer(
+        &self,
+        search_type: LocateSearchType,
+        protocol: Option<&Guid>,
+        search_key: Option<ProtocolRegistration<'_>>,
+    ) -> Result<HandleBuffer<'_>> {
+        let protocol = protocol.map_or(core::ptr::null(), core::ptr::from_ref);
+        let search_key = search_key.map_or(core::ptr::null(), |k| k.0.as_ptr().cast_const());
+        let mut buffer = core::ptr::null_mut();
+        let mut count = 0;
+
+        let status =
+            (self.LocateHandleBuffer)(search_type, protocol, search_key, &mut count, &mut buffer);
+
+        match status {
+            Status::SUCCESS => Ok(HandleBuffer {
+                bs: self,
+                buffer,
+                count,
+            }),
+            e => Err(e),
+        }
+    }
+
+    /// Returns the first protocol instance that matches the given protocol.
+    ///
+    /// As with [`BootServices::handle_protocol`], the interface is returned as
+    /// a pointer that the caller must not alias mutably.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: No protocol instances were found that match the protocol.
+    pub fn locate_protocol<P: Protocol>(&self) -> Result<NonNull<P>> {
+        let mut interface = core::ptr::null_mut();
+        let status = (self.LocateProtocol)(&P::GUID, core::ptr::null(), &mut interface);
+
+        match status {
+            Status::SUCCESS => NonNull::new(interface.cast::<P>()).ok_or(Status::NOT_FOUND),
+            e => Err(e),
+        }
+    }
+
+    /// Installs one or more protocol interfaces on a handle.
+    ///
+    /// This provides the semantics of `InstallMultipleProtocolInterfaces()`,
+    /// which cannot be called from Rust as it is variadic. The interfaces are
+    /// installed at `TPL_NOTIFY`, and either all or none of them are
+    /// installed. If `handle` is `None`, a new handle is created.
+    ///
+    /// # Safety
+    ///
+    /// Each interface must be valid for its protocol until it is uninstalled.
+    /// A [`DevicePathProtocol`] interface must be a well-formed device path,
+    /// terminated by an end node.
+    ///
+    /// # Errors
+    ///
+    /// - `ALREADY_STARTED`: A device path interface is given, and a handle with
+    ///   the same device path already exists.
+    /// - The error from installing an interface.
+    pub unsafe fn install_protocols(
+        &self,
+        handle: Option<Handle>,
+        protocols: &[(&Guid, *const u8)],
+    ) -> Result<Handle> {
+        let _tpl = self.raise_tpl(Tpl::NOTIFY);
+
+        for (guid, interface) in protocols {
+            if **guid == DevicePathProtocol::GUID && !interface.is_null() {
+                let mut path = unsafe { DevicePath::from_ptr(interface.cast()) };
+                let found = self.locate_device_path::<DevicePathProtocol>(&mut path);
+                if found.is_ok() && path.nodes().next().is_none() {
+                    return Err(Status::ALREADY_STARTED);
+                }
+            }
+        }
+
+        let mut handle = handle;
+        for (i, (guid, interface)) in protocols.iter().enumerate() {
+            match self.install_protocol_interface(handle, guid, *interface) {
+                Ok(h) => handle = Some(h),
+                Err(e) => {
+                    if let Some(handle) = handle {
+                        for (guid, interface) in protocols[..i].iter().rev() {
+                            let _ = self.uninstall_protocol_interface(handle, guid, *interface);
+                        }
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        handle.ok_or(Status::INVALID_PARAMETER)
+    }
+
+    /// Removes one or more protocol interfaces from a handle.
+    ///
+    /// This provides the semantics of `UninstallMultipleProtocolInterfaces()`.
+    /// The interfaces are removed at `TPL_NOTIFY`, and either all or none of
+    /// them are removed.
+    ///
+    /// # Safety
+    ///
+    /// The interfaces must have been installed with
+    /// [`install_protocols()`](Self::install_protocols), as they are installed
+    /// again if any of them cannot be removed.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: An interface could not be removed.
+    pub unsafe fn uninstall_protocols(
+        &self,
+        handle: Handle,
+        protocols: &[(&Guid, *const u8)],
+    ) -> Result<()> {
+        let _tpl = self.raise_tpl(Tpl::NOTIFY);
+
+        for (i, (guid, interface)) in protocols.iter().enumerate() {
+            if self.uninstall_protocol_interface(handle, guid, *interface).is_err() {
+                for (guid, interface) in &protocols[..i] {
+                    let _ = self.install_protocol_interface(Some(handle), guid, *interface);
+                }
+                return Err(Status::INVALID_PARAMETER);
+            }
+        }
+
+        Ok(())
+    }
 
     /// Computes and returns a 32-bit CRC for a data buffer.
     ///
@@ -474,6 +1236,9 @@ impl BootServices {
 
     /// Creates an event in a group.
     ///
+    /// The `notify` closure is called when the event is notified, and is
+    /// freed after the returned handle closes the event.
+    ///
     /// # Errors
     ///
     /// - `OUT_OF_RESOURCES`: The event could not be allocated.
@@ -483,12 +1248,42 @@ impl BootServices {
     ///   `NOTIFY_WAIT` set and `notify` is `None`.
     /// - `INVALID_PARAMETER`: `event_type` has either `NOTIFY_SIGNAL` or
     ///   `NOTIFY_WAIT` set and `tpl` is not a supported level.
+    #[cfg(feature = "alloc")]
     pub fn create_event_ex(
+        &self,
+        event_type: EventType,
+        tpl: Tpl,
+        notify: Option<Box<dyn FnMut(Event)>>,
+        group: Option<&Guid>,
+    ) -> Result<EventHandle<'_>> {
+        let (notify_fn, context, notify) = EventHandle::trampoline(notify);
+        let event =
+            unsafe { self.create_event_ex_raw(event_type, tpl, notify_fn, context, group)? };
+
+        Ok(EventHandle {
+            bs: self,
+            event,
+            notify,
+        })
+    }
+
+    /// Creates an event in a group with a raw notification function and
+    /// context.
+    ///
+    /// # Safety
+    ///
+    /// `context` must be valid for `notify` to use until the event is closed.
+    ///
+    /// # Errors
+    ///
+    /// - `OUT_OF_RESOURCES`: The event could not be allocated.
+    /// - `INVALID_PARAMETER`: One of the parameters has an invalid value.
+    pub unsafe fn create_event_ex_raw(
         &self,
         event_type: EventType,
         tpl: Tpl,
         notify: Option<EventNotifyFn>,
-        context: Option<&[u8]>,
+        context: *mut c_void,
         group: Option<&Guid>,
     ) -> Result<Event> {
         let mut event = unsafe { Event::uninit() };
@@ -496,12 +1291,8 @@ impl BootServices {
             Some(g) => g,
             None => core::ptr::null(),
         };
-        let ctx = match context {
-            Some(c) => c.as_ptr(),
-            None => core::ptr::null(),
-        };
 
-        let status = (self.CreateEventEx)(event_type, tpl, notify, ctx, guid, &mut event);
+        let status = (self.CreateEventEx)(event_type, tpl, notify, context, guid, &mut event);
 
         match status {
             Status::SUCCESS => Ok(event),
@@ -509,3 +1300,496 @@ impl BootServices {
         }
     }
 }
+
+/// The notification closure of an [`EventHandle`].
+#[cfg(feature = "alloc")]
+type EventNotify = Box<dyn FnMut(Event)>;
+
+/// An event created with `CreateEvent()` or `CreateEventEx()`.
+///
+/// The event is closed with `CloseEvent()` when dropped, after which the
+/// notification closure is freed.
+pub struct EventHandle<'a> {
+    bs: &'a BootServices,
+    event: Event,
+    #[cfg(feature = "alloc")]
+    notify: Option<Box<EventNotify>>,
+}
+
+impl<'a> EventHandle<'a> {
+    /// Takes ownership of an event.
+    ///
+    /// # Safety
+    ///
+    /// `event` must be a valid event that is not closed elsewhere.
+    pub unsafe fn from_raw(bs: &'a BootServices, event: Event) -> Self {
+        Self {
+            bs,
+            event,
+            #[cfg(feature = "alloc")]
+            notify: None,
+        }
+    }
+
+    /// Returns the event without closing it.
+    ///
+    /// The notification closure, if any, is leaked so it remains valid.
+    pub fn into_raw(self) -> Event {
+        let this = core::mem::ManuallyDrop::new(self);
+        #[cfg(feature = "alloc")]
+        if let Some(notify) = unsafe { core::ptr::read(&this.notify) } {
+            Box::leak(notify);
+        }
+        this.event
+    }
+
+    /// The raw event.
+    pub fn event(&self) -> Event {
+        self.event
+    }
+
+    /// Sets the timer of a `TIMER` event.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The event is not a timer event.
+    pub fn set_timer(&self, timer: Timer) -> Result<()> {
+        self.bs.set_timer(self.event, timer.kind(), timer.time())
+    }
+
+    /// Signals the event.
+    ///
+    /// # Errors
+    ///
+    /// - None documented
+    pub fn signal(&self) -> Result<()> {
+        self.bs.signal_event(self.event)
+    }
+
+    /// Checks whether the event is in the signaled state.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The event is of type `NOTIFY_SIGNAL`.
+    pub fn is_signaled(&self) -> Result<bool> {
+        match self.bs.check_event(self.event) {
+            Ok(()) => Ok(true),
+            Err(Status::NOT_READY) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Stops execution until the event is signaled.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The event is of type `NOTIFY_SIGNAL`.
+    /// - `UNSUPPORTED`: The current TPL is not `APPLICATION`.
+    pub fn wait(&self) -> Result<()> {
+        self.bs.wait_for_event(&[self.event]).map(|_| ())
+    }
+
+    /// Splits a closure into a notification function and context pointer.
+    #[cfg(feature = "alloc")]
+    fn trampoline(
+        notify: Option<EventNotify>,
+    ) -> (Option<EventNotifyFn>, *mut c_void, Option<Box<EventNotify>>) {
+        extern "efiapi" fn call(event: Event, context: *mut c_void) {
+            let notify = unsafe { &mut *context.cast::<EventNotify>() };
+            notify(event);
+        }
+
+        match notify {
+            Some(notify) => {
+                let mut notify = Box::new(notify);
+                let context = core::ptr::from_mut::<EventNotify>(&mut *notify).cast();
+                (Some(call), context, Some(notify))
+            }
+            None => (None, core::ptr::null_mut(), None),
+        }
+    }
+}
+
+impl Drop for EventHandle<'_> {
+    fn drop(&mut self) {
+        let _ = self.bs.close_event(self.event);
+    }
+}
+
+/// A registration for protocol install notifications, created by
+/// [`BootServices::watch_protocol()`].
+///
+/// The event is signaled each time an interface of `P` is installed, and the
+/// new handles are returned by [`next_handle()`](Self::next_handle). The
+/// registration is removed when dropped.
+///
+/// ```ignore
+/// let watch = bs.watch_protocol::<GraphicsOutput>()?;
+/// loop {
+///     watch.wait()?;
+///     for handle in watch.handles() {
+///         // ...
+///     }
+/// }
+/// ```
+pub struct ProtocolWatch<'a, P: Protocol> {
+    event: EventHandle<'a>,
+    /// The registration of `event`, which is valid while the event is open.
+    registration: NonNull<u8>,
+    _protocol: PhantomData<fn() -> P>,
+}
+
+impl<P: Protocol> ProtocolWatch<'_, P> {
+    /// The event signaled when an interface is installed.
+    ///
+    /// The event is not a `NOTIFY_SIGNAL` event, so it may be waited on with
+    /// `WaitForEvent()` or
+    /// [`Executor::wait_event()`](crate::executor::Executor::wait_event).
+    pub fn event(&self) -> Event {
+        self.event.event()
+    }
+
+    /// The registration used as the search key for `BY_REGISTER_NOTIFY`.
+    pub fn registration(&self) -> ProtocolRegistration<'_> {
+        ProtocolRegistration(self.registration, PhantomData)
+    }
+
+    /// Stops execution until an interface is installed.
+    ///
+    /// # Errors
+    ///
+    /// - `UNSUPPORTED`: The current TPL is not `APPLICATION`.
+    pub fn wait(&self) -> Result<()> {
+        self.event.wait()
+    }
+
+    /// Returns the next handle an interface was installed on since the last
+    /// call, or `None` if there are no new handles.
+    pub fn next_handle(&self) -> Option<Handle> {
+        let bs = self.event.bs;
+        let mut handle = unsafe { Handle::uninit() };
+        let mut size = size_of::<Handle>();
+
+        let status = (bs.LocateHandle)(
+            LocateSearchType::BY_REGISTER_NOTIFY,
+            core::ptr::null(),
+            self.registration.as_ptr(),
+            &mut size,
+            &mut handle,
+        );
+
+        match status {
+            Status::SUCCESS => Some(handle),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the new handles, as returned by
+    /// [`next_handle()`](Self::next_handle).
+    pub fn handles(&self) -> impl Iterator<Item = Handle> + '_ {
+        core::iter::from_fn(|| self.next_handle())
+    }
+}
+
+/// The source of an image for [`BootServices::load_image()`].
+#[derive(Clone, Copy)]
+pub enum ImageSource<'a> {
+    /// Load the image from a file located by its device path.
+    DevicePath(&'a DevicePath),
+    /// Load the image from a buffer in memory.
+    Buffer {
+        buffer: &'a [u8],
+        /// The device path the image was read from, if known.
+        file_path: Option<&'a DevicePath>,
+    },
+}
+
+/// Data passed to `Exit()` by an image started with `StartImage()`.
+///
+/// The data is a null-terminated UCS-2 string optionally followed by binary
+/// data. The buffer is freed when dropped.
+pub struct ExitData<'a> {
+    bs: &'a BootServices,
+    buffer: *mut u16,
+    size: usize,
+}
+
+impl ExitData<'_> {
+    /// The raw exit data.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.buffer.cast(), self.size) }
+    }
+
+    /// The string at the start of the exit data.
+    ///
+    /// Returns `None` if the data does not start with a valid string.
+    pub fn description(&self) -> Option<&CStr16> {
+        if self.buffer.align_offset(align_of::<u16>()) != 0 || self.size < 2 {
+            return None;
+        }
+
+        let units = unsafe { core::slice::from_raw_parts(self.buffer, self.size / 2) };
+        let nul = units.iter().position(|&c| c == 0)?;
+        CStr16::from_u16_with_nul(&units[..=nul]).ok()
+    }
+}
+
+impl Drop for ExitData<'_> {
+    fn drop(&mut self) {
+        let _ = self.bs.free_pool(self.buffer.cast());
+    }
+}
+
+/// A raised task priority level returned by [`BootServices::raise_tpl()`].
+///
+/// The previous level is restored with `RestoreTpl()` when dropped.
+#[must_use = "the previous TPL is restored when the guard is dropped"]
+pub struct TplGuard<'a> {
+    bs: &'a BootServices,
+    old: Tpl,
+}
+
+impl TplGuard<'_> {
+    /// The level that will be restored.
+    pub fn old_tpl(&self) -> Tpl {
+        self.old
+    }
+}
+
+impl Drop for TplGuard<'_> {
+    fn drop(&mut self) {
+        self.bs.restore_tpl(self.old);
+    }
+}
+
+/// A protocol interface opened with `OpenProtocol()`.
+///
+/// The protocol is closed with `CloseProtocol()` when dropped.
+pub struct ScopedProtocol<'a, P: Protocol> {
+    bs: &'a BootServices,
+    interface: NonNull<P>,
+    handle: Handle,
+    agent: Handle,
+    controller: Option<Handle>,
+}
+
+impl<P: Protocol> ScopedProtocol<'_, P> {
+    /// The handle the protocol was opened on.
+    pub fn handle(&self) -> Handle {
+        self.handle
+    }
+
+    /// The handle of the agent that opened the protocol.
+    pub fn agent(&self) -> Handle {
+        self.agent
+    }
+
+    /// The handle of the controller that requires the protocol.
+    pub fn controller(&self) -> Option<Handle> {
+        self.controller
+    }
+}
+
+impl<P: Protocol> ops::Deref for ScopedProtocol<'_, P> {
+    type Target = P;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.interface.as_ref() }
+    }
+}
+
+impl<P: Protocol> ops::DerefMut for ScopedProtocol<'_, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { self.interface.as_mut() }
+    }
+}
+
+impl<P: Protocol> Drop for ScopedProtocol<'_, P> {
+    fn drop(&mut self) {
+        let _ = self.bs.close_protocol(self.handle, &P::GUID, self.agent, self.controller);
+    }
+}
+
+/// An array of handles allocated from pool by `LocateHandleBuffer()`.
+///
+/// The buffer is freed when dropped.
+pub struct HandleBuffer<'a> {
+    bs: &'a BootServices,
+    buffer: *mut Handle,
+    count: usize,
+}
+
+impl ops::Deref for HandleBuffer<'_> {
+    type Target = [Handle];
+
+    fn deref(&self) -> &Self::Target {
+        if self.count == 0 {
+            return &[];
+        }
+
+        unsafe { core::slice::from_raw_parts(self.buffer, self.count) }
+    }
+}
+
+impl Drop for HandleBuffer<'_> {
+    fn drop(&mut self) {
+        if !self.buffer.is_null() {
+            let _ = self.bs.free_pool(self.buffer.cast());
+        }
+    }
+}
+
+/// An array of protocol GUIDs allocated from pool by `ProtocolsPerHandle()`.
+///
+/// The buffer is freed when dropped.
+pub struct ProtocolsBuffer<'a> {
+    bs: &'a BootServices,
+    buffer: *mut &'a Guid,
+    count: usize,
+}
+
+impl<'a> ops::Deref for ProtocolsBuffer<'a> {
+    type Target = [&'a Guid];
+
+    fn deref(&self) -> &Self::Target {
+        if self.count == 0 {
+            return &[];
+        }
+
+        unsafe { core::slice::from_raw_parts(self.buffer, self.count) }
+    }
+}
+
+impl Drop for ProtocolsBuffer<'_> {
+    fn drop(&mut self) {
+        if !self.buffer.is_null() {
+            let _ = self.bs.free_pool(self.buffer.cast());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+impl BootServices {
+    /// Boot services for tests of code that takes them but does not use them.
+    ///
+    /// None of the services may be called.
+    pub(crate) fn unusable() -> Self {
+        extern "efiapi" fn unusable() {
+            unreachable!("unusable boot service called");
+        }
+
+        /// Returns `unusable()` as a function pointer of another type.
+        fn stub<F>() -> F {
+            let f: extern "efiapi" fn() = unusable;
+            assert_eq!(size_of::<F>(), size_of_val(&f));
+            unsafe { core::mem::transmute_copy(&f) }
+        }
+
+        Self {
+            Hdr: Header {
+                Signature: 0,
+                Revision: 0,
+                Size: 0,
+                Crc32: 0,
+                _Reserved: 0,
+            },
+            RaiseTpl: stub(),
+            RestoreTpl: stub(),
+            AllocatePages: stub(),
+            FreePages: stub(),
+            GetMemoryMap: stub(),
+            AllocatePool: stub(),
+            FreePool: stub(),
+            CreateEvent: stub(),
+            SetTimer: stub(),
+            WaitForEvent: stub(),
+            SignalEvent: stub(),
+            CloseEvent: stub(),
+            CheckEvent: stub(),
+            InstallProtocolInterface: stub(),
+            ReinstallProtocolInterface: stub(),
+            UninstallProtocolInterface: stub(),
+            HandleProtocol: stub(),
+            _Reserved: 0,
+            RegisterProtocolNotify: stub(),
+            LocateHandle: stub(),
+            LocateDevicePath: stub(),
+            InstallConfigurationTable: stub(),
+            LoadImage: stub(),
+            StartImage: stub(),
+            Exit: stub(),
+            UnloadImage: stub(),
+            ExitBootServices: stub(),
+            GetNextMonotonicCount: stub(),
+            Stall: stub(),
+            SetWatchdogTimer: stub(),
+            ConnectController: stub(),
+            DisconnectController: stub(),
+            OpenProtocol: stub(),
+            CloseProtocol: stub(),
+            OpenProtocolInformation: stub(),
+            ProtocolsPerHandle: stub(),
+            LocateHandleBuffer: stub(),
+            LocateProtocol: stub(),
+            InstallMultipleProtocolInterfaces: stub(),
+            UninstallMultipleProtocolInterfaces: stub(),
+            CalculateCrc32: stub(),
+            CopyMem: stub(),
+            SetMem: stub(),
+            CreateEventEx: stub(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn timer_units() {
+        assert_eq!(Timer::oneshot(Duration::from_millis(1)).time(), 10_000);
+        assert_eq!(Timer::periodic(Duration::from_nanos(250)).time(), 2);
+        assert_eq!(Timer::oneshot(Duration::MAX).time(), u64::MAX);
+        assert_eq!(Timer::CANCEL.time(), 0);
+    }
+
+    /// Exit data that is not freed, as the boot services are unusable.
+    #[cfg(feature = "alloc")]
+    fn exit_data<'a>(bs: &'a BootServices, data: &[u8]) -> core::mem::ManuallyDrop<ExitData<'a>> {
+        core::mem::ManuallyDrop::new(ExitData {
+            bs,
+            buffer: data.as_ptr().cast::<c_void>().cast_mut().cast(),
+            size: data.len(),
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn bytes(units: &[u16]) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(units.as_ptr().cast(), size_of_val(units)) }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn exit_data_description() {
+        use alloc::borrow::ToOwned;
+
+        let bs = BootServices::unusable();
+        let description = |data| exit_data(&bs, data).description().map(CStr16::to_owned);
+        let ok = Some(cstr16!("ab").to_owned());
+
+        assert_eq!(description(bytes(&[0x0061, 0x0062, 0x0000])), ok);
+        // Binary data after the string.
+        assert_eq!(description(bytes(&[0x0061, 0x0062, 0x0000, 0xD800, 0xFFFF])), ok);
+        // A trailing odd byte.
+        assert_eq!(description(&bytes(&[0x0061, 0x0062, 0x0000, 0x0063])[..7]), ok);
+        // Missing the null terminator.
+        assert_eq!(description(bytes(&[0x0061, 0x0062])), None);
+        assert_eq!(description(&bytes(&[0x0061, 0x0062, 0x0000])[..5]), None);
+        assert_eq!(description(&[]), None);
+        // A lone surrogate.
+        assert_eq!(description(bytes(&[0x0061, 0xD800, 0x0000])), None);
+        assert_eq!(description(bytes(&[0xDC00, 0x0000])), None);
+        // An unaligned buffer.
+        assert_eq!(description(&bytes(&[0x6100, 0x6200, 0x0000, 0x0000])[1..7]), None);
+    }
+}
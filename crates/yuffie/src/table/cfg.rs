@@ -2,11 +2,52 @@
 // SPDX-FileCopyrightText: 2024 System76, Inc.
 
 //! Configuration Table and Properties Table
+//!
+//! ## References
+//!
+//! - UEFI Specification, Version 2.10
+//!   - 4.6: EFI Configuration Table & Properties Table
 
+use crate::guid;
 use crate::guid::Guid;
 
+/// ACPI 1.0 RSDP
+pub const ACPI_10_TABLE: Guid = guid!("eb9d2d30-2d88-11d3-9a16-0090273fc14d");
+/// ACPI 2.0 or later RSDP
+pub const ACPI_20_TABLE: Guid = guid!("8868e871-e4f1-11d3-bc22-0080c73c8881");
+/// SMBIOS 2.x entry point structure
+pub const SMBIOS_TABLE: Guid = guid!("eb9d2d31-2d88-11d3-9a16-0090273fc14d");
+/// SMBIOS 3.x entry point structure
+pub const SMBIOS3_TABLE: Guid = guid!("f2fd1544-9794-4a2c-992e-e5bbcf20e394");
+/// Flattened device tree blob
+pub const DTB_TABLE: Guid = guid!("b1b621d5-f19c-41a5-830b-d9152c69aae0");
+/// EFI System Resource Table
+pub const ESRT_TABLE: Guid = guid!("b122a263-3661-4f68-9929-78f8b0d62180");
+/// EFI Memory Attributes Table
+pub const MEMORY_ATTRIBUTES_TABLE: Guid = guid!("dcfa911d-26eb-469f-a220-38b7dc461220");
+/// EFI Debug Image Info Table
+pub const DEBUG_IMAGE_INFO_TABLE: Guid = guid!("49152e77-1ada-4764-b7a2-7afefed95e8b");
+/// PI Hand-Off Block list
+pub const HOB_LIST: Guid = guid!("7739f24c-93d7-11d4-9a3a-0090273fc14d");
+/// LZMA-compressed HOB list or firmware volume
+pub const LZMA_COMPRESS: Guid = guid!("ee4e5898-3914-4259-9d6e-dc7bd79403cf");
+
+/// `EFI_CONFIGURATION_TABLE`
+#[derive(Debug)]
 #[repr(C)]
 pub struct ConfigurationTable {
     pub VendorGuid: Guid,
     pub VendorTable: usize,
 }
+
+impl ConfigurationTable {
+    /// The GUID that identifies the table.
+    pub fn guid(&self) -> &Guid {
+        &self.VendorGuid
+    }
+
+    /// A pointer to the table.
+    pub fn table(&self) -> *const u8 {
+        core::ptr::with_exposed_provenance(self.VendorTable)
+    }
+}
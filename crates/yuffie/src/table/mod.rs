@@ -10,6 +10,8 @@ pub mod system;
 
 pub use boot::BootServices;
 pub use runtime::RuntimeServices;
+#[cfg(feature = "alloc")]
+pub use system::RuntimeSystemTable;
 pub use system::SystemTable;
 
 /// Data struct that precedes all stable UEFI tables.
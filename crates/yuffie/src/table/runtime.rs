@@ -13,10 +13,19 @@
 //!
 //! [UEFI Spec]: https://uefi.org/sites/default/files/resources/UEFI_Spec_2_10_Aug29.pdf
 
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ops;
+
 use super::Header;
 use crate::mem::MemoryDescriptor;
 use crate::mem::PhysicalAddress;
 use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use crate::string::CString16;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(transparent)]
@@ -26,9 +35,34 @@ impl CapsuleFlags {
     pub const PERSIST_ACROSS_RESET: Self = Self(1 << 16);
     pub const POPULATE_SYSTEM_TABLE: Self = Self(1 << 17);
     pub const INITIATE_RESET: Self = Self(1 << 18);
+
+    /// Returns `true` if all of the flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl From<u32> for CapsuleFlags {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CapsuleFlags> for u32 {
+    fn from(value: CapsuleFlags) -> Self {
+        value.0
+    }
 }
 
-#[derive(Debug)]
+impl ops::BitOr for CapsuleFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub struct CapsuleHeader {
     pub CapsuleGuid: Guid,
@@ -37,6 +71,74 @@ pub struct CapsuleHeader {
     pub CapsuleImageSize: u32,
 }
 
+/// `EFI_CAPSULE_BLOCK_DESCRIPTOR`
+///
+/// An entry in the scatter-gather list passed to `UpdateCapsule()`. If
+/// `Length` is 0, `Address` is a continuation pointer to the next list, or
+/// 0 to terminate the list. Otherwise it is the address of a data block.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CapsuleBlockDescriptor {
+    pub Length: u64,
+    pub Address: PhysicalAddress,
+}
+
+/// A capsule header followed by its image, checked to fit in its buffer.
+///
+/// A slice of capsules has the layout of the `CapsuleHeaderArray` passed to
+/// `UpdateCapsule()` and `QueryCapsuleCapabilities()`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct Capsule<'a>(&'a CapsuleHeader);
+
+impl<'a> Capsule<'a> {
+    /// Checks that `data` starts with a capsule header that describes an
+    /// image contained in `data`.
+    ///
+    /// # Errors
+    ///
+    /// - `BAD_BUFFER_SIZE`: `data` is smaller than the capsule image.
+    /// - `INVALID_PARAMETER`: `data` is not aligned for the header.
+    /// - `INVALID_PARAMETER`: The header size is larger than the image size, or
+    ///   smaller than the header.
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        if data.len() < size_of::<CapsuleHeader>() {
+            return Err(Status::BAD_BUFFER_SIZE);
+        }
+        if data.as_ptr().align_offset(align_of::<CapsuleHeader>()) != 0 {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        let header = unsafe { &*data.as_ptr().cast::<c_void>().cast::<CapsuleHeader>() };
+        let header_size = header.HeaderSize as usize;
+        let image_size = header.CapsuleImageSize as usize;
+        if header_size < size_of::<CapsuleHeader>() || header_size > image_size {
+            return Err(Status::INVALID_PARAMETER);
+        }
+        if image_size > data.len() {
+            return Err(Status::BAD_BUFFER_SIZE);
+        }
+
+        Ok(Self(header))
+    }
+
+    /// The capsule header.
+    pub fn header(&self) -> &'a CapsuleHeader {
+        self.0
+    }
+
+    /// The capsule image after the header.
+    pub fn body(&self) -> &'a [u8] {
+        &self.as_bytes()[self.0.HeaderSize as usize..]
+    }
+
+    /// The entire capsule, including the header.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        let ptr = core::ptr::from_ref(self.0).cast::<u8>();
+        unsafe { core::slice::from_raw_parts(ptr, self.0.CapsuleImageSize as usize) }
+    }
+}
+
 /// System reset type
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(transparent)]
@@ -79,6 +181,7 @@ pub struct TimeCapabilities {
     pub SetsToZero: bool,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct VariableAttributes(u32);
 
@@ -91,6 +194,25 @@ impl VariableAttributes {
     pub const TIME_BASED_AUTHENTICATED_WRITE_ACCESS: Self = Self(1 << 5);
     pub const APPEND_WRITE: Self = Self(1 << 6);
     pub const ENHANCED_AUTHENTICATED_ACCESS: Self = Self(1 << 7);
+
+    /// Returns `true` if all of the attributes in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the combination of the attributes in `self` and `other`.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl ops::BitOr for VariableAttributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
 }
 
 /// The UEFI Runtime Services table
@@ -119,8 +241,8 @@ pub struct RuntimeServices {
     pub ResetSystem: extern "efiapi" fn(ResetType, Status, usize, *const u8),
 
     // UEFI 2.0 Capsule Services
-    pub UpdateCapsule: extern "efiapi" fn(*const *const CapsuleHeader, usize, *const PhysicalAddress) -> Status,
-    pub QueryCapsuleCapabilities: extern "efiapi" fn() -> Status,
+    pub UpdateCapsule: extern "efiapi" fn(*const Capsule, usize, PhysicalAddress) -> Status,
+    pub QueryCapsuleCapabilities: extern "efiapi" fn(*const Capsule, usize, *mut u64, *mut ResetType) -> Status,
 
     // Misc UEFI 2.0 Service
     pub QueryVariableInfo: extern "efiapi" fn(VariableAttributes, *mut u64, *mut u64, *mut u64) -> Status,
@@ -265,8 +387,83 @@ impl RuntimeServices {
         }
     }
 
-    // TODO: GetVarialbe
-    // TODO: GetNextVariableName
+    /// Returns the value and attributes of a variable.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `DEVICE_ERROR`: The variable could not be retrieved due to a hardware
+    ///   error.
+    /// - `SECURITY_VIOLATION`: The variable could not be retrieved due to an
+    ///   authentication failure.
+    /// - `UNSUPPORTED`: Not supported by the platform at the time the call is
+    ///   made.
+    #[cfg(feature = "alloc")]
+    pub fn get_variable(
+        &self,
+        name: &CStr16,
+        guid: &Guid,
+    ) -> Result<(Vec<u8>, VariableAttributes)> {
+        let mut attrs = VariableAttributes::default();
+        let mut data = Vec::new();
+
+        loop {
+            let mut size = data.len();
+            let status =
+                (self.GetVariable)(name.as_ptr(), guid, &mut attrs, &mut size, data.as_mut_ptr());
+
+            match status {
+                Status::SUCCESS => {
+                    data.truncate(size);
+                    return Ok((data, attrs));
+                }
+                Status::BUFFER_TOO_SMALL => data.resize(size, 0),
+                e => return Err(e),
+            }
+        }
+    }
+
+    /// Reads the value of a variable into a buffer, returning the size of the
+    /// data and the attributes of the variable.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `BUFFER_TOO_SMALL`: `buf` is too small for the result.
+    /// - `DEVICE_ERROR`: The variable could not be retrieved due to a hardware
+    ///   error.
+    /// - `SECURITY_VIOLATION`: The variable could not be retrieved due to an
+    ///   authentication failure.
+    /// - `UNSUPPORTED`: Not supported by the platform at the time the call is
+    ///   made.
+    pub fn get_variable_into(
+        &self,
+        name: &CStr16,
+        guid: &Guid,
+        buf: &mut [u8],
+    ) -> Result<(usize, VariableAttributes)> {
+        let mut attrs = VariableAttributes::default();
+        let mut size = buf.len();
+        let status =
+            (self.GetVariable)(name.as_ptr(), guid, &mut attrs, &mut size, buf.as_mut_ptr());
+
+        match status {
+            Status::SUCCESS => Ok((size, attrs)),
+            e => Err(e),
+        }
+    }
+
+    /// Returns an iterator over the names and vendor GUIDs of all variables.
+    #[cfg(feature = "alloc")]
+    pub fn variable_names(&self) -> VariableNames<'_> {
+        VariableNames {
+            rs: self,
+            // Start with an empty string to get the first variable.
+            name: alloc::vec![0; 128],
+            guid: Guid::NULL,
+            done: false,
+        }
+    }
 
     /// Sets the value of a variable.
     ///
@@ -290,12 +487,25 @@ impl RuntimeServices {
     ///   made.
     pub fn set_variable(
         &self,
-        name: &u16,
+        name: &CStr16,
         guid: &Guid,
         attrs: VariableAttributes,
         data: &[u8],
     ) -> Result<()> {
-        (self.SetVariable)(name, guid, attrs, data.len(), data.as_ptr()).into()
+        (self.SetVariable)(name.as_ptr(), guid, attrs, data.len(), data.as_ptr()).into()
+    }
+
+    /// Deletes a variable.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `WRITE_PROTECTED`: The variable cannot be deleted.
+    /// - `DEVICE_ERROR`: Hardware error.
+    /// - `UNSUPPORTED`: Not supported by the platform at the time the call is
+    ///   made.
+    pub fn delete_variable(&self, name: &CStr16, guid: &Guid) -> Result<()> {
+        self.set_variable(name, guid, VariableAttributes::default(), &[])
     }
 
     /// Returns the next high 32 bits of the paltform's monotonic counter.
@@ -327,8 +537,109 @@ impl RuntimeServices {
         (self.ResetSystem)(kind, status, size, data);
     }
 
-    // TODO: UpdateCapsule
-    // TODO: QueryCapsuleCapabilities
+    /// Passes capsules to the firmware to be processed immediately.
+    ///
+    /// Capsules with `PERSIST_ACROSS_RESET` are processed after a reset, and
+    /// must be passed to [`RuntimeServices::update_capsule_persistent()`]
+    /// instead. If a capsule has `INITIATE_RESET`, this call does not return
+    /// on success.
+    ///
+    /// This must be called before `ExitBootServices()`, as the scatter-gather
+    /// list holds the physical addresses of the capsules.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: `capsules` is empty, a capsule has
+    ///   `PERSIST_ACROSS_RESET`, or the flags of a capsule are invalid.
+    /// - `DEVICE_ERROR`: The capsule update was started, but failed due to a
+    ///   device error.
+    /// - `UNSUPPORTED`: The capsule type is not supported on this platform.
+    /// - `OUT_OF_RESOURCES`: There were insufficient resources to process the
+    ///   capsule.
+    #[cfg(feature = "alloc")]
+    pub fn update_capsule(&self, capsules: &[Capsule<'_>]) -> Result<()> {
+        if capsules.iter().any(|c| c.header().Flags.contains(CapsuleFlags::PERSIST_ACROSS_RESET)) {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        self.update_capsule_with(capsules)?;
+        Ok(())
+    }
+
+    /// Passes capsules to the firmware, to be processed after a reset if they
+    /// have `PERSIST_ACROSS_RESET`.
+    ///
+    /// The firmware reads the capsules after the reset, so they must live
+    /// until then, and the scatter-gather list built for them is leaked.
+    ///
+    /// # Errors
+    ///
+    /// - The errors of [`RuntimeServices::update_capsule()`], except for
+    ///   `PERSIST_ACROSS_RESET` being set.
+    #[cfg(feature = "alloc")]
+    pub fn update_capsule_persistent(&self, capsules: &[Capsule<'static>]) -> Result<()> {
+        let blocks = self.update_capsule_with(capsules)?;
+        // The firmware reads the list after the reset.
+        blocks.leak();
+        Ok(())
+    }
+
+    /// Calls `UpdateCapsule()`, returning the scatter-gather list on success.
+    #[cfg(feature = "alloc")]
+    fn update_capsule_with(&self, capsules: &[Capsule<'_>]) -> Result<Vec<CapsuleBlockDescriptor>> {
+        if capsules.is_empty() {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        let mut blocks: Vec<CapsuleBlockDescriptor> = capsules
+            .iter()
+            .map(|c| CapsuleBlockDescriptor {
+                Length: u64::from(c.header().CapsuleImageSize),
+                Address: c.as_bytes().as_ptr().into(),
+            })
+            .collect();
+        blocks.push(CapsuleBlockDescriptor {
+            Length: 0,
+            Address: PhysicalAddress::from(0),
+        });
+
+        let status =
+            (self.UpdateCapsule)(capsules.as_ptr(), capsules.len(), blocks.as_ptr().into());
+        match status {
+            Status::SUCCESS => Ok(blocks),
+            e => Err(e),
+        }
+    }
+
+    /// Returns the maximum size of a capsule that can be passed to
+    /// `UpdateCapsule()` with the same flags as `capsules`, and the type of
+    /// reset required to process them.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: `capsules` is empty.
+    /// - `UNSUPPORTED`: The capsule type is not supported on this platform.
+    /// - `OUT_OF_RESOURCES`: The capsules would be processed after a reset, but
+    ///   there are insufficient resources to process them.
+    pub fn query_capsule_capabilities(&self, capsules: &[Capsule<'_>]) -> Result<(u64, ResetType)> {
+        if capsules.is_empty() {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        let mut max_size = 0;
+        let mut reset_type = ResetType::COLD;
+        let status = (self.QueryCapsuleCapabilities)(
+            capsules.as_ptr(),
+            capsules.len(),
+            &mut max_size,
+            &mut reset_type,
+        );
+
+        match status {
+            Status::SUCCESS => Ok((max_size, reset_type)),
+            e => Err(e),
+        }
+    }
 
     /// Returns information about the variables.
     ///
@@ -355,3 +666,93 @@ impl RuntimeServices {
         }
     }
 }
+
+/// An iterator over the names and vendor GUIDs of all variables.
+///
+/// Created by [`RuntimeServices::variable_names()`]. Variables must not be
+/// added or removed while iterating.
+#[cfg(feature = "alloc")]
+pub struct VariableNames<'a> {
+    rs: &'a RuntimeServices,
+    /// Buffer containing the previously returned name.
+    name: Vec<u16>,
+    guid: Guid,
+    done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for VariableNames<'_> {
+    type Item = Result<(CString16, Guid)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            let mut size = self.name.len() * size_of::<u16>();
+            let status =
+                (self.rs.GetNextVariableName)(&mut size, self.name.as_mut_ptr(), &mut self.guid);
+
+            match status {
+                Status::SUCCESS => {
+                    let Some(nul) = self.name.iter().position(|&c| c == 0) else {
+                        self.done = true;
+                        return Some(Err(Status::DEVICE_ERROR));
+                    };
+
+                    let name = unsafe { CStr16::from_u16_with_nul_unchecked(&self.name[..=nul]) };
+                    return Some(Ok((name.to_owned(), self.guid)));
+                }
+                // Resizing keeps the previous name at the start of the buffer.
+                Status::BUFFER_TOO_SMALL => self.name.resize(size.div_ceil(size_of::<u16>()), 0),
+                Status::NOT_FOUND => {
+                    self.done = true;
+                    return None;
+                }
+                e => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A 40 byte buffer starting with a capsule header.
+    fn capsule(header_size: u32, image_size: u32) -> [u64; 5] {
+        let mut buf = [0u64; 5];
+        let header = CapsuleHeader {
+            CapsuleGuid: Guid::NULL,
+            HeaderSize: header_size,
+            Flags: CapsuleFlags::PERSIST_ACROSS_RESET,
+            CapsuleImageSize: image_size,
+        };
+        unsafe { buf.as_mut_ptr().cast::<CapsuleHeader>().write(header) };
+        buf
+    }
+
+    fn bytes(buf: &[u64], len: usize) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(buf.as_ptr().cast(), len) }
+    }
+
+    #[test]
+    fn capsule_bounds() {
+        let buf = capsule(28, 36);
+        let c = Capsule::new(bytes(&buf, 40)).unwrap();
+        assert_eq!(c.as_bytes().len(), 36);
+        assert_eq!(c.body().len(), 8);
+
+        assert_eq!(Capsule::new(bytes(&buf, 32)).unwrap_err(), Status::BAD_BUFFER_SIZE);
+        assert_eq!(Capsule::new(bytes(&buf, 20)).unwrap_err(), Status::BAD_BUFFER_SIZE);
+
+        let buf = capsule(16, 36);
+        assert_eq!(Capsule::new(bytes(&buf, 40)).unwrap_err(), Status::INVALID_PARAMETER);
+        let buf = capsule(40, 36);
+        assert_eq!(Capsule::new(bytes(&buf, 40)).unwrap_err(), Status::INVALID_PARAMETER);
+    }
+}
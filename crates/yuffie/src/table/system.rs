@@ -4,13 +4,16 @@
 //! System Table
 
 use super::Header;
+#[cfg(feature = "alloc")]
+use crate::global_alloc;
+#[cfg(feature = "alloc")]
+use crate::mem::MemoryMap;
 use crate::prelude::*;
 use crate::proto::console::text::SimpleTextInput;
 use crate::proto::console::text::SimpleTextOutput;
 use crate::table::BootServices;
 use crate::table::RuntimeServices;
-
-// TODO: Handle `ExitBootServices` making most fields invalid.
+use crate::table::cfg::ConfigurationTable;
 
 /// The UEFI System table
 #[repr(C)]
@@ -43,7 +46,7 @@ pub struct SystemTable {
     /// The number of system configuration tables in `ConfigurationTable`.
     pub NumberOfTableEntries: usize,
     /// A pointer to the system configuration tables.
-    pub ConfigurationTable: *mut usize,
+    pub ConfigurationTable: *mut ConfigurationTable,
 }
 
 impl SystemTable {
@@ -55,6 +58,15 @@ impl SystemTable {
         self.FirmwareRevision
     }
 
+    /// Returns the vendor of the system firmware.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The string is not valid UCS-2.
+    pub fn firmware_vendor(&self) -> Result<&CStr16> {
+        unsafe { CStr16::from_ptr(self.FirmwareVendor) }
+    }
+
     pub fn boot_services(&mut self) -> &mut BootServices {
         unsafe { &mut *self.BootServices }
     }
@@ -74,4 +86,118 @@ impl SystemTable {
     pub fn stderr(&mut self) -> &mut SimpleTextOutput {
         unsafe { &mut *self.StdErr }
     }
+
+    /// Returns the system configuration tables.
+    pub fn config_tables(&self) -> &[ConfigurationTable] {
+        if self.ConfigurationTable.is_null() || self.NumberOfTableEntries == 0 {
+            return &[];
+        }
+
+        unsafe { core::slice::from_raw_parts(self.ConfigurationTable, self.NumberOfTableEntries) }
+    }
+
+    /// Returns a pointer to the configuration table identified by `guid`.
+    ///
+    /// See [`cfg`](crate::table::cfg) for the GUIDs of well-known tables.
+    pub fn find_config_table(&self, guid: &Guid) -> Option<*const u8> {
+        self.config_tables().iter().find(|t| t.guid() == guid).map(ConfigurationTable::table)
+    }
+
+    /// Terminates all boot services.
+    ///
+    /// The final memory map is retrieved and passed to `ExitBootServices()`,
+    /// retrying with an updated map if it changed in between. On success, the
+    /// global allocator is disabled and a table with access to only the
+    /// runtime services is returned.
+    ///
+    /// The table is consumed, so it cannot be used to access boot services
+    /// after they are terminated. The table passed to the entry point lives
+    /// for the lifetime of the image, and
+    /// [`system_table()`](crate::system_table) is no longer available
+    /// afterwards.
+    ///
+    /// # Errors
+    ///
+    /// - The error from `GetMemoryMap()`.
+    /// - `INVALID_PARAMETER`: The memory map kept changing. Only
+    ///   `GetMemoryMap()` and `ExitBootServices()` may be called.
+    #[cfg(feature = "alloc")]
+    pub fn exit_boot_services(&'static mut self, image: Handle) -> Result<RuntimeSystemTable> {
+        /// The number of times to retry if the memory map changes.
+        const RETRIES: usize = 4;
+
+        let bs = self.boot_services();
+        let mut map = bs.memory_map()?;
+
+        let mut status = unsafe { bs.exit_boot_services(image, map.key()) };
+        for _ in 0..RETRIES {
+            if status != Err(Status::INVALID_PARAMETER) {
+                break;
+            }
+
+            // The map changed; fetch it again into the existing buffer, as
+            // no allocations are allowed at this point.
+            bs.get_memory_map(&mut map)?;
+            status = unsafe { bs.exit_boot_services(image, map.key()) };
+        }
+        status?;
+
+        global_alloc::exit_boot_services();
+        unsafe {
+            crate::SYSTEM_TABLE = None;
+        }
+
+        Ok(RuntimeSystemTable {
+            table: self,
+            memory_map: map,
+        })
+    }
+}
+
+/// The UEFI System table after `ExitBootServices()`.
+///
+/// Firmware clears the console and Boot Services fields of the system table
+/// when boot services are terminated, so only the fields that remain valid
+/// are accessible.
+#[cfg(feature = "alloc")]
+pub struct RuntimeSystemTable {
+    table: &'static mut SystemTable,
+    memory_map: MemoryMap,
+}
+
+#[cfg(feature = "alloc")]
+impl RuntimeSystemTable {
+    pub fn header(&self) -> &Header {
+        &self.table.Hdr
+    }
+
+    pub fn revision(&self) -> u32 {
+        self.table.FirmwareRevision
+    }
+
+    pub fn runtime_services(&mut self) -> &mut RuntimeServices {
+        self.table.runtime_services()
+    }
+
+    /// Returns the system configuration tables.
+    ///
+    /// Tables installed in boot services memory are no longer valid.
+    pub fn config_tables(&self) -> &[ConfigurationTable] {
+        self.table.config_tables()
+    }
+
+    /// Returns a pointer to the configuration table identified by `guid`.
+    pub fn find_config_table(&self, guid: &Guid) -> Option<*const u8> {
+        self.table.find_config_table(guid)
+    }
+
+    /// The memory map at the time boot services were terminated.
+    pub fn memory_map(&self) -> &MemoryMap {
+        &self.memory_map
+    }
+
+    /// The memory map at the time boot services were terminated.
+    pub fn memory_map_mut(&mut self) -> &mut MemoryMap {
+        &mut self.memory_map
+    }
 }
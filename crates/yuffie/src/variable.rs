@@ -0,0 +1,430 @@
+// SPDX-License-Identifier: BSD-2-Clause-Patent
+// SPDX-FileCopyrightText: 2026 System76, Inc.
+
+//! # Global Variables
+//!
+//! Typed accessors for the architecturally defined variables in the
+//! `EFI_GLOBAL_VARIABLE` namespace.
+//!
+//! ## References
+//!
+//! - [UEFI Specification, Version 2.10][UEFI Spec]
+//!   - 3.1.3: Load Options
+//!   - 3.3: Globally Defined Variables
+//!
+//! [UEFI Spec]: https://uefi.org/sites/default/files/resources/UEFI_Spec_2_10_Aug29.pdf
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops;
+
+use crate::prelude::*;
+use crate::proto::device_path::DevicePath;
+use crate::proto::device_path::DevicePathBuf;
+use crate::string::CString16;
+use crate::table::RuntimeServices;
+use crate::table::runtime::VariableAttributes;
+
+/// Vendor GUID of the architecturally defined variables.
+pub const GLOBAL_VARIABLE: Guid = guid!("8be4df61-93ca-11d2-aa0d-00e098032b8c");
+
+/// Attributes used when writing global variables.
+const ATTRIBUTES: VariableAttributes = VariableAttributes::NON_VOLATILE
+    .union(VariableAttributes::BOOTSERVICE_ACCESS)
+    .union(VariableAttributes::RUNTIME_ACCESS);
+
+/// Attributes of a load option.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct LoadOptionAttributes(u32);
+
+impl LoadOptionAttributes {
+    /// The boot manager will attempt to boot the option.
+    pub const ACTIVE: Self = Self(0x0000_0001);
+    /// All drivers are reconnected after a driver option is loaded.
+    pub const FORCE_RECONNECT: Self = Self(0x0000_0002);
+    /// The option is not shown in the boot manager menu.
+    pub const HIDDEN: Self = Self(0x0000_0008);
+    /// Mask of the option category.
+    pub const CATEGORY: Self = Self(0x0000_1F00);
+    /// The option is part of the normal boot processing.
+    pub const CATEGORY_BOOT: Self = Self(0x0000_0000);
+    /// The option is an application that is only run on demand.
+    pub const CATEGORY_APP: Self = Self(0x0000_0100);
+
+    /// Returns `true` if all of the attributes in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the category of the option.
+    #[must_use]
+    pub const fn category(self) -> Self {
+        Self(self.0 & Self::CATEGORY.0)
+    }
+}
+
+impl From<u32> for LoadOptionAttributes {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<LoadOptionAttributes> for u32 {
+    fn from(value: LoadOptionAttributes) -> Self {
+        value.0
+    }
+}
+
+impl ops::BitOr for LoadOptionAttributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Bits of the `OsIndications` and `OsIndicationsSupported` variables.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct OsIndications(u64);
+
+impl OsIndications {
+    pub const BOOT_TO_FW_UI: Self = Self(0x0001);
+    pub const TIMESTAMP_REVOCATION: Self = Self(0x0002);
+    pub const FILE_CAPSULE_DELIVERY_SUPPORTED: Self = Self(0x0004);
+    pub const FMP_CAPSULE_SUPPORTED: Self = Self(0x0008);
+    pub const CAPSULE_RESULT_VAR_SUPPORTED: Self = Self(0x0010);
+    pub const START_OS_RECOVERY: Self = Self(0x0020);
+    pub const START_PLATFORM_RECOVERY: Self = Self(0x0040);
+    pub const JSON_CONFIG_DATA_REFRESH: Self = Self(0x0080);
+
+    /// Returns `true` if all of the bits in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl From<u64> for OsIndications {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OsIndications> for u64 {
+    fn from(value: OsIndications) -> Self {
+        value.0
+    }
+}
+
+/// The kind of a load option, which determines its variable name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoadOptionType {
+    /// `Boot####`
+    Boot,
+    /// `Driver####`
+    Driver,
+    /// `SysPrep####`
+    SysPrep,
+}
+
+impl LoadOptionType {
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Boot => "Boot",
+            Self::Driver => "Driver",
+            Self::SysPrep => "SysPrep",
+        }
+    }
+}
+
+/// A load option (`EFI_LOAD_OPTION`), as stored in `Boot####` and
+/// `Driver####` variables.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoadOption {
+    pub attributes: LoadOptionAttributes,
+    /// Description of the option shown to the user.
+    pub description: CString16,
+    /// The device paths of the option. The first path is the location of the
+    /// image to load; the meaning of any others is specific to the image.
+    pub file_paths: Vec<DevicePathBuf>,
+    /// Data passed to the loaded image as its load options.
+    pub optional_data: Vec<u8>,
+}
+
+impl LoadOption {
+    /// Returns the device path of the image to load.
+    pub fn file_path(&self) -> Option<&DevicePath> {
+        self.file_paths.first().map(|p| &**p)
+    }
+
+    /// Parses a serialized load option.
+    ///
+    /// # Errors
+    ///
+    /// - `INVALID_PARAMETER`: The data is not a valid load option.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let (attributes, rest) = data.split_first_chunk::<4>().ok_or(Status::INVALID_PARAMETER)?;
+        let (path_len, rest) = rest.split_first_chunk::<2>().ok_or(Status::INVALID_PARAMETER)?;
+        let path_len = usize::from(u16::from_le_bytes(*path_len));
+
+        // The description is an unaligned, NUL-terminated UCS-2 string.
+        let mut description = Vec::new();
+        for c in rest.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])) {
+            description.push(c);
+            if c == 0 {
+                break;
+            }
+        }
+        let description = CString16::from_vec_with_nul(description)?;
+
+        let rest = &rest[description.size()..];
+        if rest.len() < path_len {
+            return Err(Status::INVALID_PARAMETER);
+        }
+        let (mut paths, optional_data) = rest.split_at(path_len);
+
+        let mut file_paths = Vec::new();
+        while !paths.is_empty() {
+            let path = DevicePath::from_bytes(paths).ok_or(Status::INVALID_PARAMETER)?;
+            paths = &paths[path.size()..];
+            file_paths.push(path.to_owned());
+        }
+
+        Ok(Self {
+            attributes: LoadOptionAttributes(u32::from_le_bytes(*attributes)),
+            description,
+            file_paths,
+            optional_data: optional_data.to_vec(),
+        })
+    }
+
+    /// Serializes the load option.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the device paths are larger than 64 KiB.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let path_len: usize = self.file_paths.iter().map(|p| p.size()).sum();
+        let path_len = u16::try_from(path_len).expect("load option device paths too large");
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.attributes.0.to_le_bytes());
+        data.extend_from_slice(&path_len.to_le_bytes());
+        for c in self.description.as_slice_with_nul() {
+            data.extend_from_slice(&c.to_le_bytes());
+        }
+        for path in &self.file_paths {
+            data.extend_from_slice(path.as_bytes());
+        }
+        data.extend_from_slice(&self.optional_data);
+        data
+    }
+}
+
+/// Returns the name of a load option variable, e.g. `Boot0001`.
+pub fn load_option_name(kind: LoadOptionType, number: u16) -> CString16 {
+    let name = format!("{}{:04X}", kind.prefix(), number);
+    CString16::try_from(name.as_str()).expect("load option names are ASCII")
+}
+
+impl RuntimeServices {
+    /// Reads a global variable that must be exactly `N` bytes.
+    fn get_global<const N: usize>(&self, name: &CStr16) -> Result<[u8; N]> {
+        let mut buf = [0; N];
+        match self.get_variable_into(name, &GLOBAL_VARIABLE, &mut buf) {
+            Ok((size, _)) if size == N => Ok(buf),
+            Ok(_) | Err(Status::BUFFER_TOO_SMALL) => Err(Status::BAD_BUFFER_SIZE),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the boot option numbers in the order they are attempted.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `BAD_BUFFER_SIZE`: The variable is not a list of 16-bit numbers.
+    /// - Any error returned by [`RuntimeServices::get_variable()`].
+    pub fn boot_order(&self) -> Result<Vec<u16>> {
+        let (data, _) = self.get_variable(cstr16!("BootOrder"), &GLOBAL_VARIABLE)?;
+        if data.len() % 2 != 0 {
+            return Err(Status::BAD_BUFFER_SIZE);
+        }
+
+        Ok(data.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect())
+    }
+
+    /// Sets the order in which boot options are attempted.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by [`RuntimeServices::set_variable()`].
+    pub fn set_boot_order(&self, order: &[u16]) -> Result<()> {
+        let data: Vec<u8> = order.iter().flat_map(|n| n.to_le_bytes()).collect();
+        self.set_variable(cstr16!("BootOrder"), &GLOBAL_VARIABLE, ATTRIBUTES, &data)
+    }
+
+    /// Reads and parses a `Boot####`, `Driver####`, or `SysPrep####` load
+    /// option.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `INVALID_PARAMETER`: The variable is not a valid load option.
+    /// - Any error returned by [`RuntimeServices::get_variable()`].
+    pub fn load_option(&self, kind: LoadOptionType, number: u16) -> Result<LoadOption> {
+        let (data, _) = self.get_variable(&load_option_name(kind, number), &GLOBAL_VARIABLE)?;
+        LoadOption::from_bytes(&data)
+    }
+
+    /// Writes a `Boot####`, `Driver####`, or `SysPrep####` load option.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by [`RuntimeServices::set_variable()`].
+    pub fn set_load_option(
+        &self,
+        kind: LoadOptionType,
+        number: u16,
+        option: &LoadOption,
+    ) -> Result<()> {
+        let name = load_option_name(kind, number);
+        self.set_variable(&name, &GLOBAL_VARIABLE, ATTRIBUTES, &option.to_bytes())
+    }
+
+    /// Returns the firmware's boot manager timeout, in seconds.
+    ///
+    /// A value of `0xFFFF` means to wait indefinitely for user input.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `BAD_BUFFER_SIZE`: The variable is not 2 bytes.
+    /// - Any error returned by [`RuntimeServices::get_variable_into()`].
+    pub fn timeout(&self) -> Result<u16> {
+        self.get_global(cstr16!("Timeout")).map(u16::from_le_bytes)
+    }
+
+    /// Sets the firmware's boot manager timeout, in seconds.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by [`RuntimeServices::set_variable()`].
+    pub fn set_timeout(&self, timeout: u16) -> Result<()> {
+        self.set_variable(cstr16!("Timeout"), &GLOBAL_VARIABLE, ATTRIBUTES, &timeout.to_le_bytes())
+    }
+
+    /// Returns `true` if the platform firmware is operating in secure boot
+    /// mode.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `BAD_BUFFER_SIZE`: The variable is not 1 byte.
+    /// - Any error returned by [`RuntimeServices::get_variable_into()`].
+    pub fn secure_boot(&self) -> Result<bool> {
+        self.get_global::<1>(cstr16!("SecureBoot")).map(|b| b[0] == 1)
+    }
+
+    /// Returns `true` if the system is in setup mode.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `BAD_BUFFER_SIZE`: The variable is not 1 byte.
+    /// - Any error returned by [`RuntimeServices::get_variable_into()`].
+    pub fn setup_mode(&self) -> Result<bool> {
+        self.get_global::<1>(cstr16!("SetupMode")).map(|b| b[0] == 1)
+    }
+
+    /// Returns the language code the system is configured for, e.g. "en-US".
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `INVALID_PARAMETER`: The variable is not an ASCII string.
+    /// - Any error returned by [`RuntimeServices::get_variable()`].
+    pub fn platform_lang(&self) -> Result<String> {
+        let (mut data, _) = self.get_variable(cstr16!("PlatformLang"), &GLOBAL_VARIABLE)?;
+        if let Some(nul) = data.iter().position(|&b| b == 0) {
+            data.truncate(nul);
+        }
+
+        if !data.is_ascii() {
+            return Err(Status::INVALID_PARAMETER);
+        }
+
+        String::from_utf8(data).map_err(|_| Status::INVALID_PARAMETER)
+    }
+
+    /// Returns the OS-to-firmware features supported by the firmware.
+    ///
+    /// # Errors
+    ///
+    /// - `NOT_FOUND`: The variable was not found.
+    /// - `BAD_BUFFER_SIZE`: The variable is not 8 bytes.
+    /// - Any error returned by [`RuntimeServices::get_variable_into()`].
+    pub fn os_indications_supported(&self) -> Result<OsIndications> {
+        self.get_global(cstr16!("OsIndicationsSupported"))
+            .map(|b| OsIndications(u64::from_le_bytes(b)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// `Boot0000` with description "UEFI OS",
+    /// `HD(1,GPT,...)/\EFI\BOOT\BOOTX64.EFI`, and optional data.
+    fn fixture() -> LoadOption {
+        LoadOption {
+            attributes: LoadOptionAttributes::ACTIVE,
+            description: cstr16!("UEFI OS").to_owned(),
+            file_paths: alloc::vec![
+                DevicePathBuf::from_text(concat!(
+                    "HD(1,GPT,2A9E8B3C-1D4F-4A6B-9C8D-7E6F5A4B3C2D,0x800,0x100000)/",
+                    "\\EFI\\BOOT\\BOOTX64.EFI",
+                ))
+                .unwrap(),
+                DevicePathBuf::from_text("\\initrd.img").unwrap(),
+            ],
+            optional_data: alloc::vec![0xDE, 0xAD, 0xBE, 0xEF],
+        }
+    }
+
+    #[test]
+    fn load_option_round_trip() {
+        let option = fixture();
+        let bytes = option.to_bytes();
+
+        assert_eq!(&bytes[..4], &[1, 0, 0, 0]);
+        let path_len = option.file_paths.iter().map(|p| p.size()).sum::<usize>();
+        assert_eq!(usize::from(u16::from_le_bytes([bytes[4], bytes[5]])), path_len);
+        assert_eq!(&bytes[6..8], &[u8::try_from('U').unwrap(), 0]);
+        assert!(bytes.ends_with(&[0xDE, 0xAD, 0xBE, 0xEF]));
+
+        let parsed = LoadOption::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, option);
+        assert_eq!(parsed.file_path().unwrap().nodes().count(), 2);
+    }
+
+    #[test]
+    fn load_option_invalid() {
+        let bytes = fixture().to_bytes();
+        // Truncated header
+        assert_eq!(LoadOption::from_bytes(&bytes[..5]), Err(Status::INVALID_PARAMETER));
+        // Unterminated description
+        assert_eq!(LoadOption::from_bytes(&bytes[..12]), Err(Status::INVALID_PARAMETER));
+        // Truncated device path
+        assert_eq!(LoadOption::from_bytes(&bytes[..30]), Err(Status::INVALID_PARAMETER));
+    }
+
+    #[test]
+    fn option_names() {
+        assert_eq!(load_option_name(LoadOptionType::Boot, 0x1A), cstr16!("Boot001A"));
+        assert_eq!(load_option_name(LoadOptionType::Driver, 0), cstr16!("Driver0000"));
+    }
+}